@@ -0,0 +1,627 @@
+//! 类型级长度的定长数组
+//! Fixed-size arrays whose length is a type-level integer
+//!
+//! `TArray<T, N>` 的长度 `N` 是 `Z0`/`P1`/`B0`/`B1` 组成的类型级无符号整数，
+//! 拼接、拆分和按类型下标访问都在编译期检查长度。
+//! The length `N` of `TArray<T, N>` is a type-level unsigned integer built from
+//! `Z0`/`P1`/`B0`/`B1`; concatenation, splitting and type-indexed access are all
+//! checked at compile time.
+//!
+//! 底层存储就是原生数组 `[T; N::USIZE]`，因此不需要 `unsafe`。
+//! The storage is a plain `[T; N::USIZE]`, so no `unsafe` is required.
+//!
+//! # 范围 / Range
+//! 与 [`Const`](crate::number::Const) 一致，目前支持长度 0 ~ 64。
+//! Same as [`Const`](crate::number::Const): lengths 0 to 64 are supported.
+
+use core::fmt;
+use core::ops::{Add, Sub, Index, IndexMut};
+
+use crate::number::{Z0, P1, B0, B1, Unsigned, Cmp, Less};
+
+// ========== 存储与长度特质 ==========
+// ========== Storage and Length Traits ==========
+
+/// 数组存储特质，为原生数组 `[T; L]` 实现
+/// Array storage trait, implemented for the native array `[T; L]`
+pub trait ArrayStorage<T>: AsRef<[T]> + AsMut<[T]> + IntoIterator<Item = T> {
+    /// 按下标逐个生成元素
+    /// Builds the array by calling `f` for every index
+    fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self;
+}
+
+impl<T, const L: usize> ArrayStorage<T> for [T; L] {
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        core::array::from_fn(f)
+    }
+}
+
+/// 可用作数组长度的类型级无符号整数
+/// Type-level unsigned integers usable as an array length
+pub trait ArrayLength: Unsigned {
+    /// 对应的运行时长度
+    /// The corresponding runtime length
+    const USIZE: usize;
+
+    /// 对应的原生数组类型 `[T; Self::USIZE]`
+    /// The matching native array type `[T; Self::USIZE]`
+    type Array<T>: ArrayStorage<T>;
+}
+
+// ========== 定长数组 ==========
+// ========== Fixed-size Array ==========
+
+/// 长度为类型级整数 `N` 的定长数组
+/// Fixed-size array whose length is the type-level integer `N`
+///
+/// # 示例 / Example
+/// ```rust
+/// use unitrix::number::{TArray, Const};
+///
+/// let a: TArray<i32, Const<2>> = [1, 2].into();
+/// let b: TArray<i32, Const<3>> = [3, 4, 5].into();
+/// let c: TArray<i32, Const<5>> = a.concat(b);
+/// assert_eq!(c.as_slice(), &[1, 2, 3, 4, 5]);
+/// assert_eq!(*c.get::<Const<4>>(), 5);
+///
+/// let (head, tail) = c.split::<Const<1>>();
+/// let tail: [i32; 4] = tail.into();
+/// assert_eq!(head[0], 1);
+/// assert_eq!(tail, [2, 3, 4, 5]);
+/// ```
+pub struct TArray<T, N: ArrayLength>(pub N::Array<T>);
+
+impl<T, N: ArrayLength> TArray<T, N> {
+    /// 由原生数组创建
+    /// Creates a new array from the native array
+    #[inline(always)]
+    pub fn new(array: N::Array<T>) -> Self {
+        TArray(array)
+    }
+
+    /// 按下标逐个生成元素
+    /// Builds the array by calling `f` for every index
+    #[inline(always)]
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        TArray(N::Array::<T>::from_fn(f))
+    }
+
+    /// 取出原生数组
+    /// Extracts the native array
+    #[inline(always)]
+    pub fn into_inner(self) -> N::Array<T> {
+        self.0
+    }
+
+    /// 数组长度（编译期常量）
+    /// Array length (a compile-time constant)
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        N::USIZE
+    }
+
+    /// 数组是否为空
+    /// Whether the array is empty
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        N::USIZE == 0
+    }
+
+    /// 以切片形式访问
+    /// Views the array as a slice
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[T] {
+        self.0.as_ref()
+    }
+
+    /// 以可变切片形式访问
+    /// Views the array as a mutable slice
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        self.0.as_mut()
+    }
+
+    /// 类型级下标访问，`I >= N` 时编译失败
+    /// Type-indexed access; fails to compile when `I >= N`
+    #[inline(always)]
+    pub fn get<I>(&self) -> &T
+    where
+        I: ArrayLength + Cmp<N, Output = Less>,
+    {
+        &self.as_slice()[I::USIZE]
+    }
+
+    /// 类型级下标可变访问，`I >= N` 时编译失败
+    /// Type-indexed mutable access; fails to compile when `I >= N`
+    #[inline(always)]
+    pub fn get_mut<I>(&mut self) -> &mut T
+    where
+        I: ArrayLength + Cmp<N, Output = Less>,
+    {
+        &mut self.as_mut_slice()[I::USIZE]
+    }
+
+    /// 拼接两个数组，结果长度为 `N + M`
+    /// Concatenates two arrays; the result has length `N + M`
+    pub fn concat<M>(self, rhs: TArray<T, M>) -> TArray<T, <N as Add<M>>::Output>
+    where
+        M: ArrayLength,
+        N: Add<M, Output: ArrayLength>,
+    {
+        let mut iter = self.0.into_iter().chain(rhs.0);
+        TArray::from_fn(|_| iter.next().expect("length checked at compile time"))
+    }
+
+    /// 在位置 `M` 处拆分，得到长度为 `M` 与 `N - M` 的两个数组；`M > N` 时编译失败
+    /// Splits at `M` into arrays of length `M` and `N - M`; fails to compile when `M > N`
+    #[allow(clippy::type_complexity)]
+    pub fn split<M>(self) -> (TArray<T, M>, TArray<T, <N as Sub<M>>::Output>)
+    where
+        M: ArrayLength,
+        N: Sub<M, Output: ArrayLength>,
+    {
+        let mut iter = self.0.into_iter();
+        let head = TArray::from_fn(|_| iter.next().expect("length checked at compile time"));
+        let tail = TArray::from_fn(|_| iter.next().expect("length checked at compile time"));
+        (head, tail)
+    }
+}
+
+// ========== 常用特质实现 ==========
+// ========== Common Trait Implementations ==========
+
+impl<T: Clone, N: ArrayLength> Clone for TArray<T, N>
+where
+    N::Array<T>: Clone,
+{
+    fn clone(&self) -> Self {
+        TArray(self.0.clone())
+    }
+}
+
+impl<T: Copy, N: ArrayLength> Copy for TArray<T, N> where N::Array<T>: Copy {}
+
+impl<T: Default, N: ArrayLength> Default for TArray<T, N> {
+    fn default() -> Self {
+        TArray::from_fn(|_| T::default())
+    }
+}
+
+impl<T: fmt::Debug, N: ArrayLength> fmt::Debug for TArray<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T: PartialEq, N: ArrayLength> PartialEq for TArray<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, N: ArrayLength> Eq for TArray<T, N> {}
+
+/// 运行时下标访问（越界时 panic，与原生数组一致）
+/// Runtime indexing (panics when out of bounds, like native arrays)
+impl<T, N: ArrayLength> Index<usize> for TArray<T, N> {
+    type Output = T;
+    #[inline(always)]
+    fn index(&self, index: usize) -> &T {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T, N: ArrayLength> IndexMut<usize> for TArray<T, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.as_mut_slice()[index]
+    }
+}
+
+impl<T, N: ArrayLength> IntoIterator for TArray<T, N> {
+    type Item = T;
+    type IntoIter = <N::Array<T> as IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+// ========== 与原生数组互转 ==========
+// ========== Conversion to and from Native Arrays ==========
+
+/// `[T; L]` => `TArray<T, N>`（要求 `N::USIZE == L`）
+/// `[T; L]` => `TArray<T, N>` (requires `N::USIZE == L`)
+impl<T, N, const L: usize> From<[T; L]> for TArray<T, N>
+where
+    N: ArrayLength<Array<T> = [T; L]>,
+{
+    #[inline(always)]
+    fn from(array: [T; L]) -> Self {
+        TArray(array)
+    }
+}
+
+/// `TArray<T, N>` => `[T; L]`（要求 `N::USIZE == L`）
+/// `TArray<T, N>` => `[T; L]` (requires `N::USIZE == L`)
+impl<T, N, const L: usize> From<TArray<T, N>> for [T; L]
+where
+    N: ArrayLength<Array<T> = [T; L]>,
+{
+    #[inline(always)]
+    fn from(array: TArray<T, N>) -> Self {
+        array.0
+    }
+}
+
+// ========== 长度实现 (0 ~ 64) ==========
+// ========== Length Implementations (0 to 64) ==========
+
+impl ArrayLength for Z0 {
+    const USIZE: usize = 0;
+    type Array<T> = [T; 0];
+}
+
+impl ArrayLength for P1 {
+    const USIZE: usize = 1;
+    type Array<T> = [T; 1];
+}
+
+impl ArrayLength for B0<P1> {
+    const USIZE: usize = 2;
+    type Array<T> = [T; 2];
+}
+
+impl ArrayLength for B1<P1> {
+    const USIZE: usize = 3;
+    type Array<T> = [T; 3];
+}
+
+impl ArrayLength for B0<B0<P1>> {
+    const USIZE: usize = 4;
+    type Array<T> = [T; 4];
+}
+
+impl ArrayLength for B1<B0<P1>> {
+    const USIZE: usize = 5;
+    type Array<T> = [T; 5];
+}
+
+impl ArrayLength for B0<B1<P1>> {
+    const USIZE: usize = 6;
+    type Array<T> = [T; 6];
+}
+
+impl ArrayLength for B1<B1<P1>> {
+    const USIZE: usize = 7;
+    type Array<T> = [T; 7];
+}
+
+impl ArrayLength for B0<B0<B0<P1>>> {
+    const USIZE: usize = 8;
+    type Array<T> = [T; 8];
+}
+
+impl ArrayLength for B1<B0<B0<P1>>> {
+    const USIZE: usize = 9;
+    type Array<T> = [T; 9];
+}
+
+impl ArrayLength for B0<B1<B0<P1>>> {
+    const USIZE: usize = 10;
+    type Array<T> = [T; 10];
+}
+
+impl ArrayLength for B1<B1<B0<P1>>> {
+    const USIZE: usize = 11;
+    type Array<T> = [T; 11];
+}
+
+impl ArrayLength for B0<B0<B1<P1>>> {
+    const USIZE: usize = 12;
+    type Array<T> = [T; 12];
+}
+
+impl ArrayLength for B1<B0<B1<P1>>> {
+    const USIZE: usize = 13;
+    type Array<T> = [T; 13];
+}
+
+impl ArrayLength for B0<B1<B1<P1>>> {
+    const USIZE: usize = 14;
+    type Array<T> = [T; 14];
+}
+
+impl ArrayLength for B1<B1<B1<P1>>> {
+    const USIZE: usize = 15;
+    type Array<T> = [T; 15];
+}
+
+impl ArrayLength for B0<B0<B0<B0<P1>>>> {
+    const USIZE: usize = 16;
+    type Array<T> = [T; 16];
+}
+
+impl ArrayLength for B1<B0<B0<B0<P1>>>> {
+    const USIZE: usize = 17;
+    type Array<T> = [T; 17];
+}
+
+impl ArrayLength for B0<B1<B0<B0<P1>>>> {
+    const USIZE: usize = 18;
+    type Array<T> = [T; 18];
+}
+
+impl ArrayLength for B1<B1<B0<B0<P1>>>> {
+    const USIZE: usize = 19;
+    type Array<T> = [T; 19];
+}
+
+impl ArrayLength for B0<B0<B1<B0<P1>>>> {
+    const USIZE: usize = 20;
+    type Array<T> = [T; 20];
+}
+
+impl ArrayLength for B1<B0<B1<B0<P1>>>> {
+    const USIZE: usize = 21;
+    type Array<T> = [T; 21];
+}
+
+impl ArrayLength for B0<B1<B1<B0<P1>>>> {
+    const USIZE: usize = 22;
+    type Array<T> = [T; 22];
+}
+
+impl ArrayLength for B1<B1<B1<B0<P1>>>> {
+    const USIZE: usize = 23;
+    type Array<T> = [T; 23];
+}
+
+impl ArrayLength for B0<B0<B0<B1<P1>>>> {
+    const USIZE: usize = 24;
+    type Array<T> = [T; 24];
+}
+
+impl ArrayLength for B1<B0<B0<B1<P1>>>> {
+    const USIZE: usize = 25;
+    type Array<T> = [T; 25];
+}
+
+impl ArrayLength for B0<B1<B0<B1<P1>>>> {
+    const USIZE: usize = 26;
+    type Array<T> = [T; 26];
+}
+
+impl ArrayLength for B1<B1<B0<B1<P1>>>> {
+    const USIZE: usize = 27;
+    type Array<T> = [T; 27];
+}
+
+impl ArrayLength for B0<B0<B1<B1<P1>>>> {
+    const USIZE: usize = 28;
+    type Array<T> = [T; 28];
+}
+
+impl ArrayLength for B1<B0<B1<B1<P1>>>> {
+    const USIZE: usize = 29;
+    type Array<T> = [T; 29];
+}
+
+impl ArrayLength for B0<B1<B1<B1<P1>>>> {
+    const USIZE: usize = 30;
+    type Array<T> = [T; 30];
+}
+
+impl ArrayLength for B1<B1<B1<B1<P1>>>> {
+    const USIZE: usize = 31;
+    type Array<T> = [T; 31];
+}
+
+impl ArrayLength for B0<B0<B0<B0<B0<P1>>>>> {
+    const USIZE: usize = 32;
+    type Array<T> = [T; 32];
+}
+
+impl ArrayLength for B1<B0<B0<B0<B0<P1>>>>> {
+    const USIZE: usize = 33;
+    type Array<T> = [T; 33];
+}
+
+impl ArrayLength for B0<B1<B0<B0<B0<P1>>>>> {
+    const USIZE: usize = 34;
+    type Array<T> = [T; 34];
+}
+
+impl ArrayLength for B1<B1<B0<B0<B0<P1>>>>> {
+    const USIZE: usize = 35;
+    type Array<T> = [T; 35];
+}
+
+impl ArrayLength for B0<B0<B1<B0<B0<P1>>>>> {
+    const USIZE: usize = 36;
+    type Array<T> = [T; 36];
+}
+
+impl ArrayLength for B1<B0<B1<B0<B0<P1>>>>> {
+    const USIZE: usize = 37;
+    type Array<T> = [T; 37];
+}
+
+impl ArrayLength for B0<B1<B1<B0<B0<P1>>>>> {
+    const USIZE: usize = 38;
+    type Array<T> = [T; 38];
+}
+
+impl ArrayLength for B1<B1<B1<B0<B0<P1>>>>> {
+    const USIZE: usize = 39;
+    type Array<T> = [T; 39];
+}
+
+impl ArrayLength for B0<B0<B0<B1<B0<P1>>>>> {
+    const USIZE: usize = 40;
+    type Array<T> = [T; 40];
+}
+
+impl ArrayLength for B1<B0<B0<B1<B0<P1>>>>> {
+    const USIZE: usize = 41;
+    type Array<T> = [T; 41];
+}
+
+impl ArrayLength for B0<B1<B0<B1<B0<P1>>>>> {
+    const USIZE: usize = 42;
+    type Array<T> = [T; 42];
+}
+
+impl ArrayLength for B1<B1<B0<B1<B0<P1>>>>> {
+    const USIZE: usize = 43;
+    type Array<T> = [T; 43];
+}
+
+impl ArrayLength for B0<B0<B1<B1<B0<P1>>>>> {
+    const USIZE: usize = 44;
+    type Array<T> = [T; 44];
+}
+
+impl ArrayLength for B1<B0<B1<B1<B0<P1>>>>> {
+    const USIZE: usize = 45;
+    type Array<T> = [T; 45];
+}
+
+impl ArrayLength for B0<B1<B1<B1<B0<P1>>>>> {
+    const USIZE: usize = 46;
+    type Array<T> = [T; 46];
+}
+
+impl ArrayLength for B1<B1<B1<B1<B0<P1>>>>> {
+    const USIZE: usize = 47;
+    type Array<T> = [T; 47];
+}
+
+impl ArrayLength for B0<B0<B0<B0<B1<P1>>>>> {
+    const USIZE: usize = 48;
+    type Array<T> = [T; 48];
+}
+
+impl ArrayLength for B1<B0<B0<B0<B1<P1>>>>> {
+    const USIZE: usize = 49;
+    type Array<T> = [T; 49];
+}
+
+impl ArrayLength for B0<B1<B0<B0<B1<P1>>>>> {
+    const USIZE: usize = 50;
+    type Array<T> = [T; 50];
+}
+
+impl ArrayLength for B1<B1<B0<B0<B1<P1>>>>> {
+    const USIZE: usize = 51;
+    type Array<T> = [T; 51];
+}
+
+impl ArrayLength for B0<B0<B1<B0<B1<P1>>>>> {
+    const USIZE: usize = 52;
+    type Array<T> = [T; 52];
+}
+
+impl ArrayLength for B1<B0<B1<B0<B1<P1>>>>> {
+    const USIZE: usize = 53;
+    type Array<T> = [T; 53];
+}
+
+impl ArrayLength for B0<B1<B1<B0<B1<P1>>>>> {
+    const USIZE: usize = 54;
+    type Array<T> = [T; 54];
+}
+
+impl ArrayLength for B1<B1<B1<B0<B1<P1>>>>> {
+    const USIZE: usize = 55;
+    type Array<T> = [T; 55];
+}
+
+impl ArrayLength for B0<B0<B0<B1<B1<P1>>>>> {
+    const USIZE: usize = 56;
+    type Array<T> = [T; 56];
+}
+
+impl ArrayLength for B1<B0<B0<B1<B1<P1>>>>> {
+    const USIZE: usize = 57;
+    type Array<T> = [T; 57];
+}
+
+impl ArrayLength for B0<B1<B0<B1<B1<P1>>>>> {
+    const USIZE: usize = 58;
+    type Array<T> = [T; 58];
+}
+
+impl ArrayLength for B1<B1<B0<B1<B1<P1>>>>> {
+    const USIZE: usize = 59;
+    type Array<T> = [T; 59];
+}
+
+impl ArrayLength for B0<B0<B1<B1<B1<P1>>>>> {
+    const USIZE: usize = 60;
+    type Array<T> = [T; 60];
+}
+
+impl ArrayLength for B1<B0<B1<B1<B1<P1>>>>> {
+    const USIZE: usize = 61;
+    type Array<T> = [T; 61];
+}
+
+impl ArrayLength for B0<B1<B1<B1<B1<P1>>>>> {
+    const USIZE: usize = 62;
+    type Array<T> = [T; 62];
+}
+
+impl ArrayLength for B1<B1<B1<B1<B1<P1>>>>> {
+    const USIZE: usize = 63;
+    type Array<T> = [T; 63];
+}
+
+impl ArrayLength for B0<B0<B0<B0<B0<B0<P1>>>>>> {
+    const USIZE: usize = 64;
+    type Array<T> = [T; 64];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::Const;
+
+    #[test]
+    fn test_len() {
+        assert_eq!(<Z0 as ArrayLength>::USIZE, 0);
+        assert_eq!(<Const<7> as ArrayLength>::USIZE, 7);
+        assert_eq!(<Const<64> as ArrayLength>::USIZE, 64);
+
+        let a: TArray<u8, Const<3>> = TArray::default();
+        assert_eq!(a.len(), 3);
+        assert!(!a.is_empty());
+        assert!(TArray::<u8, Z0>::default().is_empty());
+    }
+
+    #[test]
+    fn test_concat_split() {
+        let a: TArray<i32, Const<2>> = [1, 2].into();
+        let b: TArray<i32, Const<3>> = [3, 4, 5].into();
+        let c: TArray<i32, Const<5>> = a.concat(b);
+        assert_eq!(c.as_slice(), &[1, 2, 3, 4, 5]);
+
+        let (l, r): (TArray<i32, Const<2>>, TArray<i32, Const<3>>) = c.split::<Const<2>>();
+        assert_eq!(<[i32; 2]>::from(l), [1, 2]);
+        assert_eq!(<[i32; 3]>::from(r), [3, 4, 5]);
+
+        let (e, all) = c.split::<Z0>();
+        assert!(e.is_empty());
+        assert_eq!(all, c);
+    }
+
+    #[test]
+    fn test_index() {
+        let mut a: TArray<i32, Const<4>> = TArray::from_fn(|i| i as i32 * 10);
+        assert_eq!(*a.get::<Z0>(), 0);
+        assert_eq!(*a.get::<Const<3>>(), 30);
+        *a.get_mut::<P1>() = 7;
+        a[2] += 1;
+        assert_eq!(a.into_inner(), [0, 7, 21, 30]);
+    }
+}
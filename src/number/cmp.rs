@@ -1,7 +1,7 @@
 use core::cmp::Ordering;
 use core::default::Default;
 use crate::sealed::Sealed;
-use crate::number::{Z0, P1, N1, B0, B1, NonZero};
+use crate::number::{Z0, P1, N1, B0, B1, NonZero, Min, Max};
 ///类型级比较,仅考虑类型级整数比较
 
 //-------------基本定义-------------------------------------------------
@@ -42,12 +42,12 @@ impl Ord for Equal {
 ///
 /// # 示例
 /// ```rust
-/// use unitrix::number::{Cmp, Ord, N1, Z0, P1};
+/// use unitrix::number::{Cmp, Ord, Const};
 /// use std::cmp::Ordering;
 ///
-/// assert_eq!(<Z0 as Cmp<N1>>::Output::to_ordering(), Ordering::Greater);
-/// assert_eq!(<Z0 as Cmp<Z0>>::Output::to_ordering(), Ordering::Equal);
-/// assert_eq!(<Z0 as Cmp<P1>>::Output::to_ordering(), Ordering::Less);
+/// assert_eq!(<Const<0> as Cmp<Const<-1>>>::Output::to_ordering(), Ordering::Greater);
+/// assert_eq!(<Const<0> as Cmp<Const<0>>>::Output::to_ordering(), Ordering::Equal);
+/// assert_eq!(<Const<0> as Cmp<Const<1>>>::Output::to_ordering(), Ordering::Less);
 /// ```
 pub trait Cmp<Rhs = Self> {
     /// 比较结果的类型（只能是 `Greater`、`Less` 或 `Equal` 之一）
@@ -440,4 +440,62 @@ impl IfGreater for Greater {
     fn greater() -> Self::Output {
         Greater::new()
     }
-}
\ No newline at end of file
+}
+//---------------------类型级整数的最小/最大值---------------------------------
+
+/// 根据比较结果 `O` 在 `Self` 与 `Rhs` 之间选择较小值和较大值
+pub trait SelectBy<O, Rhs> {
+    /// 较小者
+    type Min;
+    /// 较大者
+    type Max;
+}
+
+impl<A, B> SelectBy<Less, B> for A {
+    type Min = A;
+    type Max = B;
+}
+
+impl<A, B> SelectBy<Equal, B> for A {
+    type Min = A;
+    type Max = A;
+}
+
+impl<A, B> SelectBy<Greater, B> for A {
+    type Min = B;
+    type Max = A;
+}
+
+macro_rules! impl_min_max {
+    ($([$($g:tt)*] $t:ty),*) => {
+        $(
+            impl<$($g)* Rhs> Min<Rhs> for $t
+            where
+                $t: Cmp<Rhs> + SelectBy<<$t as Cmp<Rhs>>::Output, Rhs>,
+                <$t as SelectBy<<$t as Cmp<Rhs>>::Output, Rhs>>::Min: Default,
+            {
+                type Output = <$t as SelectBy<<$t as Cmp<Rhs>>::Output, Rhs>>::Min;
+
+                #[inline]
+                fn min(self, _: Rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+
+            impl<$($g)* Rhs> Max<Rhs> for $t
+            where
+                $t: Cmp<Rhs> + SelectBy<<$t as Cmp<Rhs>>::Output, Rhs>,
+                <$t as SelectBy<<$t as Cmp<Rhs>>::Output, Rhs>>::Max: Default,
+            {
+                type Output = <$t as SelectBy<<$t as Cmp<Rhs>>::Output, Rhs>>::Max;
+
+                #[inline]
+                fn max(self, _: Rhs) -> Self::Output {
+                    Self::Output::default()
+                }
+            }
+        )*
+    };
+}
+
+impl_min_max!([] Z0, [] P1, [] N1, [H: NonZero,] B0<H>, [H: NonZero,] B1<H>);
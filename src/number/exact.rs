@@ -0,0 +1,650 @@
+//! 类型级常量的精确值与 Var 的精确缩放
+//! Exact values of type-level constants and exact scaling of `Var`
+//!
+//! 为类型级整数、`FixedPoint`、`Float` 与 `Rational` 提供精确的 分子/分母 表示，
+//! 并实现 `Var<T>` 与这些常量之间的乘除法：
+//! Provides an exact numerator/denominator for type-level integers, `FixedPoint`,
+//! `Float` and `Rational`, and implements multiplication and division between
+//! `Var<T>` and these constants:
+//! - 整数 `T`：先乘后除，按所选取整模式舍入
+//!   - integer `T`: multiply then divide, rounded with the chosen rounding mode
+//! - 浮点 `T`：乘以折叠后的单个常量
+//!   - float `T`: a single multiply by the folded constant
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::number::{Var, Rational, RoundTowardZero, Const};
+//!
+//! type FiveNinths = Rational<Const<5>, Const<9>>;
+//!
+//! assert_eq!(Var(90_i32) * FiveNinths::new(), Var(50));
+//! assert_eq!(Var(4_i32) * FiveNinths::new(), Var(2));      // 2.22 -> 2
+//! assert_eq!(Var(5_i32) * FiveNinths::new(), Var(3));      // 2.78 -> 3
+//! assert_eq!(Var(5_i32).mul_const::<FiveNinths, RoundTowardZero>(), Var(2));
+//! ```
+
+use core::ops::{Mul, Div};
+use crate::number::{Z0, P1, B0, B1, TypedInt, NonZero, FixedPoint, Float, Rational, Var};
+
+// ========== 精确值 ==========
+// ========== Exact Values ==========
+
+/// 可精确表示为 分子/分母 的类型级常量
+/// Type-level constants that are exactly representable as numerator/denominator
+///
+/// 精确值在编译期求值，超出 i128 的常量在使用处编译失败：
+/// The exact value is evaluated at compile time, so a constant beyond i128 fails to
+/// compile where it is used:
+/// ```compile_fail,E0080
+/// use unitrix::number::{Const, ExactValue, Float};
+///
+/// let _ = <Float<Float<Const<1>, Const<64>>, Const<64>>>::EXACT;
+/// ```
+pub trait ExactValue {
+    /// 约分后的 (分子, 分母)，分母恒为正
+    /// Reduced (numerator, denominator); the denominator is always positive
+    const EXACT: (i128, i128);
+
+    /// 转换为 f64（可能有精度损失）
+    /// Converts to f64 (may lose precision)
+    #[inline(always)]
+    fn to_f64() -> f64 {
+        let (num, den) = Self::EXACT;
+        num as f64 / den as f64
+    }
+}
+
+/// 约分并使分母为正
+/// Reduces the fraction and makes the denominator positive
+pub(crate) const fn reduce(num: i128, den: i128) -> (i128, i128) {
+    let (mut a, mut b) = (num.unsigned_abs(), den.unsigned_abs());
+    while b != 0 {
+        let t = a % b;
+        a = b;
+        b = t;
+    }
+    if a == 0 {
+        return (num, den);
+    }
+    let g = a as i128;
+    if den < 0 {
+        (-num / g, -den / g)
+    } else {
+        (num / g, den / g)
+    }
+}
+
+/// 2 的 `exp` 次幂，超出 i128 时 panic（类型级常量过大）
+/// 2 to the power `exp`; panics beyond i128 (the type-level constant is too large)
+const fn pow2(exp: u32) -> i128 {
+    match 1_i128.checked_shl(exp) {
+        Some(p) if p > 0 => p,
+        _ => panic!("type-level constant overflows i128"),
+    }
+}
+
+/// 乘以 2 的 `exp` 次幂，超出 i128 时 panic
+/// Multiplies by 2 to the power `exp`; panics beyond i128
+const fn shl_exact(value: i128, exp: u32) -> i128 {
+    value.checked_mul(pow2(exp)).expect("type-level constant overflows i128")
+}
+
+/// 类型级整数 I = I / 1
+/// Type-level integer I = I / 1
+impl<I: TypedInt> ExactValue for I {
+    const EXACT: (i128, i128) = (I::I32 as i128, 1);
+}
+
+/// 定点数的二进制小数部分
+/// Binary fraction part of a fixed-point number
+///
+/// 值为 `num / 2^bits`，小数位从高到低为 2^-1, 2^-2, ...
+/// The value is `num / 2^bits`; the bits run from 2^-1, 2^-2, ... downwards
+pub trait BinaryFraction {
+    /// 小数位组成的整数
+    /// The fraction bits read as an integer
+    const NUM: i128;
+    /// 小数位数
+    /// Number of fraction bits
+    const BITS: u32;
+}
+
+/// Z0 单独使用，表示 0
+/// Z0 on its own means 0
+impl BinaryFraction for Z0 {
+    const NUM: i128 = 0;
+    const BITS: u32 = 0;
+}
+
+/// P1 表示当前位为1并结束
+/// P1 is a final 1 bit
+impl BinaryFraction for P1 {
+    const NUM: i128 = 1;
+    const BITS: u32 = 1;
+}
+
+/// B0<Next>：当前位为0
+/// B0<Next>: the current bit is 0
+impl<Next: BinaryFraction> BinaryFraction for B0<Next> {
+    const NUM: i128 = Next::NUM;
+    const BITS: u32 = Next::BITS + 1;
+}
+
+/// B1<Next>：当前位为1
+/// B1<Next>: the current bit is 1
+impl<Next: BinaryFraction> BinaryFraction for B1<Next> {
+    const NUM: i128 = pow2(Next::BITS) + Next::NUM;
+    const BITS: u32 = Next::BITS + 1;
+}
+
+/// 定点数 = 整数部分 + 小数部分
+/// Fixed point = integer part + fraction part
+impl<IntPart: TypedInt, FracPart: BinaryFraction> ExactValue for FixedPoint<IntPart, FracPart> {
+    const EXACT: (i128, i128) = reduce(
+        shl_exact(IntPart::I32 as i128, FracPart::BITS) + FracPart::NUM,
+        pow2(FracPart::BITS),
+    );
+}
+
+/// 浮点数 = 尾数 × 2^指数
+/// Float = significand × 2^exponent
+impl<Significand: ExactValue, Exponent: TypedInt> ExactValue for Float<Significand, Exponent> {
+    const EXACT: (i128, i128) = {
+        let (num, den) = Significand::EXACT;
+        let exp = Exponent::I32;
+        if exp >= 0 {
+            reduce(shl_exact(num, exp.unsigned_abs()), den)
+        } else {
+            reduce(num, shl_exact(den, exp.unsigned_abs()))
+        }
+    };
+}
+
+/// 有理数 = 分子 / 分母
+/// Rational = numerator / denominator
+impl<Num: TypedInt, Den: NonZero> ExactValue for Rational<Num, Den> {
+    const EXACT: (i128, i128) = reduce(Num::I32 as i128, Den::I32 as i128);
+}
+
+// ========== 取整模式 ==========
+// ========== Rounding Modes ==========
+
+/// 整数除法的取整模式
+/// Rounding mode of an integer division
+pub trait Rounding {
+    /// 计算 num / den（den > 0）
+    /// Computes num / den (den > 0)
+    fn div(num: i128, den: i128) -> i128;
+}
+
+/// 向零取整（与整数 `/` 一致）
+/// Round toward zero (same as integer `/`)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundTowardZero;
+
+/// 向下取整
+/// Round toward negative infinity
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundFloor;
+
+/// 向上取整
+/// Round toward positive infinity
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundCeil;
+
+/// 四舍五入（0.5 远离零）
+/// Round to nearest, ties away from zero
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundHalfAway;
+
+/// 四舍六入五成双（银行家舍入）
+/// Round to nearest, ties to even (banker's rounding)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundHalfEven;
+
+/// 运算符使用的默认取整模式
+/// Default rounding mode used by the operators
+pub type DefaultRounding = RoundHalfAway;
+
+impl Rounding for RoundTowardZero {
+    #[inline(always)]
+    fn div(num: i128, den: i128) -> i128 {
+        num / den
+    }
+}
+
+impl Rounding for RoundFloor {
+    #[inline(always)]
+    fn div(num: i128, den: i128) -> i128 {
+        num.div_euclid(den)
+    }
+}
+
+impl Rounding for RoundCeil {
+    #[inline(always)]
+    fn div(num: i128, den: i128) -> i128 {
+        -(-num).div_euclid(den)
+    }
+}
+
+impl Rounding for RoundHalfAway {
+    #[inline(always)]
+    fn div(num: i128, den: i128) -> i128 {
+        let (q, r) = (num / den, (num % den).abs());
+        if r >= den - r { q + num.signum() } else { q }
+    }
+}
+
+impl Rounding for RoundHalfEven {
+    #[inline(always)]
+    fn div(num: i128, den: i128) -> i128 {
+        let (q, r) = (num / den, (num % den).abs());
+        if r > den - r || (r == den - r && q % 2 != 0) { q + num.signum() } else { q }
+    }
+}
+
+// ========== 基本类型的精确缩放 ==========
+// ========== Exact Scaling of Primitive Types ==========
+
+/// 按 分子/分母 缩放基本数值类型
+/// Scales a primitive numeric type by numerator/denominator
+///
+/// - 整数：先乘后除并按 `R` 取整，溢出时返回 `None`
+///   - integers: multiply then divide, rounded with `R`; `None` on overflow
+/// - 浮点：乘以 `num / den`
+///   - floats: multiply by `num / den`
+pub trait Scale: Sized {
+    /// 计算 `self * num / den`，分母为零或溢出时返回 `None`
+    /// Computes `self * num / den`; `None` on a zero denominator or overflow
+    fn checked_scale<R: Rounding>(self, num: i128, den: i128) -> Option<Self>;
+
+    /// 计算 `self * num / den`，分母为零或溢出时 panic
+    /// Computes `self * num / den`; panics on a zero denominator or overflow
+    #[inline(always)]
+    fn scale<R: Rounding>(self, num: i128, den: i128) -> Self {
+        self.checked_scale::<R>(num, den).expect("scale factor has a zero denominator or the result overflows")
+    }
+
+    /// 乘以浮点系数 `factor`（用于无法精确表示的系数，如含π的比例），溢出时返回 `None`
+    /// Multiplies by the float `factor` (for factors without an exact form, such as ratios
+    /// involving π); `None` on overflow
+    fn checked_scale_f64<R: Rounding>(self, factor: f64) -> Option<Self>;
+
+    /// 计算 `(self * num + offset) / den`（仿射换算，如温标之间），整数只取整一次，
+    /// 分母为零或溢出时返回 `None`
+    /// Computes `(self * num + offset) / den` (an affine conversion, such as between
+    /// temperature scales); integers are rounded once; `None` on a zero denominator or overflow
+    fn checked_scale_offset<R: Rounding>(self, num: i128, den: i128, offset: i128) -> Option<Self>;
+}
+
+/// 整数乘以浮点系数的公共实现：乘积以 2^-32 为单位转为整数后按 `R` 取整
+/// Shared implementation of integer-by-float scaling: the product is converted to units
+/// of 2^-32 and then rounded with `R`
+#[inline(always)]
+fn scale_int_f64<R: Rounding>(value: i128, factor: f64) -> Option<i128> {
+    const UNIT: f64 = (1_u64 << 32) as f64;
+    let product = value as f64 * factor;
+    if product.is_nan() || product.abs() >= (1_u128 << 94) as f64 {
+        return None;
+    }
+    Some(R::div((product * UNIT) as i128, 1 << 32))
+}
+
+/// 整数缩放的公共实现
+/// Shared implementation of integer scaling
+#[inline(always)]
+fn scale_int<R: Rounding>(value: i128, num: i128, den: i128) -> Option<i128> {
+    let (num, den) = if den < 0 { (num.checked_neg()?, den.checked_neg()?) } else { (num, den) };
+    if den == 0 {
+        return None;
+    }
+    Some(R::div(value.checked_mul(num)?, den))
+}
+
+/// 整数仿射换算的公共实现
+/// Shared implementation of integer affine conversion
+#[inline(always)]
+fn scale_offset_int<R: Rounding>(value: i128, num: i128, den: i128, offset: i128) -> Option<i128> {
+    let (num, den, offset) = if den < 0 {
+        (num.checked_neg()?, den.checked_neg()?, offset.checked_neg()?)
+    } else {
+        (num, den, offset)
+    };
+    if den == 0 {
+        return None;
+    }
+    Some(R::div(value.checked_mul(num)?.checked_add(offset)?, den))
+}
+
+macro_rules! impl_scale_int {
+    ($($t:ty),*) => {
+        $(
+            impl Scale for $t {
+                #[inline(always)]
+                fn checked_scale<R: Rounding>(self, num: i128, den: i128) -> Option<Self> {
+                    scale_int::<R>(self as i128, num, den)?.try_into().ok()
+                }
+
+                #[inline(always)]
+                fn checked_scale_f64<R: Rounding>(self, factor: f64) -> Option<Self> {
+                    scale_int_f64::<R>(self as i128, factor)?.try_into().ok()
+                }
+
+                #[inline(always)]
+                fn checked_scale_offset<R: Rounding>(self, num: i128, den: i128, offset: i128) -> Option<Self> {
+                    scale_offset_int::<R>(self as i128, num, den, offset)?.try_into().ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_int!(i8, i16, i32, i64, i128, isize);
+
+impl Scale for f32 {
+    #[inline(always)]
+    fn checked_scale<R: Rounding>(self, num: i128, den: i128) -> Option<Self> {
+        if den == 0 { None } else { Some(self * (num as f64 / den as f64) as f32) }
+    }
+
+    #[inline(always)]
+    fn checked_scale_f64<R: Rounding>(self, factor: f64) -> Option<Self> {
+        Some(self * factor as f32)
+    }
+
+    #[inline(always)]
+    fn checked_scale_offset<R: Rounding>(self, num: i128, den: i128, offset: i128) -> Option<Self> {
+        if den == 0 { None } else { Some(((self as f64 * num as f64 + offset as f64) / den as f64) as f32) }
+    }
+}
+
+impl Scale for f64 {
+    #[inline(always)]
+    fn checked_scale<R: Rounding>(self, num: i128, den: i128) -> Option<Self> {
+        if den == 0 { None } else { Some(self * (num as f64 / den as f64)) }
+    }
+
+    #[inline(always)]
+    fn checked_scale_f64<R: Rounding>(self, factor: f64) -> Option<Self> {
+        Some(self * factor)
+    }
+
+    #[inline(always)]
+    fn checked_scale_offset<R: Rounding>(self, num: i128, den: i128, offset: i128) -> Option<Self> {
+        if den == 0 { None } else { Some((self * num as f64 + offset as f64) / den as f64) }
+    }
+}
+
+// ========== 基本类型的检查运算 ==========
+// ========== Checked Arithmetic of Primitive Types ==========
+
+/// 溢出或除以零时返回 `None` 的四则运算
+/// Arithmetic that returns `None` on overflow or division by zero
+///
+/// 整数使用标准库的 `checked_*`；浮点按 IEEE 754 得到无穷大或 NaN，总是返回 `Some`。
+/// Integers use the standard `checked_*` methods; floats follow IEEE 754 and yield
+/// infinity or NaN, so they always return `Some`.
+pub trait CheckedArith: Sized {
+    /// `self + rhs`
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// `self - rhs`
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// `self * rhs`
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// `self / rhs`
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_int {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedArith for $t {
+                #[inline(always)]
+                fn checked_add(self, rhs: Self) -> Option<Self> { <$t>::checked_add(self, rhs) }
+                #[inline(always)]
+                fn checked_sub(self, rhs: Self) -> Option<Self> { <$t>::checked_sub(self, rhs) }
+                #[inline(always)]
+                fn checked_mul(self, rhs: Self) -> Option<Self> { <$t>::checked_mul(self, rhs) }
+                #[inline(always)]
+                fn checked_div(self, rhs: Self) -> Option<Self> { <$t>::checked_div(self, rhs) }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_checked_float {
+    ($($t:ty),*) => {
+        $(
+            impl CheckedArith for $t {
+                #[inline(always)]
+                fn checked_add(self, rhs: Self) -> Option<Self> { Some(self + rhs) }
+                #[inline(always)]
+                fn checked_sub(self, rhs: Self) -> Option<Self> { Some(self - rhs) }
+                #[inline(always)]
+                fn checked_mul(self, rhs: Self) -> Option<Self> { Some(self * rhs) }
+                #[inline(always)]
+                fn checked_div(self, rhs: Self) -> Option<Self> { Some(self / rhs) }
+            }
+        )*
+    };
+}
+
+impl_checked_int!(i8, i16, i32, i64, i128, isize);
+impl_checked_float!(f32, f64);
+
+// ========== Var 与精确常量的运算 ==========
+// ========== Var with Exact Constants ==========
+
+impl<T: Scale> Var<T> {
+    /// 乘以类型级常量 `C`，整数按 `R` 取整
+    /// Multiplies by the type-level constant `C`; integers are rounded with `R`
+    #[inline(always)]
+    pub fn mul_const<C: ExactValue, R: Rounding>(self) -> Self {
+        let (num, den) = C::EXACT;
+        Var(self.0.scale::<R>(num, den))
+    }
+
+    /// 除以类型级常量 `C`，整数按 `R` 取整
+    /// Divides by the type-level constant `C`; integers are rounded with `R`
+    #[inline(always)]
+    pub fn div_const<C: ExactValue, R: Rounding>(self) -> Self {
+        let (num, den) = C::EXACT;
+        Var(self.0.scale::<R>(den, num))
+    }
+
+    /// 乘以类型级常量 `C`，溢出时返回 `None`
+    /// Multiplies by the type-level constant `C`; `None` on overflow
+    #[inline(always)]
+    pub fn checked_mul_const<C: ExactValue, R: Rounding>(self) -> Option<Self> {
+        let (num, den) = C::EXACT;
+        self.0.checked_scale::<R>(num, den).map(Var)
+    }
+
+    /// 除以类型级常量 `C`，除数为零或溢出时返回 `None`
+    /// Divides by the type-level constant `C`; `None` on a zero divisor or overflow
+    #[inline(always)]
+    pub fn checked_div_const<C: ExactValue, R: Rounding>(self) -> Option<Self> {
+        let (num, den) = C::EXACT;
+        self.0.checked_scale::<R>(den, num).map(Var)
+    }
+}
+
+/// V * FixedPoint
+impl<T: Scale, IntPart, FracPart> Mul<FixedPoint<IntPart, FracPart>> for Var<T>
+where
+    FixedPoint<IntPart, FracPart>: ExactValue,
+{
+    type Output = Var<T>;
+    #[inline(always)]
+    fn mul(self, _rhs: FixedPoint<IntPart, FracPart>) -> Self::Output {
+        self.mul_const::<FixedPoint<IntPart, FracPart>, DefaultRounding>()
+    }
+}
+
+/// V / FixedPoint
+impl<T: Scale, IntPart, FracPart> Div<FixedPoint<IntPart, FracPart>> for Var<T>
+where
+    FixedPoint<IntPart, FracPart>: ExactValue,
+{
+    type Output = Var<T>;
+    #[inline(always)]
+    fn div(self, _rhs: FixedPoint<IntPart, FracPart>) -> Self::Output {
+        self.div_const::<FixedPoint<IntPart, FracPart>, DefaultRounding>()
+    }
+}
+
+/// FixedPoint * V
+impl<T: Scale, IntPart, FracPart> Mul<Var<T>> for FixedPoint<IntPart, FracPart>
+where
+    FixedPoint<IntPart, FracPart>: ExactValue,
+{
+    type Output = Var<T>;
+    #[inline(always)]
+    fn mul(self, rhs: Var<T>) -> Self::Output {
+        rhs * self
+    }
+}
+
+/// V * Float
+impl<T: Scale, Significand, Exponent> Mul<Float<Significand, Exponent>> for Var<T>
+where
+    Float<Significand, Exponent>: ExactValue,
+{
+    type Output = Var<T>;
+    #[inline(always)]
+    fn mul(self, _rhs: Float<Significand, Exponent>) -> Self::Output {
+        self.mul_const::<Float<Significand, Exponent>, DefaultRounding>()
+    }
+}
+
+/// V / Float
+impl<T: Scale, Significand, Exponent> Div<Float<Significand, Exponent>> for Var<T>
+where
+    Float<Significand, Exponent>: ExactValue,
+{
+    type Output = Var<T>;
+    #[inline(always)]
+    fn div(self, _rhs: Float<Significand, Exponent>) -> Self::Output {
+        self.div_const::<Float<Significand, Exponent>, DefaultRounding>()
+    }
+}
+
+/// Float * V
+impl<T: Scale, Significand, Exponent> Mul<Var<T>> for Float<Significand, Exponent>
+where
+    Float<Significand, Exponent>: ExactValue,
+{
+    type Output = Var<T>;
+    #[inline(always)]
+    fn mul(self, rhs: Var<T>) -> Self::Output {
+        rhs * self
+    }
+}
+
+/// V * Rational
+impl<T: Scale, Num, Den> Mul<Rational<Num, Den>> for Var<T>
+where
+    Rational<Num, Den>: ExactValue,
+{
+    type Output = Var<T>;
+    #[inline(always)]
+    fn mul(self, _rhs: Rational<Num, Den>) -> Self::Output {
+        self.mul_const::<Rational<Num, Den>, DefaultRounding>()
+    }
+}
+
+/// V / Rational（分子为零时编译失败）
+/// V / Rational (fails to compile when the numerator is zero)
+impl<T: Scale, Num: NonZero, Den> Div<Rational<Num, Den>> for Var<T>
+where
+    Rational<Num, Den>: ExactValue,
+{
+    type Output = Var<T>;
+    #[inline(always)]
+    fn div(self, _rhs: Rational<Num, Den>) -> Self::Output {
+        self.div_const::<Rational<Num, Den>, DefaultRounding>()
+    }
+}
+
+/// Rational * V
+impl<T: Scale, Num, Den> Mul<Var<T>> for Rational<Num, Den>
+where
+    Rational<Num, Den>: ExactValue,
+{
+    type Output = Var<T>;
+    #[inline(always)]
+    fn mul(self, rhs: Var<T>) -> Self::Output {
+        rhs * self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{N1, Const};
+
+    type FiveNinths = Rational<Const<5>, Const<9>>;
+    type ThreeHalves = FixedPoint<P1, P1>;          // 1.1b = 1.5
+    type ThreeQuarters = FixedPoint<Z0, B1<P1>>;    // 0.11b = 0.75
+
+    #[test]
+    fn test_exact_values() {
+        assert_eq!(<Const<-6> as ExactValue>::EXACT, (-6, 1));
+        assert_eq!(<ThreeHalves as ExactValue>::EXACT, (3, 2));
+        assert_eq!(<ThreeQuarters as ExactValue>::EXACT, (3, 4));
+        assert_eq!(<FixedPoint<N1, P1> as ExactValue>::EXACT, (-1, 2));
+        assert_eq!(<Float<Const<3>, Const<-2>> as ExactValue>::EXACT, (3, 4));
+        assert_eq!(<Float<ThreeHalves, Const<3>> as ExactValue>::EXACT, (12, 1));
+        assert_eq!(<Rational<Const<6>, Const<-4>> as ExactValue>::EXACT, (-3, 2));
+        assert_eq!(FiveNinths::to_f64(), 5.0 / 9.0);
+    }
+
+    #[test]
+    fn test_rounding() {
+        assert_eq!(RoundTowardZero::div(-7, 2), -3);
+        assert_eq!(RoundFloor::div(-7, 2), -4);
+        assert_eq!(RoundCeil::div(-7, 2), -3);
+        assert_eq!(RoundCeil::div(7, 2), 4);
+        assert_eq!(RoundHalfAway::div(-7, 2), -4);
+        assert_eq!(RoundHalfAway::div(5, 3), 2);
+        assert_eq!(RoundHalfEven::div(5, 2), 2);
+        assert_eq!(RoundHalfEven::div(7, 2), 4);
+        assert_eq!(RoundHalfEven::div(-5, 2), -2);
+    }
+
+    #[test]
+    fn test_var_integer() {
+        assert_eq!(Var(10_i32) * ThreeHalves::new(), Var(15));
+        assert_eq!(Var(5_i64) * ThreeQuarters::new(), Var(4));
+        assert_eq!(Var(9_i8) / FiveNinths::new(), Var(16));
+        assert_eq!(FiveNinths::new() * Var(-9_i16), Var(-5));
+        assert_eq!(Var(-7_i32).div_const::<Const<2>, RoundFloor>(), Var(-4));
+        assert_eq!(Var(100_i8).checked_mul_const::<Const<2>, RoundFloor>(), None);
+        assert_eq!(Var(1_i32).checked_div_const::<Z0, RoundFloor>(), None);
+    }
+
+    #[test]
+    fn test_var_float() {
+        assert_eq!(Var(2.0_f64) * ThreeQuarters::new(), Var(1.5));
+        assert_eq!(Var(1.0_f32) / <Float<P1, N1>>::new(), Var(2.0));
+        assert!(((Var(212.0_f64 - 32.0) * FiveNinths::new()).0 - 100.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_scale_f64() {
+        assert_eq!(180_i32.checked_scale_f64::<RoundHalfAway>(core::f64::consts::PI / 180.0), Some(3));
+        assert_eq!(10_i32.checked_scale_f64::<RoundFloor>(-0.25), Some(-3));
+        assert_eq!(100_i8.checked_scale_f64::<RoundHalfAway>(2.0), None);
+        assert_eq!(2.0_f64.checked_scale_f64::<RoundHalfAway>(0.5), Some(1.0));
+    }
+
+    #[test]
+    fn test_scale_offset() {
+        assert_eq!(20_i32.checked_scale_offset::<RoundHalfAway>(9, 5, 160), Some(68));
+        assert_eq!(1_i32.checked_scale_offset::<RoundHalfAway>(1, 2, 0), Some(1));
+        assert_eq!(1_i32.checked_scale_offset::<RoundFloor>(-1, -2, 0), Some(0));
+        assert_eq!(100_i8.checked_scale_offset::<RoundHalfAway>(9, 5, 160), None);
+        assert_eq!(2_i32.checked_scale_offset::<RoundHalfAway>(1, 0, 0), None);
+        assert_eq!(20.0_f64.checked_scale_offset::<RoundHalfAway>(9, 5, 160), Some(68.0));
+    }
+}
@@ -10,6 +10,7 @@ pub use cmp::*;
 
 mod types;
 pub(super) use types::*;
+pub use types::{Var, FixedPoint, Float, Rational};
 
 mod method;
 pub use method::*;
@@ -36,6 +37,14 @@ pub use to_int::Const;
 mod standardization;
 pub use standardization::*;
 
+// 类型级常量的精确值与Var的精确缩放
+mod exact;
+pub use exact::*;
+
+// 类型级长度的定长数组
+mod array;
+pub use array::*;
+
 // 自定义加一减一运算
 mod add1;
 pub use add1::*;
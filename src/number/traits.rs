@@ -42,6 +42,10 @@ pub trait NonSpecial: TypedNum {}
 /// - Must be copyable and have 'static lifetime
 /// - Provides conversion to i32 (temporary until Var is implemented)
 pub trait TypedInt: TypedNum + Copy + 'static {
+    /// 类型级整数的i32值（编译期常量）
+    /// The i32 value of the type-level integer (compile-time constant)
+    const I32: i32;
+
     /// 将类型级整数转换为运行时i32值
     /// Converts the type-level integer to a runtime i32 value
     ///
@@ -220,6 +224,8 @@ impl<H: NonZero> NonZero for B1<H> {}  // B1保持非零属性 | B1 preserves no
 
 // Z0表示0 | Z0 represents 0
 impl TypedInt for Z0 {
+    const I32: i32 = 0;
+
     #[inline(always)]
     fn to_i32() -> i32 {
         0
@@ -228,6 +234,8 @@ impl TypedInt for Z0 {
 
 // P1表示+1 | P1 represents +1
 impl TypedInt for P1 {
+    const I32: i32 = 1;
+
     #[inline(always)]
     fn to_i32() -> i32 {
         1
@@ -236,6 +244,8 @@ impl TypedInt for P1 {
 
 // N1表示-1 | N1 represents -1
 impl TypedInt for N1 {
+    const I32: i32 = -1;
+
     #[inline(always)]
     fn to_i32() -> i32 {
         -1
@@ -244,17 +254,21 @@ impl TypedInt for N1 {
 
 // B0<H>表示H * 2 | B0<H> represents H * 2
 impl<H: NonZero> TypedInt for B0<H> {
+    const I32: i32 = H::I32 * 2;
+
     #[inline(always)]
     fn to_i32() -> i32 {
-        H::to_i32() * 2
+        Self::I32
     }
 }
 
 // B1<H>表示H * 2 + 1 | B1<H> represents H * 2 + 1
 impl<H: NonZero> TypedInt for B1<H> {
+    const I32: i32 = H::I32 * 2 + 1;
+
     #[inline(always)]
     fn to_i32() -> i32 {
-        H::to_i32() * 2 + 1
+        Self::I32
     }
 }
 
@@ -127,6 +127,19 @@ impl<Significand, Exponent> Default for Float<Significand, Exponent> {
     }
 }
 
+/// **类型级有理数（分子 / 分母）**
+/// - `Num`: 分子（二进制补码表示）
+/// - `Den`: 分母（非零，二进制补码表示）
+/// - 用于表达无法用二进制小数精确表示的常量，如 5/9
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Rational<Num, Den>(PhantomData<(Num, Den)>);
+
+impl<Num, Den> Default for Rational<Num, Den> {
+    fn default() -> Self {
+        Rational(PhantomData)
+    }
+}
+
 /// **原生数值的包装类型**
 /// - 在自定义类型和原生类型间搭建桥梁
 /// - 支持类型安全的运算符重载
@@ -187,6 +200,13 @@ impl<Significand, Exponent> Float<Significand, Exponent> {
     }
 }
 
+impl<Num, Den> Rational<Num, Den> {
+    #[inline]
+    pub fn new() -> Self {
+        Rational(PhantomData)
+    }
+}
+
 //===============================================
 // Sealed trait 实现（模块私有约束）
 //===============================================
@@ -199,6 +219,7 @@ impl<Other> Sealed for B0<Other> {}
 impl<Other> Sealed for B1<Other> {}
 impl<IntPart, FracPart> Sealed for FixedPoint<IntPart, FracPart> {}
 impl<Significand, Exponent> Sealed for Float<Significand, Exponent> {}
+impl<Num, Den> Sealed for Rational<Num, Den> {}
 impl Sealed for Var<i8> {}
 impl Sealed for Var<i16> {}
 impl Sealed for Var<i32> {}
@@ -1,188 +1,226 @@
-/// Angle units / 角度单位
-/// 参考GB 3102.1 1-1 要求
-
-use core::ops::{Add, Sub, AddAssign, SubAssign};
-
-use Crate::quantity::{Dimension, Unit, Si, Z0, P1, N1, Integer, NonZero, NoPrefix, Milli, Micro};
+//! Angle units / 角度单位
+//! 参考GB 3102.1 1-1 要求
+//!
+//! 弧度是带词头的无量纲 `Si`；度与哥恩是以含π的 `Ratio` 缩放的 `Unit`。
+//! 与 SI 一致，这些别名都是无量纲的：弧度显示时不带单位，解析时接受 `rad` 并去掉角度；
+//! 需要与纯比值区分时改用 [`PlaneAngle`](crate::quantity::PlaneAngle)。
+//! Radians are a dimensionless, prefixed `Si`; degrees and gradians are `Unit`s
+//! scaled by a `Ratio` that carries π.
+//! As in the SI these aliases are dimensionless: radians display without a unit and
+//! parsing accepts `rad` and drops the angle; use
+//! [`PlaneAngle`](crate::quantity::PlaneAngle) to keep angles apart from plain ratios.
+
+use crate::number::{Const, Var, Primitive, Scale, Z0, P1, N1};
+use crate::quantity::{Dimension, Unit, Si, Ratio, NoPrefix, Milli, Micro};
 
 // ========== Angle Type Definitions ==========
 // ========== 角度类型定义 ==========
 
+/// 平面角的量纲（无量纲）
+/// Dimension of a plane angle (dimensionless)
+pub type AngleDimension = Dimension<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+
+/// 度的比例因子：π/180 = π * 5^-1 * 3^-2 * 2^-2
+/// Degree ratio: π/180 = π * 5^-1 * 3^-2 * 2^-2
+pub type DegreeRatio = Ratio<N1, P1, Const<-2>, Const<-2>>;
+
+/// 哥恩的比例因子：π/200 = π * 5^-2 * 2^-3
+/// Gradian ratio: π/200 = π * 5^-2 * 2^-3
+pub type GradianRatio = Ratio<Const<-2>, P1, Z0, Const<-3>>;
+
 /// Generic angle in radians with SI prefixes / 带SI前缀的通用弧度角度
-/// 
+///
 /// # Type Parameters / 类型参数
 /// - `V`: Value type (f32, f64, etc.) / 值类型 (如 f32, f64 等)
 /// - `Pr`: SI prefix type (NoPrefix, Milli, Micro, etc.) / SI前缀类型 (无前缀, 毫, 微等)
-pub type Angle<V = P1, Pr = NoPrefix> = Si<V, Dimension<Z0, Z0, Z0, Z0, Z0, Z0, Z0>, Pr>;
+pub type Angle<V = f64, Pr = NoPrefix> = Si<Var<V>, AngleDimension, Pr>;
 
 /// Radians / 弧度
-/// 
+///
 /// # Type Parameters / 类型参数
 /// - `V`: Value type (f32, f64, etc.) / 值类型 (如 f32, f64 等)
 pub type Radians<V = f64> = Angle<V, NoPrefix>;
 
 /// Milliradians / 毫弧度
-/// 
+///
 /// # Type Parameters / 类型参数
 /// - `V`: Value type (f32, f64, etc.) / 值类型 (如 f32, f64 等)
-pub type Milliradians<V> = Angle<V, Milli>;
+pub type Milliradians<V = f64> = Angle<V, Milli>;
 
 /// Microradians / 微弧度
-/// 
+///
 /// # Type Parameters / 类型参数
 /// - `V`: Value type (f32, f64, etc.) / 值类型 (如 f32, f64 等)
-pub type Microradians<V> = Angle<V, Micro>;
+pub type Microradians<V = f64> = Angle<V, Micro>;
 
-/// Degrees angle / 度 必须改？？？？？？？？？？？？？？？？？？？？？？？？？？？？？？？？？？？？？、
-/// 
+/// Degrees angle / 度
+///
 /// # Type Parameters / 类型参数
-/// - `V`: Value type that implements Copy (f32, f64, etc.) / 实现了Copy的值类型 (如 f32, f64 等)
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Degrees<V: Copy>(pub V);
-
-impl<V: Copy> Degrees<V> {
-    /// Create new angle in degrees / 创建新的角度值(度)
-    /// 
-    /// # Arguments / 参数
-    /// - `degrees`: Angle value in degrees / 角度值(度)
-    /// 
-    /// # Returns / 返回值
-    /// New Degrees instance / 新的度实例
-    pub fn new(degrees: V) -> Self {
-        Self(degrees)
-    }
-
-    /// Extract inner value / 解构获取内部值
-    /// 
-    /// # Returns / 返回值
-    /// The contained angle value / 包含的角度值
-    pub fn into_inner(self) -> V {
-        self.0
-    }
-}
+/// - `V`: Value type (f32, f64, etc.) / 值类型 (如 f32, f64 等)
+pub type Degrees<V = f64> = Unit<Radians<V>, DegreeRatio>;
 
 /// Gradians (gons) angle / 哥恩(百分度)
-/// 
+///
 /// # Type Parameters / 类型参数
-/// - `V`: Value type that implements Copy (f32, f64, etc.) / 实现了Copy的值类型 (如 f32, f64 等)
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct Gradians<V: Copy>(pub V);
-
-impl<V: Copy> Gradians<V> {
-    /// Create new angle in gradians / 创建新的角度值(哥恩)
-    /// 
-    /// # Arguments / 参数
-    /// - `gradians`: Angle value in gradians / 角度值(哥恩)
-    /// 
-    /// # Returns / 返回值
-    /// New Gradians instance / 新的哥恩实例
-    pub fn new(gradians: V) -> Self {
-        Self(gradians)
+/// - `V`: Value type (f32, f64, etc.) / 值类型 (如 f32, f64 等)
+pub type Gradians<V = f64> = Unit<Radians<V>, GradianRatio>;
+
+// ========== Conversion Implementations ==========
+// ========== 转换实现 ==========
+// 度、哥恩与弧度之间的 `From` 只用于浮点存储（弧度与度、哥恩之间由通用的 `Unit`/`Si`
+// 换算提供），整数存储请使用下列 `to_*` 方法
+// `From` between degrees, gradians and radians is for floating-point storage only
+// (radians to and from degrees and gradians come from the generic `Unit`/`Si`
+// conversions); use the `to_*` methods below for integer storage
+
+impl<V: Primitive + Scale> Radians<V> {
+    /// Convert radians to degrees / 弧度转度
+    ///
+    /// Formula: deg = rad * (180/π) / 公式: deg = rad * (180/π)
+    pub fn to_degrees(self) -> Degrees<V> {
+        self.to_unit()
     }
 
-    /// Extract inner value / 解构获取内部值
-    /// 
-    /// # Returns / 返回值
-    /// The contained angle value / 包含的角度值
-    pub fn into_inner(self) -> V {
-        self.0
+    /// Convert radians to gradians / 弧度转哥恩
+    ///
+    /// Formula: gon = rad * (200/π) / 公式: gon = rad * (200/π)
+    pub fn to_gradians(self) -> Gradians<V> {
+        self.to_unit()
     }
-}
 
-// ========== Conversion Implementations ==========
-// ========== 转换实现 ==========
+    /// Convert radians to milliradians / 弧度转毫弧度
+    pub fn to_milliradians(self) -> Milliradians<V> {
+        self.convert_to()
+    }
 
-// Degrees <-> Radians conversions
-impl<V: Copy + From<f64> + Into<f64>> From<Degrees<V>> for Radians<V> {
-    /// Convert degrees to radians / 度转弧度
-    /// 
-    /// Formula: rad = deg * (π/180) / 公式: rad = deg * (π/180)
-    fn from(deg: Degrees<V>) -> Self {
-        Radians::new(V::from(deg.0.into() * std::f64::consts::PI / 180.0))
+    /// Convert radians to microradians / 弧度转微弧度
+    pub fn to_microradians(self) -> Microradians<V> {
+        self.convert_to()
     }
 }
 
-impl<V: Copy + From<f64> + Into<f64>> From<Radians<V>> for Degrees<V> {
-    /// Convert radians to degrees / 弧度转度
-    /// 
-    /// Formula: deg = rad * (180/π) / 公式: deg = rad * (180/π)
-    fn from(rad: Radians<V>) -> Self {
-        Degrees::new(V::from(rad.into_inner().into() * 180.0 / std::f64::consts::PI))
+impl<V: Primitive + Scale> Milliradians<V> {
+    /// Convert milliradians to radians / 毫弧度转弧度
+    pub fn to_radians(self) -> Radians<V> {
+        self.convert_to()
     }
 }
 
-// Gradians <-> Radians conversions
-impl<V: Copy + From<f64> + Into<f64>> From<Gradians<V>> for Radians<V> {
-    /// Convert gradians to radians / 哥恩转弧度
-    /// 
-    /// Formula: rad = gon * (π/200) / 公式: rad = gon * (π/200)
-    fn from(gon: Gradians<V>) -> Self {
-        Radians::new(V::from(gon.0.into() * std::f64::consts::PI / 200.0))
+impl<V: Primitive + Scale> Microradians<V> {
+    /// Convert microradians to radians / 微弧度转弧度
+    pub fn to_radians(self) -> Radians<V> {
+        self.convert_to()
     }
 }
 
-impl<V: Copy + From<f64> + Into<f64>> From<Radians<V>> for Gradians<V> {
-    /// Convert radians to gradians / 弧度转哥恩
-    /// 
-    /// Formula: gon = rad * (200/π) / 公式: gon = rad * (200/π)
-    fn from(rad: Radians<V>) -> Self {
-        Gradians::new(V::from(rad.into_inner().into() * 200.0 / std::f64::consts::PI))
+impl<V: Primitive + Scale> Degrees<V> {
+    /// Convert degrees to radians / 度转弧度
+    ///
+    /// Formula: rad = deg * (π/180) / 公式: rad = deg * (π/180)
+    pub fn to_radians(self) -> Radians<V> {
+        self.to_si()
     }
-}
 
-// Degrees <-> Gradians conversions
-impl<V: Copy + From<f64> + Into<f64>> From<Degrees<V>> for Gradians<V> {
     /// Convert degrees to gradians / 度转哥恩
-    /// 
+    ///
     /// Formula: gon = deg * (10/9) / 公式: gon = deg * (10/9)
-    fn from(deg: Degrees<V>) -> Self {
-        Gradians::new(V::from(deg.0.into() * 10.0 / 9.0))
+    pub fn to_gradians(self) -> Gradians<V> {
+        self.convert_to()
     }
 }
 
-impl<V: Copy + From<f64> + Into<f64>> From<Gradians<V>> for Degrees<V> {
+impl<V: Primitive + Scale> Gradians<V> {
+    /// Convert gradians to radians / 哥恩转弧度
+    ///
+    /// Formula: rad = gon * (π/200) / 公式: rad = gon * (π/200)
+    pub fn to_radians(self) -> Radians<V> {
+        self.to_si()
+    }
+
     /// Convert gradians to degrees / 哥恩转度
-    /// 
+    ///
     /// Formula: deg = gon * (9/10) / 公式: deg = gon * (9/10)
-    fn from(gon: Gradians<V>) -> Self {
-        Degrees::new(V::from(gon.0.into() * 9.0 / 10.0))
+    pub fn to_degrees(self) -> Degrees<V> {
+        self.convert_to()
     }
 }
 
-// Milliradians <-> Radians conversions
-impl<V: Copy + From<f64> + Into<f64>> From<Milliradians<V>> for Radians<V> {
-    /// Convert milliradians to radians / 毫弧度转弧度
-    /// 
-    /// Formula: rad = mrad / 1000 / 公式: rad = mrad / 1000
-    fn from(mrad: Milliradians<V>) -> Self {
-        mrad.convert_to::<NoPrefix>()
-    }
+macro_rules! impl_float_angle_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<Degrees<$t>> for Gradians<$t> {
+                fn from(deg: Degrees<$t>) -> Self {
+                    deg.to_gradians()
+                }
+            }
+
+            impl From<Gradians<$t>> for Degrees<$t> {
+                fn from(gon: Gradians<$t>) -> Self {
+                    gon.to_degrees()
+                }
+            }
+
+            impl From<Milliradians<$t>> for Radians<$t> {
+                fn from(mrad: Milliradians<$t>) -> Self {
+                    mrad.to_radians()
+                }
+            }
+
+            impl From<Radians<$t>> for Milliradians<$t> {
+                fn from(rad: Radians<$t>) -> Self {
+                    rad.to_milliradians()
+                }
+            }
+
+            impl From<Microradians<$t>> for Radians<$t> {
+                fn from(urad: Microradians<$t>) -> Self {
+                    urad.to_radians()
+                }
+            }
+
+            impl From<Radians<$t>> for Microradians<$t> {
+                fn from(rad: Radians<$t>) -> Self {
+                    rad.to_microradians()
+                }
+            }
+        )*
+    };
 }
 
-impl<V: Copy + From<f64> + Into<f64>> From<Radians<V>> for Milliradians<V> {
-    /// Convert radians to milliradians / 弧度转毫弧度
-    /// 
-    /// Formula: mrad = rad * 1000 / 公式: mrad = rad * 1000
-    fn from(rad: Radians<V>) -> Self {
-        rad.convert_to::<Milli>()
+impl_float_angle_from!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::PI;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-12
     }
-}
 
-// Microradians <-> Radians conversions
-impl<V: Copy + From<f64> + Into<f64>> From<Microradians<V>> for Radians<V> {
-    /// Convert microradians to radians / 微弧度转弧度
-    /// 
-    /// Formula: rad = μrad / 1_000_000 / 公式: rad = μrad / 1_000_000
-    fn from(μrad: Microradians<V>) -> Self {
-        μrad.convert_to::<NoPrefix>()
+    #[test]
+    fn test_degrees_radians() {
+        let right: Degrees = Degrees::new(90.0);
+        let rad: Radians = right.into();
+        assert!(close(rad.0 .0, PI / 2.0));
+
+        let back: Degrees = rad.into();
+        assert!(close((back.0).0 .0, 90.0));
     }
-}
 
-impl<V: Copy + From<f64> + Into<f64>> From<Radians<V>> for Microradians<V> {
-    /// Convert radians to microradians / 弧度转微弧度
-    /// 
-    /// Formula: μrad = rad * 1_000_000 / 公式: μrad = rad * 1_000_000
-    fn from(rad: Radians<V>) -> Self {
-        rad.convert_to::<Micro>()
+    #[test]
+    fn test_gradians() {
+        let gon: Gradians = Gradians::new(100.0);
+        assert!(close(gon.to_radians().0 .0, PI / 2.0));
+        assert!(close((gon.to_degrees().0).0 .0, 90.0));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_prefixed_radians() {
+        let rad: Radians = Si::new(0.5);
+        let mrad: Milliradians = rad.into();
+        assert!(close(mrad.0 .0, 500.0));
+        let urad: Microradians = rad.into();
+        assert!(close(urad.0 .0, 500_000.0));
+    }
+}
@@ -1,2 +1,8 @@
-//mod angle;
-//pub use angle::*;
\ No newline at end of file
+//! 常用单位别名
+//! Common unit aliases
+
+mod angle;
+pub use angle::*;
+
+mod time;
+pub use time::*;
@@ -1,71 +1,172 @@
-/// Time (second) / 时间 (秒)
-use super::{Quantity, prefix::*};
-use crate::dimension::Second;
+//! Time (second) / 时间 (秒)
+//!
+//! 秒是带词头的 `Si`；分钟、小时、天是以精确 `Ratio` 缩放的 `Unit`，
+//! 整数存储时的换算没有舍入误差。
+//! Seconds are a prefixed `Si`; minutes, hours and days are `Unit`s scaled by an
+//! exact `Ratio`, so conversions on integer storage are free of rounding.
+
+use core::ops::Mul;
+
+use crate::number::{Const, Var, Primitive, Scale, Z0, P1};
+use crate::quantity::{Dimension, Unit, Si, RatioOf, NoPrefix, Kilo, Milli, Micro, Nano};
+
+// ========== 时间单位定义 ==========
+// ========== Time Unit Ratios ==========
+
+/// 时间的量纲
+/// Dimension of time
+pub type TimeDimension = Dimension<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+
+/// 分钟 (60秒) = 5^1 * 3^1 * 2^2
+/// Minute (60 s) = 5^1 * 3^1 * 2^2
+pub type Minute = RatioOf<Const<60>>;
+
+/// 小时 (3600秒) = 5^2 * 3^2 * 2^4
+/// Hour (3600 s) = 5^2 * 3^2 * 2^4
+pub type Hour = RatioOf<<Const<60> as Mul<Const<60>>>::Output>;
+
+/// 天 (86400秒) = 5^2 * 3^3 * 2^7
+/// Day (86400 s) = 5^2 * 3^3 * 2^7
+pub type Day = RatioOf<<<Const<60> as Mul<Const<60>>>::Output as Mul<Const<24>>>::Output>;
+
+// ========== 时间类型定义 ==========
+// ========== Time Type Definitions ==========
 
 /// Generic time with SI prefixes / 带SI前缀的通用时间
-pub type Time<V, P = NoPrefix> = Quantity<V, SI<P, Dimension<Z0, Z0, P1, Z0, Z0, Z0, Z0>>>;
+pub type Time<V = i64, Pr = NoPrefix> = Si<Var<V>, TimeDimension, Pr>;
 
 /// Seconds / 秒
 pub type Seconds<V = i64> = Time<V, NoPrefix>;
-impl<V> Seconds<V> {    
-    /// Create from days, hours, minutes and seconds
-    pub fn from_dhms(days: V, hours: V, minutes: V, seconds: V) -> Self{        
-        Seconds::new(seconds+minutes*60+hours*3600+days*86400)
-    }
-}
 
-/// kiloseconds / 千秒
-pub type Kiloseconds<V> = Quantity<V, Kilo, Second>;
+/// Kiloseconds / 千秒
+pub type Kiloseconds<V = i64> = Time<V, Kilo>;
 
 /// Milliseconds / 毫秒
-pub type Milliseconds<V> = Time<V, Milli>;
+pub type Milliseconds<V = i64> = Time<V, Milli>;
 
 /// Microseconds / 微秒
-pub type Microseconds<V> = Time<V, Micro>;
+pub type Microseconds<V = i64> = Time<V, Micro>;
 
 /// Nanoseconds / 纳秒
-pub type Nanoseconds<V> = Time<V, Nano>;
+pub type Nanoseconds<V = i64> = Time<V, Nano>;
+
 /// Minutes / 分钟
-pub type Minutes<V = i64> = Time<V, Minute>;
+pub type Minutes<V = i64> = Unit<Seconds<V>, Minute>;
 
 /// Hours / 小时
-pub type Hours<V = i64> = Time<V, Hour>;
+pub type Hours<V = i64> = Unit<Seconds<V>, Hour>;
 
 /// Days / 天
-pub type Days<V = i64> = Time<V, Day>;
-
+pub type Days<V = i64> = Unit<Seconds<V>, Day>;
+
+// ========== 构造 ==========
+// ========== Construction ==========
+
+macro_rules! impl_from_dhms {
+    ($($t:ty),*) => {
+        $(
+            impl Seconds<$t> {
+                /// Create from days, hours, minutes and seconds
+                /// 由天、时、分、秒构造
+                ///
+                /// # Panics
+                /// 结果溢出时 panic（调试与发布模式均检查）
+                /// Panics if the result overflows (checked in both debug and release builds)
+                pub fn from_dhms(days: $t, hours: $t, minutes: $t, seconds: $t) -> Self {
+                    let total = days.checked_mul(86400)
+                        .and_then(|d| hours.checked_mul(3600).and_then(|h| d.checked_add(h)))
+                        .and_then(|t| minutes.checked_mul(60).and_then(|m| t.checked_add(m)))
+                        .and_then(|t| t.checked_add(seconds))
+                        .expect("time overflows the storage type");
+                    Seconds::new(total)
+                }
+            }
+        )*
+    };
+}
 
+impl_from_dhms!(i32, i64, i128, isize);
+
+// ========== 转换 ==========
+// ========== Conversions ==========
+
+// 委托给泛型的 `to_si` / `to_unit`，取整方式与 `convert_to` 一致
+// Delegates to the generic `to_si` / `to_unit`, so rounding matches `convert_to`
+macro_rules! impl_time_conversions {
+    ($($unit:ident, $ratio:ty, $to:ident);*) => {
+        $(
+            impl<V: Primitive + Scale> $unit<V> {
+                /// 换算为秒（整数四舍五入，溢出时 panic），同 [`to_si`](Unit::to_si)
+                /// Converts to seconds (integers rounded half away from zero; panics on overflow), as [`to_si`](Unit::to_si)
+                pub fn to_seconds(self) -> Seconds<V> {
+                    self.to_si()
+                }
+
+                /// 由秒换算（整数四舍五入，溢出时 panic），同 [`to_unit`](Si::to_unit)
+                /// Converts from seconds (integers rounded half away from zero; panics on overflow), as [`to_unit`](Si::to_unit)
+                pub fn from_seconds(seconds: Seconds<V>) -> Self {
+                    seconds.to_unit::<$ratio>()
+                }
+            }
+
+            impl<V: Primitive + Scale> Seconds<V> {
+                /// 换算为该单位（整数四舍五入，溢出时 panic）
+                /// Converts to this unit (integers rounded half away from zero; panics on overflow)
+                pub fn $to(self) -> $unit<V> {
+                    $unit::from_seconds(self)
+                }
+            }
+        )*
+    };
+}
 
+impl_time_conversions!(
+    Minutes, Minute, to_minutes;
+    Hours, Hour, to_hours;
+    Days, Day, to_days
+);
 
-/// 处理时间单位的符号
-    fn time_symbol() -> Option<&'static str> {
-        type One = Ratio<Z0, Z0, Z0>;
-        type Minute = Ratio<P1, P1, P2>;  // 60 = 5^1 * 3^1 * 2^2
-        type Hour = Ratio<P2, P2, P2>;    // 3600 = 5^2 * 3^2 * 2^2
-        type Day = Ratio<P2, P3, P7>;     // 86400 = 5^2 * 3^3 * 2^7
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        if TypeId::of::<Self>() == TypeId::of::<Minute>() {
-            Some("min")
-        } else if TypeId::of::<Self>() == TypeId::of::<Hour>() {
-            Some("h")
-        } else if TypeId::of::<Self>() == TypeId::of::<Day>() {
-            Some("d")
-        } else if TypeId::of::<Self>() == TypeId::of::<One>() {
-            Some("")
-        } else {
-            None
-        }
+    #[test]
+    fn test_from_dhms() {
+        let t = Seconds::<i64>::from_dhms(1, 2, 3, 4);
+        assert_eq!(t.0 .0, 86400 + 7200 + 180 + 4);
     }
 
+    #[test]
+    fn test_exact_conversions() {
+        let h: Hours = Hours::new(2);
+        let s: Seconds = h.to_si();
+        assert_eq!(s.0 .0, 7200);
+        assert_eq!((s.to_minutes().0).0 .0, 120);
+        // 与 convert_to 一样四舍五入 / Rounded half away from zero, like convert_to
+        assert_eq!((Seconds::<i64>::new(100).to_minutes().0).0 .0, 2);
+        assert_eq!((Seconds::<i64>::new(89).to_minutes().0).0 .0, 1);
+        let min: Minutes = Seconds::<i64>::new(100).to_unit();
+        assert_eq!((min.0).0 .0, 2);
+        assert_eq!((Seconds::<i64>::from_dhms(3, 0, 0, 0).to_days().0).0 .0, 3);
+
+        let d: Days<f64> = Days::new(0.5);
+        assert_eq!(d.to_seconds().0 .0, 43200.0);
+    }
 
-    // ========== 时间单位定义 ==========
-
-/// 分钟 (60秒) = 5^1 * 3^1 * 2^2
-pub type Minute = Ratio<P1, P1, P2>;
-
-/// 小时 (3600秒) = 5^2 * 3^2 * 2^2
-pub type Hour = Ratio<P2, P2, P2>;
-
-/// 天 (86400秒) = 5^2 * 3^3 * 2^7
-pub type Day = Ratio<P2, P3, P7>;
-
+    #[test]
+    fn test_mixed_ratio_arithmetic() {
+        let total: Minutes = Hours::new(1) + Minutes::new(30);
+        assert_eq!((total.0).0 .0, 90);
+        let rest: Minutes = Hours::new(1) - Minutes::new(30);
+        assert_eq!((rest.0).0 .0, 30);
+
+        // 与秒相加时公共比例为1，结果退化为 `Si`
+        // With seconds the common ratio is 1, so the result collapses to `Si`
+        let s: Seconds = Minutes::new(2) + Seconds::new(5);
+        assert_eq!(s.0 .0, 125);
+        let ms: Milliseconds = Seconds::new(1) - Minutes::new(1) + Milliseconds::new(1);
+        assert_eq!(ms.0 .0, -58_999);
+        let d: Hours = Days::new(1) + Hours::new(1);
+        assert_eq!((d.0).0 .0, 25);
+    }
+}
@@ -0,0 +1,356 @@
+//! 可选的角度伪量纲
+//! Optional angle pseudo-dimension
+//!
+//! SI 把弧度与球面度视为无量纲，因此 `rad`、`sr` 与纯比值的类型相同，可以被悄悄相加。
+//! [`WithAngle<D, A>`] 在量纲 `D` 之外附加角度指数 `A`：平面角为 rad¹，立体角为
+//! rad²（即 sr），计数等纯比值不带角度。乘除时角度指数相加减，指数归零时自动还原为
+//! 普通的 [`Dimension`]，因此 rad/rad 是纯比值、rad·m 是不同于 m 的弧长，
+//! 只有显式调用 [`without_angle`](Si::without_angle) 才会去掉角度。
+//! 原有的 [`Radian`](super::si_units::Radian) 等别名仍是无量纲的（解析时接受 `rad`、`sr`
+//! 并去掉角度），本模块是按需选用的。
+//! The SI treats the radian and the steradian as dimensionless, so `rad`, `sr` and a
+//! plain ratio share one type and can be added silently. [`WithAngle<D, A>`] attaches
+//! an angle exponent `A` to the dimension `D`: a plane angle is rad¹, a solid angle
+//! rad² (that is, sr), and counts and other plain ratios carry no angle. Multiplication
+//! and division add and subtract the angle exponent, and an exponent of zero collapses
+//! back to a plain [`Dimension`], so rad/rad is a plain ratio and rad·m an arc length
+//! distinct from m that only drops its angle through an explicit
+//! [`without_angle`](Si::without_angle). The existing aliases such as
+//! [`Radian`](super::si_units::Radian) stay dimensionless (parsing accepts `rad` and
+//! `sr` into them and drops the angle); this module is opt-in.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{ArcLength, Count, PlaneAngle, Si};
+//! use unitrix::quantity::si_units::Meter;
+//!
+//! let theta: PlaneAngle = Si::new(0.5);
+//! let arc: ArcLength = theta * <Meter>::new(2.0);
+//! assert_eq!(format!("{}", arc), "1 m·rad");
+//!
+//! let m: Meter = arc.without_angle();
+//! assert_eq!(m.0 .0, 1.0);
+//!
+//! let ratio: Count = theta / <PlaneAngle>::new(0.25);
+//! assert_eq!(ratio.into_inner(), 2.0);
+//! ```
+//!
+//! 角度与纯比值相加无法编译：
+//! Adding an angle to a plain ratio does not compile:
+//! ```compile_fail
+//! use unitrix::quantity::{Count, PlaneAngle, Si};
+//!
+//! let _ = <PlaneAngle>::new(1.0) + <Count>::new(1.0);
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{Add, Sub, Mul, Div, Neg};
+
+use crate::sealed::Sealed;
+use crate::number::{TypedInt, NonZero, Primitive, Var, Const, Z0, P1, N1, B0, B1};
+use super::{Dimension, Dimensional, Extended, Prefixed, Si, NoPrefix};
+use super::si_units::{Dimensionless, LengthDimension};
+
+// ========== 带角度的量纲 ==========
+// ========== Dimension with an Angle ==========
+
+/// 附加角度指数 `A` 的量纲 `D`
+/// The dimension `D` with the angle exponent `A` attached
+///
+/// 通常不直接书写，而是由 [`PlaneAngle`] 等别名与乘除运算得到；`A` 为零时运算结果
+/// 还原为 `D` 本身。
+/// Usually not written directly but obtained from aliases such as [`PlaneAngle`] and
+/// from multiplication and division; results with a zero `A` collapse to `D` itself.
+#[derive(Debug, Clone, Copy)]
+pub struct WithAngle<D, A>(D, PhantomData<A>);
+
+impl<D: Dimensional, A: TypedInt> Sealed for WithAngle<D, A> {}
+
+impl<D: Dimensional, A: TypedInt> Dimensional for WithAngle<D, A> {
+    const EXPONENTS: [i32; 7] = D::EXPONENTS;
+    const ANGLE: i32 = D::ANGLE + A::I32;
+    const EXTRA_EXPONENTS: [i32; 4] = D::EXTRA_EXPONENTS;
+}
+
+/// 给量纲附加角度指数，指数为零时不附加
+/// Attaches an angle exponent to a dimension; a zero exponent attaches nothing
+pub trait AttachAngle<D: Dimensional>: TypedInt {
+    /// 结果量纲 / Resulting dimension
+    type Output: Dimensional;
+
+    /// 附加角度 / Attaches the angle
+    fn attach(dimension: D) -> Self::Output;
+}
+
+impl<D: Dimensional> AttachAngle<D> for Z0 {
+    type Output = D;
+
+    fn attach(dimension: D) -> D {
+        dimension
+    }
+}
+
+impl<D: Dimensional> AttachAngle<D> for P1 {
+    type Output = WithAngle<D, P1>;
+
+    fn attach(dimension: D) -> Self::Output {
+        WithAngle(dimension, PhantomData)
+    }
+}
+
+impl<D: Dimensional> AttachAngle<D> for N1 {
+    type Output = WithAngle<D, N1>;
+
+    fn attach(dimension: D) -> Self::Output {
+        WithAngle(dimension, PhantomData)
+    }
+}
+
+impl<D: Dimensional, H: NonZero> AttachAngle<D> for B0<H> {
+    type Output = WithAngle<D, B0<H>>;
+
+    fn attach(dimension: D) -> Self::Output {
+        WithAngle(dimension, PhantomData)
+    }
+}
+
+impl<D: Dimensional, H: NonZero> AttachAngle<D> for B1<H> {
+    type Output = WithAngle<D, B1<H>>;
+
+    fn attach(dimension: D) -> Self::Output {
+        WithAngle(dimension, PhantomData)
+    }
+}
+
+// ========== 运算 ==========
+// ========== Arithmetic ==========
+
+impl<D1, A1, D2, A2> Mul<WithAngle<D2, A2>> for WithAngle<D1, A1>
+where
+    D1: Mul<D2, Output: Dimensional>,
+    A1: Add<A2, Output: AttachAngle<<D1 as Mul<D2>>::Output>>,
+{
+    type Output = <<A1 as Add<A2>>::Output as AttachAngle<<D1 as Mul<D2>>::Output>>::Output;
+
+    fn mul(self, rhs: WithAngle<D2, A2>) -> Self::Output {
+        <A1 as Add<A2>>::Output::attach(self.0 * rhs.0)
+    }
+}
+
+impl<D1, A1, D2, A2> Div<WithAngle<D2, A2>> for WithAngle<D1, A1>
+where
+    D1: Div<D2, Output: Dimensional>,
+    A1: Sub<A2, Output: AttachAngle<<D1 as Div<D2>>::Output>>,
+{
+    type Output = <<A1 as Sub<A2>>::Output as AttachAngle<<D1 as Div<D2>>::Output>>::Output;
+
+    fn div(self, rhs: WithAngle<D2, A2>) -> Self::Output {
+        <A1 as Sub<A2>>::Output::attach(self.0 / rhs.0)
+    }
+}
+
+impl<D1, A1, M, KG, S, A, K, MOL, CD> Mul<Dimension<M, KG, S, A, K, MOL, CD>> for WithAngle<D1, A1>
+where
+    M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+    D1: Mul<Dimension<M, KG, S, A, K, MOL, CD>, Output: Dimensional>,
+    A1: AttachAngle<<D1 as Mul<Dimension<M, KG, S, A, K, MOL, CD>>>::Output>,
+{
+    type Output = <A1 as AttachAngle<<D1 as Mul<Dimension<M, KG, S, A, K, MOL, CD>>>::Output>>::Output;
+
+    fn mul(self, rhs: Dimension<M, KG, S, A, K, MOL, CD>) -> Self::Output {
+        A1::attach(self.0 * rhs)
+    }
+}
+
+impl<D1, A1, M, KG, S, A, K, MOL, CD> Div<Dimension<M, KG, S, A, K, MOL, CD>> for WithAngle<D1, A1>
+where
+    M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+    D1: Div<Dimension<M, KG, S, A, K, MOL, CD>, Output: Dimensional>,
+    A1: AttachAngle<<D1 as Div<Dimension<M, KG, S, A, K, MOL, CD>>>::Output>,
+{
+    type Output = <A1 as AttachAngle<<D1 as Div<Dimension<M, KG, S, A, K, MOL, CD>>>::Output>>::Output;
+
+    fn div(self, rhs: Dimension<M, KG, S, A, K, MOL, CD>) -> Self::Output {
+        A1::attach(self.0 / rhs)
+    }
+}
+
+impl<M, KG, S, A, K, MOL, CD, D2, A2> Mul<WithAngle<D2, A2>> for Dimension<M, KG, S, A, K, MOL, CD>
+where
+    M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+    Self: Mul<D2, Output: Dimensional>,
+    A2: AttachAngle<<Self as Mul<D2>>::Output>,
+{
+    type Output = <A2 as AttachAngle<<Self as Mul<D2>>::Output>>::Output;
+
+    fn mul(self, rhs: WithAngle<D2, A2>) -> Self::Output {
+        A2::attach(self * rhs.0)
+    }
+}
+
+impl<M, KG, S, A, K, MOL, CD, D2, A2> Div<WithAngle<D2, A2>> for Dimension<M, KG, S, A, K, MOL, CD>
+where
+    M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+    Self: Div<D2, Output: Dimensional>,
+    A2: Neg<Output: AttachAngle<<Self as Div<D2>>::Output>>,
+{
+    type Output = <<A2 as Neg>::Output as AttachAngle<<Self as Div<D2>>::Output>>::Output;
+
+    fn div(self, rhs: WithAngle<D2, A2>) -> Self::Output {
+        <A2 as Neg>::Output::attach(self / rhs.0)
+    }
+}
+
+// 与自定义基本量纲相乘除时角度在外层，即 `WithAngle<Extended<..>, A>`，
+// 使两种相乘顺序得到同一类型
+// With custom base dimensions the angle stays outermost, as in `WithAngle<Extended<..>, A>`,
+// so both orders of multiplication give the same type
+impl<D1, A1, D2, S, E1, E2, E3, E4> Mul<Extended<D2, S, E1, E2, E3, E4>> for WithAngle<D1, A1>
+where
+    D1: Mul<Extended<D2, S, E1, E2, E3, E4>, Output: Dimensional>,
+    A1: AttachAngle<<D1 as Mul<Extended<D2, S, E1, E2, E3, E4>>>::Output>,
+{
+    type Output = <A1 as AttachAngle<<D1 as Mul<Extended<D2, S, E1, E2, E3, E4>>>::Output>>::Output;
+
+    fn mul(self, rhs: Extended<D2, S, E1, E2, E3, E4>) -> Self::Output {
+        A1::attach(self.0 * rhs)
+    }
+}
+
+impl<D1, A1, D2, S, E1, E2, E3, E4> Div<Extended<D2, S, E1, E2, E3, E4>> for WithAngle<D1, A1>
+where
+    D1: Div<Extended<D2, S, E1, E2, E3, E4>, Output: Dimensional>,
+    A1: AttachAngle<<D1 as Div<Extended<D2, S, E1, E2, E3, E4>>>::Output>,
+{
+    type Output = <A1 as AttachAngle<<D1 as Div<Extended<D2, S, E1, E2, E3, E4>>>::Output>>::Output;
+
+    fn div(self, rhs: Extended<D2, S, E1, E2, E3, E4>) -> Self::Output {
+        A1::attach(self.0 / rhs)
+    }
+}
+
+// 角度内层只取规范形式（`Dimension` 或 `Extended`），避免类型推断在嵌套的 `WithAngle` 上递归
+// The angle's inner dimension only takes the canonical forms (`Dimension` or
+// `Extended`), so type inference does not recurse through nested `WithAngle`s
+macro_rules! impl_extended_angle_ops {
+    ($([$($g:tt)*] $inner:ty;)*) => {
+        $(
+            impl<D1, S, E1, E2, E3, E4, A2, $($g)*> Mul<WithAngle<$inner, A2>> for Extended<D1, S, E1, E2, E3, E4>
+            where
+                Self: Mul<$inner, Output: Dimensional>,
+                A2: AttachAngle<<Self as Mul<$inner>>::Output>,
+            {
+                type Output = <A2 as AttachAngle<<Self as Mul<$inner>>::Output>>::Output;
+
+                fn mul(self, rhs: WithAngle<$inner, A2>) -> Self::Output {
+                    A2::attach(self * rhs.0)
+                }
+            }
+
+            impl<D1, S, E1, E2, E3, E4, A2, $($g)*> Div<WithAngle<$inner, A2>> for Extended<D1, S, E1, E2, E3, E4>
+            where
+                Self: Div<$inner, Output: Dimensional>,
+                A2: Neg<Output: AttachAngle<<Self as Div<$inner>>::Output>>,
+            {
+                type Output = <<A2 as Neg>::Output as AttachAngle<<Self as Div<$inner>>::Output>>::Output;
+
+                fn div(self, rhs: WithAngle<$inner, A2>) -> Self::Output {
+                    <A2 as Neg>::Output::attach(self / rhs.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_extended_angle_ops! {
+    [M: TypedInt, KG: TypedInt, SE: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt]
+        Dimension<M, KG, SE, A, K, MOL, CD>;
+    [D2, S2, F1, F2, F3, F4] Extended<D2, S2, F1, F2, F3, F4>;
+}
+
+// ========== 别名 ==========
+// ========== Aliases ==========
+
+/// 平面角的量纲 rad / Dimension of a plane angle, rad
+pub type PlaneAngleDimension = WithAngle<Dimensionless, P1>;
+/// 立体角的量纲 sr = rad² / Dimension of a solid angle, sr = rad²
+pub type SolidAngleDimension = WithAngle<Dimensionless, Const<2>>;
+/// 弧长的量纲 m·rad / Dimension of an arc length, m·rad
+pub type ArcLengthDimension = WithAngle<LengthDimension, P1>;
+
+/// 与纯比值区分的平面角 rad / Plane angle kept apart from plain ratios, rad
+pub type PlaneAngle<V = f64, Pr = NoPrefix> = Si<Var<V>, PlaneAngleDimension, Pr>;
+/// 与纯比值区分的立体角 sr / Solid angle kept apart from plain ratios, sr
+pub type SolidAngle<V = f64, Pr = NoPrefix> = Si<Var<V>, SolidAngleDimension, Pr>;
+/// 计数或其他纯比值 / Count or other plain ratio
+pub type Count<V = f64, Pr = NoPrefix> = Si<Var<V>, Dimensionless, Pr>;
+/// 弧长 m·rad（显式去掉角度后为米）/ Arc length m·rad (metres once the angle is dropped explicitly)
+pub type ArcLength<V = f64, Pr = NoPrefix> = Si<Var<V>, ArcLengthDimension, Pr>;
+
+// ========== 显式转换 ==========
+// ========== Explicit Conversions ==========
+
+impl<T: Primitive, D: Dimensional, A: TypedInt, Pr: Prefixed> Si<Var<T>, WithAngle<D, A>, Pr> {
+    /// 去掉角度，得到SI意义下的量（如 rad → 1、m·rad → m）
+    /// Drops the angle, giving the quantity as the SI sees it (rad → 1, m·rad → m)
+    pub fn without_angle(self) -> Si<Var<T>, D, Pr> {
+        Si(self.0, PhantomData)
+    }
+}
+
+impl<T, M, KG, S, A, K, MOL, CD, Pr> Si<Var<T>, Dimension<M, KG, S, A, K, MOL, CD>, Pr>
+where
+    T: Primitive,
+    M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+    Pr: Prefixed,
+{
+    /// 附加角度指数 `E`（如把无量纲的弧度标记为平面角）
+    /// Attaches the angle exponent `E` (for instance marking a dimensionless radian as a plane angle)
+    pub fn with_angle<E: AttachAngle<Dimension<M, KG, S, A, K, MOL, CD>>>(self) -> Si<Var<T>, E::Output, Pr> {
+        Si(self.0, PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+    use crate::quantity::Milli;
+    use crate::quantity::si_units::{Meter, Radian, Second, FrequencyDimension};
+
+    #[test]
+    fn test_angle_algebra() {
+        let theta: PlaneAngle = Si::new(2.0);
+        let omega: Si<Var<f64>, WithAngle<FrequencyDimension, P1>, NoPrefix> = theta / <Second>::new(4.0);
+        assert_eq!(omega.0 .0, 0.5);
+        let back: PlaneAngle = omega * <Second>::new(2.0);
+        assert_eq!(back.0 .0, 1.0);
+
+        let sr: SolidAngle = theta * theta;
+        assert_eq!(sr.0 .0, 4.0);
+        let per_rad: Si<Var<f64>, WithAngle<Dimensionless, N1>, NoPrefix> = <Count>::new(1.0) / theta;
+        assert_eq!(per_rad.0 .0, 0.5);
+        let count: Count = per_rad * theta;
+        assert_eq!(count.into_inner(), 1.0);
+
+        let arc: ArcLength = <Meter>::new(3.0) * theta;
+        let m: Meter = arc.without_angle();
+        assert_eq!(m.0 .0, 6.0);
+        let rad: Radian = theta.without_angle();
+        let theta2: PlaneAngle = rad.with_angle::<P1>();
+        assert_eq!(theta2.0 .0, 2.0);
+    }
+
+    #[test]
+    fn test_angle_display() {
+        assert_eq!(format!("{}", <PlaneAngle>::new(1.5)), "1.5 rad");
+        assert_eq!(format!("{}", PlaneAngle::<f64, Milli>::new(3.0)), "3 mrad");
+        assert_eq!(format!("{}", <SolidAngle>::new(2.0)), "2 sr");
+        assert_eq!(format!("{}", <ArcLength>::new(1.0)), "1 m·rad");
+        assert_eq!(format!("{}", <PlaneAngle>::new(1.0) / <Second>::new(1.0)), "1 rad·s⁻¹");
+        assert_eq!(format!("{}", <Meter>::new(1.0) / <PlaneAngle>::new(1.0)), "1 m·rad⁻¹");
+        assert_eq!(format!("{}", <Count>::new(3.0)), "3");
+    }
+}
@@ -0,0 +1,723 @@
+// convert.rs
+//! 词头与比例因子之间的换算
+//! Conversions between prefixes and ratios
+//!
+//! 换算系数由类型计算：`Pr / Pr2` 或 `R / R2` 在编译期求得结果类型，再取该类型的
+//! 编译期常量 `EXACT` / `FACTOR_F64` 作为系数。
+//! 不含π的系数以精确分数 `(分子, 分母)` 参与运算，整数存储时只在最后取整一次；
+//! 含π（或超出 `i128` 范围）的系数退化为 `f64`。
+//! The factor is computed from the types: `Pr / Pr2` or `R / R2` is resolved at
+//! compile time and the resulting type's `EXACT` / `FACTOR_F64` constants are the factor. Factors without π are
+//! exact `(num, den)` fractions, so integer storage is rounded only once at the end;
+//! factors involving π (or beyond the range of `i128`) fall back to `f64`.
+//!
+//! 近似比例 [`Approx`](super::Approx) 不参与这些换算，请使用其 `*_approx` 方法。
+//! The approximate [`Approx`](super::Approx) ratios take no part in these conversions;
+//! use their `*_approx` methods instead.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{Hours, Minutes, Seconds, Kilo, Milli, Si};
+//! use unitrix::quantity::si_units::Meter;
+//!
+//! let km: Meter<i64, Kilo> = Si::new(3);
+//! let mm: Meter<i64, Milli> = km.convert_to();
+//! assert_eq!(mm.0 .0, 3_000_000);
+//!
+//! let h: Hours = Hours::new(2);
+//! let min: Minutes = h.convert_to();
+//! assert_eq!((min.0).0 .0, 120);
+//! let s: Seconds = h.to_si();
+//!
+//! // 浮点存储可用 `From` / `into` / Floating-point storage may use `From` / `into`
+//! let rad: unitrix::quantity::Radians = unitrix::quantity::Degrees::new(180.0).into();
+//! assert!((rad.0 .0 - core::f64::consts::PI).abs() < 1e-12);
+//! assert_eq!(s.0 .0, 7200);
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{Mul, Div};
+
+use crate::number::{TypedInt, Primitive, Scale, Rounding, DefaultRounding, Var, reduce};
+use super::ratio::{Ratio, NoRatio, PrimeList, Scaled, RatioGcd, exact_pow, powi_f64};
+use super::measured::Approx;
+use super::labeled::Labeled;
+use super::prefix::{Prefix, Prefixed};
+use super::{Si, Unit, Dimensional, DynError};
+use super::si_units::Dimensionless;
+
+// ========== 换算系数 ==========
+// ========== Conversion Factor ==========
+
+/// 换算系数
+/// Conversion factor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Factor {
+    /// 精确分数 (分子, 分母)，分母为正
+    /// Exact fraction (numerator, denominator) with a positive denominator
+    Exact(i128, i128),
+    /// 浮点近似值
+    /// Floating-point approximation
+    Float(f64),
+}
+
+impl Factor {
+    /// 转换为 f64
+    /// Converts to f64
+    pub fn to_f64(self) -> f64 {
+        match self {
+            Factor::Exact(num, den) => num as f64 / den as f64,
+            Factor::Float(f) => f,
+        }
+    }
+
+    /// 10的 `exp` 次幂；超出 `i128` 时为浮点近似
+    /// 10 to the power `exp`; a floating-point approximation beyond `i128`
+    pub fn power_of_ten(exp: i32) -> Factor {
+        match exact_pow(10, exp) {
+            Some((num, den)) => Factor::Exact(num, den),
+            // 超出 f64 范围时直接得到无穷大或零，不必逐次相乘
+            // Beyond the f64 range the result is infinity or zero without multiplying it out
+            None if exp > 400 => Factor::Float(f64::INFINITY),
+            None if exp < -400 => Factor::Float(0.0),
+            None => Factor::Float(powi_f64(10.0, exp)),
+        }
+    }
+
+    /// 整数次幂，按二进制逐位平方，乘法次数与指数的位数成正比
+    /// Integer power by repeated squaring, so the number of multiplications grows with
+    /// the bit length of the exponent
+    pub fn powi(self, exp: i32) -> Factor {
+        let mut result = Factor::Exact(1, 1);
+        let mut base = self;
+        let mut n = exp.unsigned_abs();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base * base;
+            }
+        }
+        if exp < 0 { result.recip() } else { result }
+    }
+
+    /// 倒数；零的倒数为无穷大
+    /// Reciprocal; the reciprocal of zero is infinity
+    pub fn recip(self) -> Factor {
+        match self {
+            Factor::Exact(num, den) if num > 0 => Factor::Exact(den, num),
+            Factor::Exact(num, den) if num < 0 && num != i128::MIN => Factor::Exact(-den, -num),
+            _ => Factor::Float(1.0 / self.to_f64()),
+        }
+    }
+
+    /// 用该系数缩放数值，整数按 `R` 取整，溢出时返回 `None`
+    /// Scales a value by this factor; integers are rounded with `R`; `None` on overflow
+    pub fn checked_apply<V: Scale, R: Rounding>(self, value: V) -> Option<V> {
+        match self {
+            Factor::Exact(num, den) => value.checked_scale::<R>(num, den),
+            Factor::Float(f) => value.checked_scale_f64::<R>(f),
+        }
+    }
+}
+
+/// 两个系数相乘；精确分数溢出时退化为 f64
+/// Multiplies two factors; exact fractions that overflow fall back to f64
+impl Mul for Factor {
+    type Output = Factor;
+
+    fn mul(self, rhs: Factor) -> Factor {
+        if let (Factor::Exact(n1, d1), Factor::Exact(n2, d2)) = (self, rhs) {
+            // 先交叉约分以减小溢出的可能
+            // Cross-reduce first to make overflow less likely
+            let (n1, d2) = reduce(n1, d2);
+            let (n2, d1) = reduce(n2, d1);
+            if let (Some(num), Some(den)) = (n1.checked_mul(n2), d1.checked_mul(d2)) {
+                return Factor::Exact(num, den);
+            }
+        }
+        Factor::Float(self.to_f64() * rhs.to_f64())
+    }
+}
+
+/// 可由类型给出换算系数的词头或比例
+/// A prefix or ratio whose conversion factor is given by its type
+pub trait Conversion {
+    /// 换算系数 / Conversion factor
+    fn conversion_factor() -> Factor;
+}
+
+impl<E: TypedInt> Conversion for Prefix<E> {
+    fn conversion_factor() -> Factor {
+        match Self::EXACT {
+            Some((num, den)) => Factor::Exact(num, den),
+            None => Factor::Float(Self::FACTOR_F64),
+        }
+    }
+}
+
+// 不含π且未溢出时为精确分数，否则为浮点近似
+// An exact fraction without π and without overflow; otherwise a floating-point approximation
+impl<E5, EPi, E3, E2, Primes> Conversion for Ratio<E5, EPi, E3, E2, Primes>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+{
+    fn conversion_factor() -> Factor {
+        match Self::EXACT {
+            Some((num, den)) => Factor::Exact(num, den),
+            None => Factor::Float(Self::FACTOR_F64),
+        }
+    }
+}
+
+// 带名称的比例与其比例的换算系数相同
+// A named ratio converts exactly like its ratio
+impl<L, R: Conversion> Conversion for Labeled<L, R> {
+    fn conversion_factor() -> Factor {
+        R::conversion_factor()
+    }
+}
+
+// ========== 公共比例 ==========
+// ========== Common Scale ==========
+
+/// 两个比例的公共细比例，以及双方换算到它的系数；用于不同比例的量相加减
+/// The common finer scale of two ratios, together with the factors that convert
+/// each side to it; used when adding or subtracting quantities of different ratios
+pub trait CommonScale<Rhs> {
+    /// 公共比例 / Common scale
+    type Output;
+    /// `Self` 与 `Rhs` 换算到公共比例的系数 / Factors converting `Self` and `Rhs` to the common scale
+    fn factors() -> (Factor, Factor);
+}
+
+// 公共比例为两者的公约数，双方系数均为正整数，整数存储时精确
+// The common scale is the common divisor of both, so both factors are positive
+// integers and integer storage stays exact
+impl<E5, EPi, E3, E2, Primes, Rhs> CommonScale<Rhs> for Ratio<E5, EPi, E3, E2, Primes>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+    Self: RatioGcd<Rhs> + Div<<Self as RatioGcd<Rhs>>::Output, Output: Conversion>,
+    Rhs: Div<<Self as RatioGcd<Rhs>>::Output, Output: Conversion>,
+{
+    type Output = <Self as RatioGcd<Rhs>>::Output;
+
+    fn factors() -> (Factor, Factor) {
+        (
+            <Self as Div<Self::Output>>::Output::conversion_factor(),
+            <Rhs as Div<Self::Output>>::Output::conversion_factor(),
+        )
+    }
+}
+
+// 近似比例只与自身相加减
+// Approximate ratios only add to and subtract from themselves
+impl<M, R> CommonScale<Approx<M, R>> for Approx<M, R> {
+    type Output = Self;
+
+    fn factors() -> (Factor, Factor) {
+        (Factor::Exact(1, 1), Factor::Exact(1, 1))
+    }
+}
+
+// 带名称的比例也只与同名比例相加减，结果保留名称
+// Named ratios likewise only add to and subtract from the same named ratio, keeping the name
+impl<L, R> CommonScale<Labeled<L, R>> for Labeled<L, R> {
+    type Output = Self;
+
+    fn factors() -> (Factor, Factor) {
+        (Factor::Exact(1, 1), Factor::Exact(1, 1))
+    }
+}
+
+// ========== Si 的换算 ==========
+// ========== Conversions of Si ==========
+
+impl<T, D, Pr> Si<Var<T>, D, Pr>
+where
+    T: Primitive + Scale,
+    D: Dimensional,
+    Pr: Prefixed,
+{
+    /// 换算为词头 `Pr2`，溢出时返回 `None`
+    /// Converts to the prefix `Pr2`; `None` on overflow
+    pub fn checked_convert_to<Pr2>(self) -> Option<Si<Var<T>, D, Pr2>>
+    where
+        Pr2: Prefixed,
+        Pr: Div<Pr2, Output: Conversion>,
+    {
+        let value = <Pr as Div<Pr2>>::Output::conversion_factor().checked_apply::<T, DefaultRounding>(self.0 .0)?;
+        Some(Si(Var(value), PhantomData))
+    }
+
+    /// 换算为词头 `Pr2`（整数四舍五入，溢出时 panic）
+    /// Converts to the prefix `Pr2` (integers rounded half away from zero; panics on overflow)
+    pub fn convert_to<Pr2>(self) -> Si<Var<T>, D, Pr2>
+    where
+        Pr2: Prefixed,
+        Pr: Div<Pr2, Output: Conversion>,
+    {
+        self.checked_convert_to().expect("prefix conversion overflows the storage type")
+    }
+
+    /// 换算为以比例 `R` 缩放的单位，溢出时返回 `None`
+    /// Converts to the unit scaled by the ratio `R`; `None` on overflow
+    pub fn checked_to_unit<R>(self) -> Option<Unit<Self, R>>
+    where
+        R: Scaled,
+        NoRatio: Div<R, Output: Conversion>,
+    {
+        let value = <NoRatio as Div<R>>::Output::conversion_factor().checked_apply::<T, DefaultRounding>(self.0 .0)?;
+        Some(Unit(Si(Var(value), PhantomData), PhantomData))
+    }
+
+    /// 换算为以比例 `R` 缩放的单位（整数四舍五入，溢出时 panic）
+    /// Converts to the unit scaled by the ratio `R` (integers rounded half away from zero; panics on overflow)
+    pub fn to_unit<R>(self) -> Unit<Self, R>
+    where
+        R: Scaled,
+        NoRatio: Div<R, Output: Conversion>,
+    {
+        self.checked_to_unit().expect("ratio conversion overflows the storage type")
+    }
+}
+
+// ========== Unit 的换算 ==========
+// ========== Conversions of Unit ==========
+
+impl<T, D, Pr, R> Unit<Si<Var<T>, D, Pr>, R>
+where
+    T: Primitive + Scale,
+    D: Dimensional,
+    Pr: Prefixed,
+    R: Scaled,
+{
+    /// 换算为比例 `R2`，溢出时返回 `None`
+    /// Converts to the ratio `R2`; `None` on overflow
+    #[allow(clippy::type_complexity)]
+    pub fn checked_convert_to<R2>(self) -> Option<Unit<Si<Var<T>, D, Pr>, R2>>
+    where
+        R2: Scaled,
+        R: Div<R2, Output: Conversion>,
+    {
+        let value = <R as Div<R2>>::Output::conversion_factor().checked_apply::<T, DefaultRounding>((self.0).0 .0)?;
+        Some(Unit(Si(Var(value), PhantomData), PhantomData))
+    }
+
+    /// 换算为比例 `R2`（整数四舍五入，溢出时 panic）
+    /// Converts to the ratio `R2` (integers rounded half away from zero; panics on overflow)
+    pub fn convert_to<R2>(self) -> Unit<Si<Var<T>, D, Pr>, R2>
+    where
+        R2: Scaled,
+        R: Div<R2, Output: Conversion>,
+    {
+        self.checked_convert_to().expect("ratio conversion overflows the storage type")
+    }
+
+    /// 换算为同词头的SI量，溢出时返回 `None`
+    /// Converts to the SI quantity with the same prefix; `None` on overflow
+    pub fn checked_to_si(self) -> Option<Si<Var<T>, D, Pr>>
+    where
+        R: Conversion,
+    {
+        let value = R::conversion_factor().checked_apply::<T, DefaultRounding>((self.0).0 .0)?;
+        Some(Si(Var(value), PhantomData))
+    }
+
+    /// 换算为同词头的SI量（整数四舍五入，溢出时 panic）
+    /// Converts to the SI quantity with the same prefix (integers rounded half away from zero; panics on overflow)
+    pub fn to_si(self) -> Si<Var<T>, D, Pr>
+    where
+        R: Conversion,
+    {
+        self.checked_to_si().expect("ratio conversion overflows the storage type")
+    }
+}
+
+// ========== 同量纲的换算 trait ==========
+// ========== Conversion Traits within a Dimension ==========
+
+/// 同量纲的量之间的换算（词头、比例可同时改变）
+/// Conversion between quantities of one dimension (the prefix and ratio may both change)
+///
+/// `Si` 之间、`Unit` 之间的泛型 `From` 会与标准库的 `impl<T> From<T> for T` 重叠，
+/// 泛型代码改用本 trait 表达“可换算为”。整数四舍五入。
+/// A generic `From` between two `Si`s or two `Unit`s would overlap the standard
+/// `impl<T> From<T> for T`, so generic code states "converts to" with this trait
+/// instead. Integers are rounded half away from zero.
+///
+/// ```rust
+/// use unitrix::quantity::{ConvertFrom, ConvertInto, Hours, Minutes, Kilo, Milli};
+/// use unitrix::quantity::si_units::Meter;
+///
+/// let mm: Meter<i64, Milli> = Meter::<i64, Kilo>::new(3).convert_into();
+/// assert_eq!(mm.0 .0, 3_000_000);
+/// let min = Minutes::<i32>::convert_from(Hours::<i32>::new(2));
+/// assert_eq!((min.0).0 .0, 120);
+/// assert!(Meter::<i8, Milli>::checked_convert_from(Meter::<i8>::new(1)).is_none());
+/// ```
+pub trait ConvertFrom<Src>: Sized {
+    /// 换算，溢出时返回 `None` / Converts; `None` on overflow
+    fn checked_convert_from(src: Src) -> Option<Self>;
+
+    /// 换算（溢出时 panic）/ Converts (panics on overflow)
+    fn convert_from(src: Src) -> Self {
+        Self::checked_convert_from(src).expect("unit conversion overflows the storage type")
+    }
+}
+
+/// [`ConvertFrom`] 的反向形式 / The reverse form of [`ConvertFrom`]
+pub trait ConvertInto<Dst>: Sized {
+    /// 换算，溢出时返回 `None` / Converts; `None` on overflow
+    fn checked_convert_into(self) -> Option<Dst>;
+
+    /// 换算（溢出时 panic）/ Converts (panics on overflow)
+    fn convert_into(self) -> Dst;
+}
+
+impl<Src, Dst: ConvertFrom<Src>> ConvertInto<Dst> for Src {
+    fn checked_convert_into(self) -> Option<Dst> {
+        Dst::checked_convert_from(self)
+    }
+
+    fn convert_into(self) -> Dst {
+        Dst::convert_from(self)
+    }
+}
+
+// Si -> Si
+impl<T, D, Pr1, Pr2> ConvertFrom<Si<Var<T>, D, Pr1>> for Si<Var<T>, D, Pr2>
+where
+    T: Primitive + Scale,
+    D: Dimensional,
+    Pr1: Prefixed + Div<Pr2, Output: Conversion>,
+    Pr2: Prefixed,
+{
+    fn checked_convert_from(si: Si<Var<T>, D, Pr1>) -> Option<Self> {
+        si.checked_convert_to()
+    }
+}
+
+// Unit -> Unit
+impl<T, D, Pr1, Pr2, R1, R2> ConvertFrom<Unit<Si<Var<T>, D, Pr1>, R1>> for Unit<Si<Var<T>, D, Pr2>, R2>
+where
+    T: Primitive + Scale,
+    D: Dimensional,
+    Pr1: Prefixed + Div<Pr2, Output: Conversion>,
+    Pr2: Prefixed,
+    R1: Scaled + Div<R2, Output: Conversion>,
+    R2: Scaled,
+{
+    fn checked_convert_from(unit: Unit<Si<Var<T>, D, Pr1>, R1>) -> Option<Self> {
+        let factor = <R1 as Div<R2>>::Output::conversion_factor() * <Pr1 as Div<Pr2>>::Output::conversion_factor();
+        let value = factor.checked_apply::<T, DefaultRounding>((unit.0).0 .0)?;
+        Some(Unit(Si(Var(value), PhantomData), PhantomData))
+    }
+}
+
+// Unit -> Si
+impl<T, D, Pr1, Pr2, R> ConvertFrom<Unit<Si<Var<T>, D, Pr1>, R>> for Si<Var<T>, D, Pr2>
+where
+    T: Primitive + Scale,
+    D: Dimensional,
+    Pr1: Prefixed + Div<Pr2, Output: Conversion>,
+    Pr2: Prefixed,
+    R: Scaled + Conversion,
+{
+    fn checked_convert_from(unit: Unit<Si<Var<T>, D, Pr1>, R>) -> Option<Self> {
+        let factor = R::conversion_factor() * <Pr1 as Div<Pr2>>::Output::conversion_factor();
+        let value = factor.checked_apply::<T, DefaultRounding>((unit.0).0 .0)?;
+        Some(Si(Var(value), PhantomData))
+    }
+}
+
+// Si -> Unit
+impl<T, D, Pr1, Pr2, R> ConvertFrom<Si<Var<T>, D, Pr1>> for Unit<Si<Var<T>, D, Pr2>, R>
+where
+    T: Primitive + Scale,
+    D: Dimensional,
+    Pr1: Prefixed + Div<Pr2, Output: Conversion>,
+    Pr2: Prefixed,
+    R: Scaled,
+    NoRatio: Div<R, Output: Conversion>,
+{
+    fn checked_convert_from(si: Si<Var<T>, D, Pr1>) -> Option<Self> {
+        let factor = <NoRatio as Div<R>>::Output::conversion_factor() * <Pr1 as Div<Pr2>>::Output::conversion_factor();
+        let value = factor.checked_apply::<T, DefaultRounding>(si.0 .0)?;
+        Some(Unit(Si(Var(value), PhantomData), PhantomData))
+    }
+}
+
+// ========== From / Into ==========
+//
+// `From` 只用于浮点存储：浮点换算不取整也不会失败。整数存储的 `Unit` 与 `Si` 之间提供
+// `TryFrom`（溢出时为 [`DynError::Overflow`]），也可使用 `to_si` / `to_unit` /
+// `convert_to` 及其 `checked_*` 版本。`Si` 之间与 `Unit` 之间的泛型 `From` 会与标准库的
+// `impl<T> From<T> for T` 重叠，因此这两种情况请使用 `convert_to` 或 [`ConvertFrom`]。
+// `From` is provided for floating-point storage only, where a conversion neither
+// rounds nor fails. Integer storage gets `TryFrom` between `Unit` and `Si` (failing
+// with [`DynError::Overflow`]), next to `to_si` / `to_unit` / `convert_to` and their
+// `checked_*` forms. A generic `From` between two `Si`s or two `Unit`s would overlap
+// the standard `impl<T> From<T> for T`, so use `convert_to` or [`ConvertFrom`] for
+// those cases.
+
+macro_rules! impl_float_unit_from {
+    ($($t:ty),*) => {
+        $(
+            // Unit -> Si（可同时换算词头）
+            // Unit -> Si (the prefix may change at the same time)
+            impl<D, Pr1, Pr2, R> From<Unit<Si<Var<$t>, D, Pr1>, R>> for Si<Var<$t>, D, Pr2>
+            where
+                D: Dimensional,
+                Pr1: Prefixed + Div<Pr2, Output: Conversion>,
+                Pr2: Prefixed,
+                R: Scaled + Conversion,
+            {
+                fn from(unit: Unit<Si<Var<$t>, D, Pr1>, R>) -> Self {
+                    let factor = R::conversion_factor() * <Pr1 as Div<Pr2>>::Output::conversion_factor();
+                    Si(Var((unit.0).0 .0 * factor.to_f64() as $t), PhantomData)
+                }
+            }
+
+            // Si -> Unit（可同时换算词头）
+            // Si -> Unit (the prefix may change at the same time)
+            impl<D, Pr1, Pr2, R> From<Si<Var<$t>, D, Pr1>> for Unit<Si<Var<$t>, D, Pr2>, R>
+            where
+                D: Dimensional,
+                Pr1: Prefixed + Div<Pr2, Output: Conversion>,
+                Pr2: Prefixed,
+                R: Scaled,
+                NoRatio: Div<R, Output: Conversion>,
+            {
+                fn from(si: Si<Var<$t>, D, Pr1>) -> Self {
+                    let factor = <NoRatio as Div<R>>::Output::conversion_factor() * <Pr1 as Div<Pr2>>::Output::conversion_factor();
+                    Unit(Si(Var(si.0 .0 * factor.to_f64() as $t), PhantomData), PhantomData)
+                }
+            }
+        )*
+    };
+}
+
+impl_float_unit_from!(f32, f64);
+
+macro_rules! impl_int_unit_try_from {
+    ($($t:ty),*) => {
+        $(
+            // Unit -> Si（可同时换算词头）
+            // Unit -> Si (the prefix may change at the same time)
+            impl<D, Pr1, Pr2, R> TryFrom<Unit<Si<Var<$t>, D, Pr1>, R>> for Si<Var<$t>, D, Pr2>
+            where
+                D: Dimensional,
+                Pr1: Prefixed + Div<Pr2, Output: Conversion>,
+                Pr2: Prefixed,
+                R: Scaled + Conversion,
+            {
+                type Error = DynError;
+
+                fn try_from(unit: Unit<Si<Var<$t>, D, Pr1>, R>) -> Result<Self, DynError> {
+                    Self::checked_convert_from(unit).ok_or(DynError::Overflow)
+                }
+            }
+
+            // Si -> Unit（可同时换算词头）
+            // Si -> Unit (the prefix may change at the same time)
+            impl<D, Pr1, Pr2, R> TryFrom<Si<Var<$t>, D, Pr1>> for Unit<Si<Var<$t>, D, Pr2>, R>
+            where
+                D: Dimensional,
+                Pr1: Prefixed + Div<Pr2, Output: Conversion>,
+                Pr2: Prefixed,
+                R: Scaled,
+                NoRatio: Div<R, Output: Conversion>,
+            {
+                type Error = DynError;
+
+                fn try_from(si: Si<Var<$t>, D, Pr1>) -> Result<Self, DynError> {
+                    Self::checked_convert_from(si).ok_or(DynError::Overflow)
+                }
+            }
+        )*
+    };
+}
+
+impl_int_unit_try_from!(i8, i16, i32, i64, i128, isize);
+
+// ========== 无量纲量 ==========
+// ========== Dimensionless Quantities ==========
+//
+// 无量纲的 `Si` / `Unit` 可以取出其纯数值，词头与比例在取出时一并计入，
+// 例如 50 厘（百分之五十）得 0.5，180° 得 π。
+// A dimensionless `Si` / `Unit` yields its plain number with the prefix and ratio
+// applied, e.g. 50 centi (fifty percent) gives 0.5 and 180° gives π.
+
+impl<T, Pr> Si<Var<T>, Dimensionless, Pr>
+where
+    T: Primitive + Scale,
+    Pr: Prefixed + Conversion,
+{
+    /// 取出纯数值（计入词头），溢出时返回 `None`
+    /// The plain number with the prefix applied; `None` on overflow
+    pub fn checked_into_inner(self) -> Option<T> {
+        Pr::conversion_factor().checked_apply::<T, DefaultRounding>(self.0 .0)
+    }
+
+    /// 取出纯数值（计入词头；整数四舍五入，溢出时 panic）
+    /// The plain number with the prefix applied (integers rounded half away from zero; panics on overflow)
+    pub fn into_inner(self) -> T {
+        self.checked_into_inner().expect("prefix conversion overflows the storage type")
+    }
+}
+
+impl<T, Pr, R> Unit<Si<Var<T>, Dimensionless, Pr>, R>
+where
+    T: Primitive + Scale,
+    Pr: Prefixed + Conversion,
+    R: Scaled + Conversion,
+{
+    /// 取出纯数值（计入比例与词头），溢出时返回 `None`
+    /// The plain number with the ratio and prefix applied; `None` on overflow
+    pub fn checked_into_inner(self) -> Option<T> {
+        (R::conversion_factor() * Pr::conversion_factor()).checked_apply::<T, DefaultRounding>((self.0).0 .0)
+    }
+
+    /// 取出纯数值（计入比例与词头；整数四舍五入，溢出时 panic）
+    /// The plain number with the ratio and prefix applied (integers rounded half away from zero; panics on overflow)
+    pub fn into_inner(self) -> T {
+        self.checked_into_inner().expect("unit conversion overflows the storage type")
+    }
+}
+
+macro_rules! impl_from_dimensionless {
+    ($($t:ty),*) => {
+        $(
+            impl<Pr: Prefixed + Conversion> From<Si<Var<$t>, Dimensionless, Pr>> for $t {
+                fn from(si: Si<Var<$t>, Dimensionless, Pr>) -> Self {
+                    si.into_inner()
+                }
+            }
+
+            impl<Pr: Prefixed + Conversion, R: Scaled + Conversion> From<Unit<Si<Var<$t>, Dimensionless, Pr>, R>> for $t {
+                fn from(unit: Unit<Si<Var<$t>, Dimensionless, Pr>, R>) -> Self {
+                    unit.into_inner()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_dimensionless!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{Const, Z0, P1};
+    use crate::quantity::{Kilo, Milli, NoPrefix, Minutes, Hours, Days, Seconds, Degrees, Gradians, Radians};
+    use crate::quantity::si_units::Meter;
+
+    #[test]
+    fn test_into_inner() {
+        let ratio: f64 = (<Meter>::new(3.0) / <Meter>::new(1.5)).into();
+        assert_eq!(ratio, 2.0);
+
+        let percent: Si<Var<i64>, Dimensionless, Prefix<Const<-2>>> = Si::new(250);
+        assert_eq!(percent.into_inner(), 3);
+        let ppm: Si<Var<f64>, Dimensionless, Prefix<Const<-6>>> = Si::new(5.0);
+        assert!((f64::from(ppm) - 5e-6).abs() < 1e-18);
+
+        let half_turn: f64 = Degrees::new(180.0).into();
+        assert!((half_turn - core::f64::consts::PI).abs() < 1e-12);
+        assert_eq!((Minutes::new(4) / Seconds::new(2)).into_inner(), 120);
+    }
+
+    #[test]
+    fn test_factor() {
+        assert_eq!(<Prefix<Const<3>>>::conversion_factor(), Factor::Exact(1000, 1));
+        assert_eq!(<Prefix<Const<-2>>>::conversion_factor(), Factor::Exact(1, 100));
+        assert!(matches!(<Prefix<Const<60>>>::conversion_factor(), Factor::Float(_)));
+        assert_eq!(<Ratio<P1, Z0, P1, Const<2>>>::conversion_factor(), Factor::Exact(60, 1));
+        assert_eq!(Factor::Exact(60, 1) * Factor::Exact(1, 1000), Factor::Exact(3, 50));
+    }
+
+    #[test]
+    fn test_si_convert() {
+        let km: Meter<i64, Kilo> = Si::new(5);
+        assert_eq!(km.convert_to::<Milli>().0 .0, 5_000_000);
+        let mm: Meter<i32, Milli> = Si::new(1499);
+        assert_eq!(mm.convert_to::<NoPrefix>().0 .0, 1);
+        let big: Meter<i32, Kilo> = Si::new(i32::MAX);
+        assert!(big.checked_convert_to::<Milli>().is_none());
+    }
+
+    #[test]
+    fn test_unit_convert() {
+        let d: Days = Days::new(1);
+        let h: Hours = d.convert_to();
+        assert_eq!((h.0).0 .0, 24);
+        let m: Minutes = h.convert_to();
+        assert_eq!((m.0).0 .0, 1440);
+        assert_eq!(m.to_si().0 .0, 86400);
+        assert_eq!((Seconds::new(7200).to_unit::<crate::quantity::Hour>().0).0 .0, 2);
+
+        let deg: Degrees = Degrees::new(180.0);
+        let gon: Gradians = deg.convert_to();
+        assert!(((gon.0).0 .0 - 200.0).abs() < 1e-9);
+        assert!((deg.to_si().0 .0 - core::f64::consts::PI).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_unit_si() {
+        let h: Hours = Hours::new(1);
+        let s: Seconds<i64> = h.to_si();
+        assert_eq!(s.0 .0, 3600);
+        let kilo: Si<Var<i64>, crate::quantity::TimeDimension, Kilo> = Hours::new(2).to_si().convert_to();
+        assert_eq!(kilo.0 .0, 7);
+        let back: Minutes = Seconds::new(180).to_unit();
+        assert_eq!((back.0).0 .0, 3);
+    }
+
+    #[test]
+    fn test_float_from() {
+        let rad: Radians = Degrees::new(90.0).into();
+        assert!((rad.0 .0 - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        let ks: Si<Var<f64>, crate::quantity::TimeDimension, Kilo> = Hours::<f64>::new(2.0).into();
+        assert!((ks.0 .0 - 7.2).abs() < 1e-12);
+        let min: Minutes<f32> = Seconds::<f32>::new(90.0).into();
+        assert!(((min.0).0 .0 - 1.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_int_try_from() {
+        let ks: Si<Var<i32>, crate::quantity::TimeDimension, Kilo> = Hours::<i32>::new(2).try_into().unwrap();
+        assert_eq!(ks.0 .0, 7);
+        let min = Minutes::<i64>::try_from(Seconds::<i64>::new(90)).unwrap();
+        assert_eq!((min.0).0 .0, 2);
+        let s: Result<Si<Var<i8>, crate::quantity::TimeDimension, NoPrefix>, _> = Hours::<i8>::new(1).try_into();
+        assert_eq!(s, Err(DynError::Overflow));
+    }
+
+    #[test]
+    fn test_convert_from() {
+        let mm: Meter<i64, Milli> = Meter::<i64, Kilo>::new(3).convert_into();
+        assert_eq!(mm.0 .0, 3_000_000);
+        assert!(Meter::<i8, Milli>::checked_convert_from(Meter::<i8>::new(1)).is_none());
+
+        let min = Minutes::<i32>::convert_from(Days::<i32>::new(1));
+        assert_eq!((min.0).0 .0, 1440);
+        let h: Option<Hours<i32>> = Minutes::<i32>::new(150).checked_convert_into();
+        assert_eq!((h.unwrap().0).0 .0, 3);
+
+        let s: Si<Var<i32>, crate::quantity::TimeDimension, Milli> = Minutes::<i32>::new(2).convert_into();
+        assert_eq!(s.0 .0, 120_000);
+        let min: Minutes<f64> = Seconds::<f64>::new(30.0).convert_into();
+        assert_eq!((min.0).0 .0, 0.5);
+    }
+}
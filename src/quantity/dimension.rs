@@ -49,7 +49,19 @@ pub struct Dimension<
 /// # Safety 安全性
 /// This trait is sealed and cannot be implemented outside this crate
 /// 该trait是密封的，不能在本crate外实现
-pub trait Dimensional: Sealed+Sized {}
+pub trait Dimensional: Sealed+Sized {
+    /// The dimensional exponents in the order m, kg, s, A, K, mol, cd
+    /// 量纲指数，顺序为 m, kg, s, A, K, mol, cd
+    const EXPONENTS: [i32; 7];
+
+    /// 角度伪量纲的指数（见 [`WithAngle`](super::WithAngle)），SI量纲为0
+    /// Exponent of the angle pseudo-dimension (see [`WithAngle`](super::WithAngle)); 0 for SI dimensions
+    const ANGLE: i32 = 0;
+
+    /// 自定义基本量纲的指数（见 [`Extended`](super::Extended)），SI量纲全为0
+    /// Exponents of the custom base dimensions (see [`Extended`](super::Extended)); all 0 for SI dimensions
+    const EXTRA_EXPONENTS: [i32; 4] = [0; 4];
+}
 
 impl<M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt>
     Sealed for Dimension<M, KG, S, A, K, MOL, CD>{
@@ -57,6 +69,7 @@ impl<M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: Type
 
 impl<M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt>
     Dimensional for Dimension<M, KG, S, A, K, MOL, CD>{
+    const EXPONENTS: [i32; 7] = [M::I32, KG::I32, S::I32, A::I32, K::I32, MOL::I32, CD::I32];
 }
 
 /// Creates a new unit instance
@@ -0,0 +1,292 @@
+//! 运行期词头的SI量
+//! SI quantities with a runtime prefix
+//!
+//! [`DynPrefixSi<T, D>`] 的量纲仍是静态类型，词头指数则是运行期的 `i8`，适合传感器
+//! 前端等量程在运行期切换的场合。它可以换算到任意静态词头的 `Si`，也可以用
+//! [`engineering`](DynPrefixSi::engineering) 自动选择3的倍数的工程词头，使尾数落在
+//! [1, 1000) 内。
+//! [`DynPrefixSi<T, D>`] keeps a static dimension but a runtime `i8` prefix exponent,
+//! suited to sensor front-ends and other settings where the range changes at runtime.
+//! It converts to an `Si` with any static prefix, and
+//! [`engineering`](DynPrefixSi::engineering) selects the engineering-notation prefix
+//! (a multiple of 3) that keeps the mantissa in [1, 1000).
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{DynPrefixSi, Milli};
+//! use unitrix::quantity::si_units::{VoltageDimension, Volt};
+//!
+//! let reading: DynPrefixSi<f64, VoltageDimension> = DynPrefixSi::new(0.0042, 0);
+//! let eng = reading.engineering();
+//! assert_eq!(eng.prefix, -3);
+//! assert_eq!(format!("{:.1}", eng), "4.2 mV");
+//!
+//! let mv: Volt<f64, Milli> = reading.to_si();
+//! assert!((mv.0 .0 - 4.2).abs() < 1e-12);
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{Add, Sub};
+
+use crate::number::{Primitive, Scale, DefaultRounding, Var};
+use super::{Si, Dimensional, Prefixed, Factor, DynQuantity, DynDimension, DynRatio, DynError};
+use super::format::DimensionNames;
+
+/// 词头在运行期确定、量纲静态的SI量
+/// An SI quantity with a static dimension and a prefix chosen at runtime
+#[derive(Debug, Clone, Copy)]
+pub struct DynPrefixSi<T, D: Dimensional> {
+    /// 数值 / Value
+    pub value: T,
+    /// 词头指数（10的幂次）/ Prefix exponent (power of ten)
+    pub prefix: i8,
+    _dimension: PhantomData<D>,
+}
+
+impl<T: Primitive, D: Dimensional> DynPrefixSi<T, D> {
+    /// 以数值与词头指数创建
+    /// Creates the quantity from a value and a prefix exponent
+    pub fn new(value: T, prefix: i8) -> Self {
+        DynPrefixSi { value, prefix, _dimension: PhantomData }
+    }
+}
+
+impl<T: Primitive + Scale, D: Dimensional> DynPrefixSi<T, D> {
+    /// 换算到词头指数 `prefix`，溢出时返回 `None`
+    /// Converts to the prefix exponent `prefix`; `None` on overflow
+    pub fn checked_rescale(self, prefix: i8) -> Option<Self> {
+        let factor = Factor::power_of_ten(i32::from(self.prefix) - i32::from(prefix));
+        let value = factor.checked_apply::<T, DefaultRounding>(self.value)?;
+        Some(DynPrefixSi::new(value, prefix))
+    }
+
+    /// 换算到词头指数 `prefix`（整数四舍五入，溢出时 panic）
+    /// Converts to the prefix exponent `prefix` (integers rounded half away from zero; panics on overflow)
+    pub fn rescale(self, prefix: i8) -> Self {
+        self.checked_rescale(prefix).expect("prefix conversion overflows the storage type")
+    }
+
+    /// 换算为静态词头 `Pr` 的 `Si`，溢出时返回 `None`
+    /// Converts to an `Si` with the static prefix `Pr`; `None` on overflow
+    pub fn checked_to_si<Pr: Prefixed>(self) -> Option<Si<Var<T>, D, Pr>> {
+        let factor = Factor::power_of_ten(i32::from(self.prefix) - Pr::EXPONENT);
+        let value = factor.checked_apply::<T, DefaultRounding>(self.value)?;
+        Some(Si(Var(value), PhantomData))
+    }
+
+    /// 换算为静态词头 `Pr` 的 `Si`（整数四舍五入，溢出时 panic）
+    /// Converts to an `Si` with the static prefix `Pr` (integers rounded half away from zero; panics on overflow)
+    pub fn to_si<Pr: Prefixed>(self) -> Si<Var<T>, D, Pr> {
+        self.checked_to_si().expect("prefix conversion overflows the storage type")
+    }
+}
+
+impl<T: Primitive + Scale + Magnitude, D: Dimensional> DynPrefixSi<T, D> {
+    /// 工程记数法的词头：3的倍数，使尾数的绝对值落在 [1, 1000) 内
+    /// 零与非有限值保持原词头，词头不超出 q…Q 的范围；整数换算到较大词头时四舍五入。
+    /// The engineering-notation prefix: a multiple of 3 that keeps the magnitude of the
+    /// mantissa in [1, 1000). Zero and non-finite values keep their prefix and the prefix
+    /// stays within q…Q; integers are rounded when moving to a larger prefix.
+    pub fn engineering_prefix(&self) -> i8 {
+        let mut magnitude = self.value.magnitude();
+        let mut prefix = i32::from(self.prefix);
+        if magnitude == 0.0 || !magnitude.is_finite() {
+            return self.prefix;
+        }
+        // 先对齐到3的倍数 / Align to a multiple of 3 first
+        for _ in 0..prefix.rem_euclid(3) {
+            magnitude *= 10.0;
+            prefix -= 1;
+        }
+        while magnitude >= 1000.0 && prefix < 30 {
+            magnitude /= 1000.0;
+            prefix += 3;
+        }
+        while magnitude < 1.0 && prefix > -30 {
+            magnitude *= 1000.0;
+            prefix -= 3;
+        }
+        prefix as i8
+    }
+
+    /// 换算到工程记数法的词头（见 [`engineering_prefix`](Self::engineering_prefix)），
+    /// 整数存储放不下该词头下的尾数时返回 `None`
+    /// Converts to the engineering-notation prefix (see
+    /// [`engineering_prefix`](Self::engineering_prefix)); `None` if integer storage
+    /// cannot hold the mantissa at that prefix
+    pub fn checked_engineering(self) -> Option<Self> {
+        let prefix = self.engineering_prefix();
+        let original = DynPrefixSi::new(self.value, self.prefix);
+        let scaled = self.checked_rescale(prefix)?;
+        // 整数四舍五入可能进位到1000（如 999 600 m → 1000 km），此时再升一级词头
+        // Integer rounding can carry up to 1000 (e.g. 999 600 m → 1000 km); step up one more prefix then
+        if scaled.value.magnitude() >= 1000.0 && prefix < 30 {
+            original.checked_rescale(prefix + 3)
+        } else {
+            Some(scaled)
+        }
+    }
+
+    /// 换算到工程记数法的词头（见 [`engineering_prefix`](Self::engineering_prefix)）；
+    /// 整数存储放不下该词头下的尾数时（如 `i8` 的 2 hm 无法写成 200 m）保持原词头
+    /// Converts to the engineering-notation prefix (see
+    /// [`engineering_prefix`](Self::engineering_prefix)); keeps the original prefix when
+    /// integer storage cannot hold the mantissa at that prefix (e.g. 2 hm in `i8` cannot
+    /// become 200 m)
+    pub fn engineering(self) -> Self {
+        let original = DynPrefixSi::new(self.value, self.prefix);
+        self.checked_engineering().unwrap_or(original)
+    }
+}
+
+/// 数值的绝对值的 f64 近似，用于选择工程词头
+/// Magnitude of a value as an f64 approximation, used to select engineering prefixes
+pub trait Magnitude: Copy {
+    /// 绝对值 / Absolute value
+    fn magnitude(self) -> f64;
+}
+
+macro_rules! impl_magnitude {
+    ($($t:ty),*) => {
+        $(
+            impl Magnitude for $t {
+                fn magnitude(self) -> f64 {
+                    (self as f64).abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_magnitude!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+// ========== 转换 ==========
+// ========== Conversions ==========
+
+// 词头指数超出 `i8` 范围时返回 [`DynError::Overflow`]
+// A prefix exponent outside the `i8` range gives [`DynError::Overflow`]
+impl<T, D, Pr> TryFrom<Si<Var<T>, D, Pr>> for DynPrefixSi<T, D>
+where
+    T: Primitive,
+    D: Dimensional,
+    Pr: Prefixed,
+{
+    type Error = DynError;
+
+    fn try_from(si: Si<Var<T>, D, Pr>) -> Result<Self, DynError> {
+        let prefix = i8::try_from(Pr::EXPONENT).map_err(|_| DynError::Overflow)?;
+        Ok(DynPrefixSi::new(si.0 .0, prefix))
+    }
+}
+
+impl<T: Primitive, D: DimensionNames> From<DynPrefixSi<T, D>> for DynQuantity<T> {
+    fn from(q: DynPrefixSi<T, D>) -> Self {
+        DynQuantity::new(q.value, DynDimension::of::<D>(), i32::from(q.prefix), DynRatio::ONE)
+    }
+}
+
+// ========== 运算 ==========
+// ========== Arithmetic ==========
+
+// 词头不同时换算到较小词头（溢出时 panic）
+// With differing prefixes both sides convert to the smaller prefix (panics on overflow)
+impl<T: Primitive + Scale, D: Dimensional> Add for DynPrefixSi<T, D> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let prefix = self.prefix.min(rhs.prefix);
+        DynPrefixSi::new(self.rescale(prefix).value + rhs.rescale(prefix).value, prefix)
+    }
+}
+
+impl<T: Primitive + Scale, D: Dimensional> Sub for DynPrefixSi<T, D> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let prefix = self.prefix.min(rhs.prefix);
+        DynPrefixSi::new(self.rescale(prefix).value - rhs.rescale(prefix).value, prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use core::ops::Mul;
+
+    use super::*;
+    use crate::number::Const;
+    use crate::quantity::{Kilo, Milli, Micro, Prefix};
+    use crate::quantity::si_units::*;
+
+    #[test]
+    fn test_engineering() {
+        let v = |value: f64, prefix: i8| DynPrefixSi::<f64, VoltageDimension>::new(value, prefix).engineering();
+        assert_eq!((v(0.0042, 0).value, v(0.0042, 0).prefix), (4.2, -3));
+        assert_eq!(v(1500.0, 0).prefix, 3);
+        assert_eq!(v(999.0, 0).prefix, 0);
+        assert_eq!(v(1.0, 0).prefix, 0);
+        assert_eq!(v(0.5, 0).prefix, -3);
+        assert_eq!(v(-25000.0, -3).prefix, 0);
+        assert_eq!(v(42.0, 1).prefix, 0);
+        assert_eq!(v(42.0, 1).value, 420.0);
+        assert_eq!(v(0.0, 5).prefix, 5);
+
+        let i = DynPrefixSi::<i32, LengthDimension>::new(12_345, 0).engineering();
+        assert_eq!((i.value, i.prefix), (12, 3));
+        assert_eq!(format!("{}", i), "12 km");
+
+        // 四舍五入进位到1000时升一级词头 / Rounding up to 1000 steps up one prefix
+        let i = DynPrefixSi::<i32, LengthDimension>::new(999_600, 0).engineering();
+        assert_eq!((i.value, i.prefix), (1, 6));
+        let i = DynPrefixSi::<i32, LengthDimension>::new(999_400, 0).engineering();
+        assert_eq!((i.value, i.prefix), (999, 3));
+    }
+
+    #[test]
+    fn test_engineering_narrow_storage() {
+        // 2 hm 在 `i8` 中写不成 200 m：checked 版返回 `None`，engineering 保持原词头
+        // 2 hm cannot become 200 m in `i8`: the checked form gives `None` and
+        // engineering keeps the original prefix
+        let q = DynPrefixSi::<i8, LengthDimension>::new(2, 2);
+        assert!(q.checked_engineering().is_none());
+        let e = q.engineering();
+        assert_eq!((e.value, e.prefix), (2, 2));
+
+        let e = DynPrefixSi::<i8, LengthDimension>::new(12, 2).engineering();
+        assert_eq!((e.value, e.prefix), (1, 3));
+        let e = DynPrefixSi::<i8, LengthDimension>::new(-3, 1).checked_engineering().unwrap();
+        assert_eq!((e.value, e.prefix), (-30, 0));
+    }
+
+    #[test]
+    fn test_static_prefix() {
+        let q = DynPrefixSi::<i64, LengthDimension>::new(3, 3);
+        let mm: Meter<i64, Milli> = q.to_si();
+        assert_eq!(mm.0 .0, 3_000_000);
+        let km: Meter<i64, Kilo> = q.to_si();
+        assert_eq!(km.0 .0, 3);
+        assert!(DynPrefixSi::<i8, LengthDimension>::new(3, 3).checked_to_si::<Milli>().is_none());
+
+        let q: DynPrefixSi<f64, TimeDimension> = Second::<f64, Micro>::new(4.0).try_into().unwrap();
+        assert_eq!(q.prefix, -6);
+        assert_eq!(format!("{}", q), "4 µs");
+        let dq: DynQuantity<f64> = q.into();
+        assert_eq!(dq.prefix, -6);
+
+        // 超出 `i8` 的词头指数 / A prefix exponent beyond `i8`
+        let huge = Second::<f64, Prefix<<Const<64> as Mul<Const<2>>>::Output>>::new(1.0);
+        assert_eq!(DynPrefixSi::try_from(huge).unwrap_err(), DynError::Overflow);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = DynPrefixSi::<i32, LengthDimension>::new(1, 3);
+        let b = DynPrefixSi::<i32, LengthDimension>::new(5, 0);
+        let sum = a + b;
+        assert_eq!((sum.value, sum.prefix), (1005, 0));
+        let diff = a - b;
+        assert_eq!((diff.value, diff.prefix), (995, 0));
+    }
+}
@@ -0,0 +1,622 @@
+//! 运行期量纲与物理量
+//! Runtime dimensions and quantities
+//!
+//! 单位只在运行期才知道的数据（来自文件或网络报文）无法使用静态的 `Si` / `Unit`。
+//! [`DynQuantity<T>`] 把量纲、词头指数与比例指数作为数值保存，运算在运行期检查并返回
+//! `Result`。与静态类型之间的转换是无损的：`From` 由类型参数的 `to_i32` 取得指数，
+//! `TryFrom` 在运行期逐项核对指数与目标类型一致，不做任何换算；需要换算时先用
+//! [`DynQuantity::rescale`]。
+//! Data whose unit is only known at runtime (from files or network messages) cannot
+//! use the static `Si` / `Unit`. [`DynQuantity<T>`] keeps the dimension, prefix
+//! exponent and ratio exponents as values; arithmetic is checked at runtime and
+//! returns `Result`. Conversions to and from the static types are lossless: `From`
+//! reads the exponents from the type parameters with `to_i32`, and `TryFrom` checks at
+//! runtime that every exponent matches the target type, without converting anything;
+//! use [`DynQuantity::rescale`] first when a conversion is wanted.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{DynQuantity, DynDimension, DynError, Hours, Minutes, Seconds};
+//! use unitrix::quantity::si_units::{Meter, Velocity, TimeDimension};
+//!
+//! let d: DynQuantity<f64> = <Meter>::new(100.0).into();
+//! let t: DynQuantity<f64> = Seconds::<f64>::new(8.0).into();
+//! let v: Velocity = (d / t).unwrap().try_into().unwrap();
+//! assert_eq!(v.0 .0, 12.5);
+//!
+//! let sum = (DynQuantity::from(Hours::new(1)) + Minutes::new(30).into()).unwrap();
+//! let min: Minutes = sum.try_into().unwrap();
+//! assert_eq!((min.0).0 .0, 90);
+//!
+//! assert!(matches!(d + t, Err(DynError::DimensionMismatch { .. })));
+//! assert_eq!(DynDimension::of::<TimeDimension>(), DynDimension::new([0, 0, 1, 0, 0, 0, 0], 0));
+//! ```
+
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use core::ops::{Add, Sub, Mul, Div};
+
+use crate::number::{TypedInt, Primitive, Scale, CheckedArith, DefaultRounding, Var, reduce};
+use super::ratio::{Ratio, PrimeList, Scaled};
+use super::{Si, Unit, Prefixed, Factor};
+use super::format::{write_dimension, DimensionNames, UnitNames};
+
+// ========== 运行期量纲 ==========
+// ========== Runtime Dimension ==========
+
+/// 运行期量纲：m, kg, s, A, K, mol, cd 的指数、角度伪量纲的指数（见 [`WithAngle`](super::WithAngle)）
+/// 与种类（见 [`Kinded`](super::Kinded)）
+/// Runtime dimension: the exponents of m, kg, s, A, K, mol, cd and of the angle
+/// pseudo-dimension (see [`WithAngle`](super::WithAngle)), and the kind (see [`Kinded`](super::Kinded))
+///
+/// 种类以其单位写法区分。乘除的结果总是不带种类：种类规则表只在编译期检查，
+/// 其现有规则的结果也都不带种类。
+/// Kinds are told apart by their unit spellings. Products and quotients never carry
+/// a kind: the kind rule table is only checked at compile time, and its existing rules
+/// all give kindless results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DynDimension {
+    /// SI基本量纲的指数 / Exponents of the SI base dimensions
+    pub exponents: [i32; 7],
+    /// 角度指数：rad 为1，sr 为2 / Angle exponent: 1 for rad, 2 for sr
+    pub angle: i32,
+    /// 种类的单位写法，无种类时为 `None` / Unit spellings of the kind; `None` without a kind
+    pub kind: Option<&'static UnitNames>,
+}
+
+impl DynDimension {
+    /// 无量纲 / Dimensionless
+    pub const DIMENSIONLESS: Self = DynDimension::new([0; 7], 0);
+
+    /// 由指数创建，不带种类
+    /// Creates the dimension from its exponents, without a kind
+    pub const fn new(exponents: [i32; 7], angle: i32) -> Self {
+        DynDimension { exponents, angle, kind: None }
+    }
+
+    /// 附加种类
+    /// Attaches a kind
+    pub const fn with_kind(self, kind: &'static UnitNames) -> Self {
+        DynDimension { kind: Some(kind), ..self }
+    }
+
+    /// 静态量纲 `D` 的指数与种类；运行期量纲不含自定义基本量纲（见 [`Extended`](super::Extended)），
+    /// 带自定义基本量纲的 `D` 无法编译
+    /// The exponents and kind of the static dimension `D`; runtime dimensions hold no
+    /// custom base dimensions (see [`Extended`](super::Extended)), and a `D` with custom
+    /// base dimensions does not compile
+    pub fn of<D: DimensionNames>() -> Self {
+        const {
+            assert!(matches!(D::EXTRA_EXPONENTS, [0, 0, 0, 0]), "runtime dimensions do not support custom base dimensions");
+        }
+        DynDimension { kind: D::KIND_NAMES, ..DynDimension::new(D::EXPONENTS, D::ANGLE) }
+    }
+
+    /// 是否与静态量纲 `D` 相同
+    /// Whether this equals the static dimension `D`
+    pub fn is<D: DimensionNames>(&self) -> bool {
+        *self == Self::of::<D>()
+    }
+
+    /// 乘积，指数溢出时为 `None`
+    /// The product; `None` when an exponent overflows
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.combine(rhs, 1)
+    }
+
+    /// 商，指数溢出时为 `None`
+    /// The quotient; `None` when an exponent overflows
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.combine(rhs, -1)
+    }
+
+    fn combine(mut self, rhs: Self, sign: i32) -> Option<Self> {
+        for (a, b) in self.exponents.iter_mut().zip(rhs.exponents) {
+            *a = a.checked_add(b.checked_mul(sign)?)?;
+        }
+        self.angle = self.angle.checked_add(rhs.angle.checked_mul(sign)?)?;
+        self.kind = None;
+        Some(self)
+    }
+}
+
+// 量纲相乘即指数相加
+// Multiplying dimensions adds their exponents
+impl Mul for DynDimension {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(mut self, rhs: Self) -> Self {
+        for (a, b) in self.exponents.iter_mut().zip(rhs.exponents) {
+            *a += b;
+        }
+        self.angle += rhs.angle;
+        self.kind = None;
+        self
+    }
+}
+
+impl Div for DynDimension {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(mut self, rhs: Self) -> Self {
+        for (a, b) in self.exponents.iter_mut().zip(rhs.exponents) {
+            *a -= b;
+        }
+        self.angle -= rhs.angle;
+        self.kind = None;
+        self
+    }
+}
+
+// 以SI符号显示，如 `m·s⁻¹`、`rad·s⁻¹`，带种类时显示种类的符号；无量纲显示 `1`
+// Displays as SI symbols, e.g. `m·s⁻¹` or `rad·s⁻¹`, or as the kind's symbol when it
+// has one; dimensionless displays `1`
+impl Display for DynDimension {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_dimension(f, self)
+    }
+}
+
+// ========== 运行期比例 ==========
+// ========== Runtime Ratio ==========
+
+/// 运行期比例：5、π、3、2 的指数，以及其它质因数之积的精确分数
+/// Runtime ratio: the exponents of 5, π, 3 and 2, and the exact fraction of the other prime factors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DynRatio {
+    /// 5、π、3、2 的指数 / Exponents of 5, π, 3 and 2
+    pub exponents: [i32; 4],
+    /// 其它质因数之积（约分，分母为正）/ Product of the other prime factors (reduced, positive denominator)
+    pub primes: (i128, i128),
+}
+
+impl DynRatio {
+    /// 比例1 / The ratio one
+    pub const ONE: Self = DynRatio { exponents: [0; 4], primes: (1, 1) };
+
+    /// 静态比例 `R` 的指数
+    /// The exponents of the static ratio `R`
+    pub fn of<R: ToDynRatio>() -> Self {
+        R::dyn_ratio()
+    }
+
+    /// 是否为1
+    /// Whether this is one
+    pub fn is_one(&self) -> bool {
+        *self == Self::ONE
+    }
+
+    /// 换算系数；不含π时为精确分数
+    /// The conversion factor; an exact fraction when π is absent
+    pub fn factor(&self) -> Factor {
+        let [e5, e_pi, e3, e2] = self.exponents;
+        Factor::Exact(self.primes.0, self.primes.1)
+            * Factor::Exact(5, 1).powi(e5)
+            * Factor::Float(core::f64::consts::PI).powi(e_pi)
+            * Factor::Exact(3, 1).powi(e3)
+            * Factor::Exact(2, 1).powi(e2)
+    }
+
+    /// 乘积，指数或质因数之积溢出时为 `None`
+    /// The product; `None` when an exponent or the prime factors overflow
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.combine(rhs, 1, rhs.primes)
+    }
+
+    /// 商，指数或质因数之积溢出时为 `None`
+    /// The quotient; `None` when an exponent or the prime factors overflow
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.combine(rhs, -1, (rhs.primes.1, rhs.primes.0))
+    }
+
+    fn combine(mut self, rhs: Self, sign: i32, (n2, d2): (i128, i128)) -> Option<Self> {
+        for (a, b) in self.exponents.iter_mut().zip(rhs.exponents) {
+            *a = a.checked_add(b.checked_mul(sign)?)?;
+        }
+        let (n1, d2) = reduce(self.primes.0, d2);
+        let (n2, d1) = reduce(n2, self.primes.1);
+        self.primes = reduce(n1.checked_mul(n2)?, d1.checked_mul(d2)?);
+        Some(self)
+    }
+
+    /// 公共细比例（各质因数取较小指数），用于不同比例的量相加减；π的指数不同时为 `None`
+    /// The common finer ratio (the smaller exponent of each prime), used to add or subtract
+    /// quantities of different ratios; `None` when the exponents of π differ
+    pub fn gcd(self, rhs: Self) -> Option<Self> {
+        if self.exponents[1] != rhs.exponents[1] {
+            return None;
+        }
+        let mut exponents = self.exponents;
+        for (a, b) in exponents.iter_mut().zip(rhs.exponents) {
+            *a = (*a).min(b);
+        }
+        // 约分后的分数取分子的最大公约数与分母的最小公倍数即各质因数的较小指数
+        // For reduced fractions, the gcd of the numerators over the lcm of the
+        // denominators takes the smaller exponent of every prime
+        let num = gcd(self.primes.0, rhs.primes.0);
+        let (d1, d2) = (self.primes.1, rhs.primes.1);
+        let den = (d1 / gcd(d1, d2)).checked_mul(d2)?;
+        Some(DynRatio { exponents, primes: (num, den) })
+    }
+}
+
+/// 正整数的最大公约数
+/// Greatest common divisor of positive integers
+fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// 可转换为运行期比例的静态比例
+/// A static ratio that converts to a runtime ratio
+pub trait ToDynRatio {
+    /// 运行期比例 / The runtime ratio
+    fn dyn_ratio() -> DynRatio;
+}
+
+impl<E5, EPi, E3, E2, Primes> ToDynRatio for Ratio<E5, EPi, E3, E2, Primes>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+{
+    fn dyn_ratio() -> DynRatio {
+        DynRatio {
+            exponents: [E5::to_i32(), EPi::to_i32(), E3::to_i32(), E2::to_i32()],
+            primes: Primes::EXACT.expect("prime factors overflow i128"),
+        }
+    }
+}
+
+// ========== 错误 ==========
+// ========== Errors ==========
+
+/// 运行期物理量的运算或转换错误
+/// Arithmetic or conversion error of runtime quantities
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DynError {
+    /// 量纲不符 / The dimensions do not match
+    DimensionMismatch {
+        /// 要求的量纲 / The required dimension
+        expected: DynDimension,
+        /// 实际的量纲 / The actual dimension
+        found: DynDimension,
+    },
+    /// 词头或比例与目标类型不同，或两个比例没有公共细比例
+    /// The prefix or ratio differs from the target type, or two ratios have no common finer ratio
+    ScaleMismatch,
+    /// 数值、词头、量纲或比例的运算溢出，或整数除以零
+    /// The value, prefix, dimension or ratio overflowed, or an integer was divided by zero
+    Overflow,
+}
+
+impl Display for DynError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DynError::DimensionMismatch { expected, found } => {
+                write!(f, "dimension mismatch: expected {}, found {}", expected, found)
+            }
+            DynError::ScaleMismatch => f.write_str("prefix or ratio mismatch"),
+            DynError::Overflow => f.write_str("value overflows the storage type"),
+        }
+    }
+}
+
+// ========== 运行期物理量 ==========
+// ========== Runtime Quantity ==========
+
+/// 运行期物理量：数值 × 比例 × 10^词头，单位为量纲对应的SI单位
+/// Runtime quantity: value × ratio × 10^prefix in the coherent SI unit of the dimension
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DynQuantity<T> {
+    /// 数值 / Value
+    pub value: T,
+    /// 量纲 / Dimension
+    pub dimension: DynDimension,
+    /// 词头指数 / Prefix exponent
+    pub prefix: i32,
+    /// 比例 / Ratio
+    pub ratio: DynRatio,
+}
+
+impl<T: Primitive> DynQuantity<T> {
+    /// 创建运行期物理量
+    /// Creates a runtime quantity
+    pub fn new(value: T, dimension: DynDimension, prefix: i32, ratio: DynRatio) -> Self {
+        DynQuantity { value, dimension, prefix, ratio }
+    }
+
+    fn check_dimension(&self, expected: DynDimension) -> Result<(), DynError> {
+        if self.dimension == expected {
+            Ok(())
+        } else {
+            Err(DynError::DimensionMismatch { expected, found: self.dimension })
+        }
+    }
+}
+
+impl<T: Primitive + Scale> DynQuantity<T> {
+    /// 换算到词头 `prefix`、比例 `ratio`（整数四舍五入）
+    /// Converts to the prefix `prefix` and ratio `ratio` (integers rounded half away from zero)
+    pub fn rescale(self, prefix: i32, ratio: DynRatio) -> Result<Self, DynError> {
+        let relative = self.ratio.checked_div(ratio).ok_or(DynError::Overflow)?;
+        let shift = self.prefix.checked_sub(prefix).ok_or(DynError::Overflow)?;
+        let factor = relative.factor() * Factor::power_of_ten(shift);
+        let value = factor.checked_apply::<T, DefaultRounding>(self.value).ok_or(DynError::Overflow)?;
+        Ok(DynQuantity { value, dimension: self.dimension, prefix, ratio })
+    }
+
+    /// 换算到两者的公共细刻度
+    /// Converts both sides to their common finer scale
+    fn common(self, rhs: Self) -> Result<(Self, Self), DynError> {
+        rhs.check_dimension(self.dimension)?;
+        if (self.prefix, self.ratio) == (rhs.prefix, rhs.ratio) {
+            return Ok((self, rhs));
+        }
+        let prefix = self.prefix.min(rhs.prefix);
+        let ratio = self.ratio.gcd(rhs.ratio).ok_or(DynError::ScaleMismatch)?;
+        Ok((self.rescale(prefix, ratio)?, rhs.rescale(prefix, ratio)?))
+    }
+}
+
+// 加减：量纲须相同，刻度不同时换算到公共细刻度；数值溢出时出错
+// Addition and subtraction: the dimensions must match; differing scales convert to the
+// common finer scale; an overflow of the value is an error
+impl<T: Primitive + Scale + CheckedArith> Add for DynQuantity<T> {
+    type Output = Result<Self, DynError>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let (a, b) = self.common(rhs)?;
+        let value = a.value.checked_add(b.value).ok_or(DynError::Overflow)?;
+        Ok(DynQuantity { value, ..a })
+    }
+}
+
+impl<T: Primitive + Scale + CheckedArith> Sub for DynQuantity<T> {
+    type Output = Result<Self, DynError>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        let (a, b) = self.common(rhs)?;
+        let value = a.value.checked_sub(b.value).ok_or(DynError::Overflow)?;
+        Ok(DynQuantity { value, ..a })
+    }
+}
+
+// 乘除：量纲、词头与比例分别相加减；数值、指数或比例的质因数之积溢出，
+// 以及整数除以零时出错
+// Multiplication and division: dimensions, prefixes and ratios combine separately; an
+// overflow of the value, an exponent or the ratio's prime factors, or an integer
+// division by zero, is an error
+impl<T: Primitive + CheckedArith> Mul for DynQuantity<T> {
+    type Output = Result<Self, DynError>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Ok(DynQuantity {
+            value: self.value.checked_mul(rhs.value).ok_or(DynError::Overflow)?,
+            dimension: self.dimension.checked_mul(rhs.dimension).ok_or(DynError::Overflow)?,
+            prefix: self.prefix.checked_add(rhs.prefix).ok_or(DynError::Overflow)?,
+            ratio: self.ratio.checked_mul(rhs.ratio).ok_or(DynError::Overflow)?,
+        })
+    }
+}
+
+impl<T: Primitive + CheckedArith> Div for DynQuantity<T> {
+    type Output = Result<Self, DynError>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        Ok(DynQuantity {
+            value: self.value.checked_div(rhs.value).ok_or(DynError::Overflow)?,
+            dimension: self.dimension.checked_div(rhs.dimension).ok_or(DynError::Overflow)?,
+            prefix: self.prefix.checked_sub(rhs.prefix).ok_or(DynError::Overflow)?,
+            ratio: self.ratio.checked_div(rhs.ratio).ok_or(DynError::Overflow)?,
+        })
+    }
+}
+
+// ========== 与静态类型的转换 ==========
+// ========== Conversions with Static Types ==========
+
+impl<T, D, Pr> From<Si<Var<T>, D, Pr>> for DynQuantity<T>
+where
+    T: Primitive,
+    D: DimensionNames,
+    Pr: Prefixed,
+{
+    fn from(si: Si<Var<T>, D, Pr>) -> Self {
+        DynQuantity::new(si.0 .0, DynDimension::of::<D>(), Pr::EXPONENT, DynRatio::ONE)
+    }
+}
+
+impl<T, D, Pr, R> From<Unit<Si<Var<T>, D, Pr>, R>> for DynQuantity<T>
+where
+    T: Primitive,
+    D: DimensionNames,
+    Pr: Prefixed,
+    R: Scaled + ToDynRatio,
+{
+    fn from(unit: Unit<Si<Var<T>, D, Pr>, R>) -> Self {
+        DynQuantity::new((unit.0).0 .0, DynDimension::of::<D>(), Pr::EXPONENT, R::dyn_ratio())
+    }
+}
+
+// 只在量纲、词头与比例都与目标类型一致时成功，数值原样保留
+// Succeeds only when dimension, prefix and ratio all match the target type; the value is kept as is
+impl<T, D, Pr> TryFrom<DynQuantity<T>> for Si<Var<T>, D, Pr>
+where
+    T: Primitive,
+    D: DimensionNames,
+    Pr: Prefixed,
+{
+    type Error = DynError;
+
+    fn try_from(q: DynQuantity<T>) -> Result<Self, DynError> {
+        q.check_dimension(DynDimension::of::<D>())?;
+        if q.prefix != Pr::EXPONENT || !q.ratio.is_one() {
+            return Err(DynError::ScaleMismatch);
+        }
+        Ok(Si(Var(q.value), PhantomData))
+    }
+}
+
+impl<T, D, Pr, R> TryFrom<DynQuantity<T>> for Unit<Si<Var<T>, D, Pr>, R>
+where
+    T: Primitive,
+    D: DimensionNames,
+    Pr: Prefixed,
+    R: Scaled + ToDynRatio,
+{
+    type Error = DynError;
+
+    fn try_from(q: DynQuantity<T>) -> Result<Self, DynError> {
+        q.check_dimension(DynDimension::of::<D>())?;
+        if q.prefix != Pr::EXPONENT || q.ratio != R::dyn_ratio() {
+            return Err(DynError::ScaleMismatch);
+        }
+        Ok(Unit(Si(Var(q.value), PhantomData), PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+    use crate::number::{Const, Z0, P1};
+    use crate::quantity::{Kilo, Milli, Hours, Minutes, Seconds, Degrees, Radians, PrimePow, NoRatio, Unit};
+    use crate::quantity::{PlaneAngle, PlaneAngleDimension, SolidAngle, Count, NEWTON_METRE};
+    use crate::quantity::si_units::*;
+
+    #[test]
+    fn test_round_trip() {
+        let km = Meter::<i32, Kilo>::new(3);
+        let q = DynQuantity::from(km);
+        assert_eq!((q.dimension, q.prefix, q.ratio), (DynDimension::new([1, 0, 0, 0, 0, 0, 0], 0), 3, DynRatio::ONE));
+        let back: Meter<i32, Kilo> = q.try_into().unwrap();
+        assert_eq!(back.0 .0, 3);
+
+        let h = DynQuantity::from(Hours::new(2));
+        assert_eq!(h.ratio, DynRatio { exponents: [2, 0, 2, 4], primes: (1, 1) });
+        let back: Hours = h.try_into().unwrap();
+        assert_eq!((back.0).0 .0, 2);
+
+        type Inch = Ratio<Const<-4>, Z0, Z0, Const<-3>, PrimePow<crate::number::B1<crate::number::B1<crate::number::B1<crate::number::B1<crate::number::B1<crate::number::B1<P1>>>>>>, P1>>;
+        assert_eq!(DynRatio::of::<Inch>().primes, (127, 1));
+        assert_eq!(DynRatio::of::<NoRatio>(), DynRatio::ONE);
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let q = DynQuantity::from(Meter::<i32, Kilo>::new(3));
+        let err = Meter::<i32, Milli>::try_from(q).unwrap_err();
+        assert_eq!(err, DynError::ScaleMismatch);
+        let err = Second::<i32, Kilo>::try_from(q).unwrap_err();
+        assert_eq!(format!("{}", err), "dimension mismatch: expected s, found m");
+        assert!(Hours::try_from(DynQuantity::from(Minutes::new(2))).is_err());
+
+        // 换算后可转换 / Convertible after rescaling
+        let mm: Meter<i32, Milli> = q.rescale(-3, DynRatio::ONE).unwrap().try_into().unwrap();
+        assert_eq!(mm.0 .0, 3_000_000);
+        let min = DynQuantity::from(Hours::new(2)).rescale(0, DynRatio::of::<Ratio<P1, Z0, P1, Const<2>>>()).unwrap();
+        assert_eq!(Minutes::try_from(min).unwrap().0 .0 .0, 120);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let sum = (DynQuantity::from(Hours::new(1)) + DynQuantity::from(Minutes::new(30))).unwrap();
+        assert_eq!((sum.value, sum.ratio), (90, DynRatio::of::<Ratio<P1, Z0, P1, Const<2>>>()));
+        let diff = (DynQuantity::from(Minutes::new(2)) - DynQuantity::from(Seconds::new(30))).unwrap();
+        assert_eq!(Seconds::try_from(diff).unwrap().0 .0, 90);
+        let sum = (DynQuantity::from(Meter::<i32, Kilo>::new(1)) + DynQuantity::from(Meter::<i32>::new(5))).unwrap();
+        assert_eq!(Meter::<i32>::try_from(sum).unwrap().0 .0, 1005);
+
+        let v = (DynQuantity::from(Meter::<f64, Kilo>::new(36.0)) / DynQuantity::from(Hours::new(1.0))).unwrap();
+        assert_eq!(v.dimension, DynDimension::of::<VelocityDimension>());
+        assert_eq!((v.prefix, v.ratio.factor()), (3, Factor::Exact(1, 3600)));
+        let v: Velocity = v.rescale(0, DynRatio::ONE).unwrap().try_into().unwrap();
+        assert_eq!(v.0 .0, 10.0);
+
+        let a = (DynQuantity::from(Meter::<f64>::new(2.0)) * DynQuantity::from(Meter::<f64>::new(3.0))).unwrap();
+        assert_eq!(Area::<f64>::try_from(a).unwrap().0 .0, 6.0);
+
+        // π 的指数不同的比例不能相加 / Ratios with different powers of π cannot be added
+        let rad = DynQuantity::from(Radians::<f64>::new(1.0));
+        let deg = DynQuantity::from(Degrees::new(1.0));
+        assert_eq!(rad + deg, Err(DynError::ScaleMismatch));
+        assert!(matches!(rad + DynQuantity::from(<Meter>::new(1.0)), Err(DynError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_kind() {
+        let t = DynQuantity::from(<Torque>::new(1.5));
+        assert_eq!(t.dimension, DynDimension::of::<EnergyDimension>().with_kind(&NEWTON_METRE));
+        assert_eq!(format!("{}", t), "1.5 N·m");
+        assert_eq!(Torque::<f64>::try_from(t).unwrap().0 .0, 1.5);
+
+        // 量纲相同、种类不同的量不可互换 / Same dimension, different kind: not interchangeable
+        let err = Joule::<f64>::try_from(t).unwrap_err();
+        assert_eq!(format!("{}", err), "dimension mismatch: expected J, found N·m");
+        assert!(Becquerel::<f64>::try_from(DynQuantity::from(<Hertz>::new(3.0))).is_err());
+        assert!(matches!(t + DynQuantity::from(<Joule>::new(1.0)), Err(DynError::DimensionMismatch { .. })));
+
+        // 乘除结果不带种类 / Products and quotients are kindless
+        let f = (t / DynQuantity::from(<Meter>::new(0.5))).unwrap();
+        assert_eq!(Newton::<f64>::try_from(f).unwrap().0 .0, 3.0);
+    }
+
+    #[test]
+    fn test_angle() {
+        assert_eq!(DynDimension::of::<PlaneAngleDimension>(), DynDimension::new([0; 7], 1));
+        let w = (DynQuantity::from(<PlaneAngle>::new(3.0)) / DynQuantity::from(<Second>::new(2.0))).unwrap();
+        assert_eq!(format!("{}", w.dimension), "rad·s⁻¹");
+        let sq = (DynQuantity::from(<PlaneAngle>::new(2.0)) * DynQuantity::from(<PlaneAngle>::new(2.0))).unwrap();
+        assert_eq!(SolidAngle::<f64>::try_from(sq).unwrap().0 .0, 4.0);
+
+        // 角度与无量纲数不可互换 / Angles and plain numbers are not interchangeable
+        let err = Count::<f64>::try_from(DynQuantity::from(<PlaneAngle>::new(1.0))).unwrap_err();
+        assert_eq!(format!("{}", err), "dimension mismatch: expected 1, found rad");
+        assert!(PlaneAngle::<f64>::try_from(DynQuantity::from(<Count>::new(1.0))).is_err());
+    }
+
+    #[test]
+    fn test_overflow() {
+        let big = DynQuantity::from(Meter::<i8>::new(100));
+        assert_eq!(big + big, Err(DynError::Overflow));
+        assert_eq!(DynQuantity::from(Meter::<i8>::new(-100)) - big, Err(DynError::Overflow));
+        assert_eq!(big * big, Err(DynError::Overflow));
+        assert_eq!(big / DynQuantity::from(Meter::<i8>::new(0)), Err(DynError::Overflow));
+        assert_eq!(DynQuantity::from(Meter::<i8>::new(i8::MIN)) / DynQuantity::from(Meter::<i8>::new(-1)), Err(DynError::Overflow));
+
+        // 换算到公共刻度后才溢出 / Overflow that only appears after converting to the common scale
+        let km = DynQuantity::from(Meter::<i32, Kilo>::new(i32::MAX / 1000));
+        assert_eq!(km + DynQuantity::from(Meter::<i32>::new(1000)), Err(DynError::Overflow));
+
+        // 词头与量纲指数 / Prefix and dimension exponents
+        let q = DynQuantity::new(1.0, DynDimension::new([i32::MAX, 0, 0, 0, 0, 0, 0], 0), i32::MAX, DynRatio::ONE);
+        assert_eq!(q * q, Err(DynError::Overflow));
+        assert_eq!(q * DynQuantity::new(1.0, DynDimension::DIMENSIONLESS, 1, DynRatio::ONE), Err(DynError::Overflow));
+        assert_eq!(q * DynQuantity::new(1.0, DynDimension::new([1, 0, 0, 0, 0, 0, 0], 0), 0, DynRatio::ONE), Err(DynError::Overflow));
+
+        // 浮点遵循 IEEE 754 / Floats follow IEEE 754
+        let x = DynQuantity::from(<Meter>::new(1.0)) / DynQuantity::from(<Meter>::new(0.0));
+        assert_eq!(x.map(|q| q.value), Ok(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_dimension_display() {
+        assert_eq!(format!("{}", DynDimension::of::<VelocityDimension>()), "m·s⁻¹");
+        assert_eq!(format!("{}", DynDimension::DIMENSIONLESS), "1");
+        assert!(DynDimension::new([0, 0, 1, 0, 0, 0, 0], 0).is::<TimeDimension>());
+
+        // 与静态类型的显示一致 / Displays like the static types
+        assert_eq!(format!("{}", DynQuantity::from(Meter::<i32, Kilo>::new(3))), "3 km");
+        assert_eq!(format!("{}", DynQuantity::from(Hours::new(2))), "2 h");
+        assert_eq!(format!("{}", DynQuantity::from(Unit::<Meter<i32>, Ratio<P1, Z0, P1, Const<2>>>::new(3))), "3×60 m");
+    }
+}
@@ -0,0 +1,427 @@
+//! 自定义基本量纲
+//! Custom base dimensions
+//!
+//! [`Dimension`] 固定为 SI 的七个基本量纲。信息量（bit）、货币、像素、物品个数等需要
+//! 作为独立基本量纲时，实现 [`BaseSystem`] 声明至多四个自定义基本单位，再用
+//! [`Extended<D, S, E1, E2, E3, E4>`] 在 SI 量纲 `D` 之外附加各自定义基本量纲的指数。
+//! 乘除时指数相加减，全部归零时还原为 `D` 本身；不同 `BaseSystem` 的量不能相乘除，
+//! 也不能相加减。显示时自定义基本单位与 SI 单位一起写出，如 `kbit·s⁻¹`。
+//! 与平面角相乘除时角度在外层（`WithAngle<Extended<..>, A>`），与带种类的量相乘除时
+//! 种类被去掉。运行期量纲 [`DynDimension`](super::DynDimension) 与解析器不支持自定义
+//! 基本量纲，转换为 `DynQuantity` 或从字符串解析时无法编译。
+//! [`Dimension`] is fixed at the seven SI base dimensions. When information (bit),
+//! currency, pixels or counts of items need to be independent base dimensions,
+//! implement [`BaseSystem`] to declare up to four custom base units and use
+//! [`Extended<D, S, E1, E2, E3, E4>`] to attach their exponents to the SI dimension
+//! `D`. Multiplication and division add and subtract the exponents, and all-zero
+//! exponents collapse back to `D` itself; quantities of different `BaseSystem`s can
+//! neither be multiplied nor added. Display writes the custom base units together
+//! with the SI ones, as in `kbit·s⁻¹`. With plane angles the angle stays outermost
+//! (`WithAngle<Extended<..>, A>`), and with kinded quantities the kind is dropped.
+//! The runtime [`DynDimension`](super::DynDimension) and the parser do not support
+//! custom base dimensions; converting to a `DynQuantity` or parsing from a string
+//! does not compile.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::number::{Const, Var};
+//! use unitrix::quantity::{BaseSystem, Extended, Kilo, NoPrefix, Si, UnitNames};
+//! use unitrix::quantity::si_units::{Dimensionless, FrequencyDimension, Second};
+//!
+//! #[derive(Debug, Clone, Copy)]
+//! struct Information;
+//!
+//! impl BaseSystem for Information {
+//!     const BASES: &'static [UnitNames] = &[UnitNames::plain("bit")];
+//! }
+//!
+//! type Bit<V = f64, Pr = NoPrefix> = Si<Var<V>, Extended<Dimensionless, Information, Const<1>>, Pr>;
+//! type BitRate<V = f64, Pr = NoPrefix> = Si<Var<V>, Extended<FrequencyDimension, Information, Const<1>>, Pr>;
+//!
+//! let rate: BitRate<f64, Kilo> = Bit::<f64, Kilo>::new(64.0) / <Second>::new(2.0);
+//! assert_eq!(format!("{}", rate), "32 kbit·s⁻¹");
+//! ```
+//!
+//! 不同基本量纲体系的量相乘无法编译：
+//! Multiplying quantities of different base systems does not compile:
+//! ```compile_fail
+//! use unitrix::number::{Const, Var};
+//! use unitrix::quantity::{BaseSystem, Extended, NoPrefix, Si, UnitNames};
+//! use unitrix::quantity::si_units::Dimensionless;
+//!
+//! #[derive(Debug, Clone, Copy)]
+//! struct Information;
+//! impl BaseSystem for Information {
+//!     const BASES: &'static [UnitNames] = &[UnitNames::plain("bit")];
+//! }
+//!
+//! #[derive(Debug, Clone, Copy)]
+//! struct Money;
+//! impl BaseSystem for Money {
+//!     const BASES: &'static [UnitNames] = &[UnitNames::plain("USD")];
+//! }
+//!
+//! let bits: Si<Var<f64>, Extended<Dimensionless, Information, Const<1>>, NoPrefix> = Si::new(8.0);
+//! let price: Si<Var<f64>, Extended<Dimensionless, Money, Const<1>>, NoPrefix> = Si::new(2.0);
+//! let _ = price / bits;
+//! ```
+//!
+//! 转换为运行期物理量无法编译：
+//! Converting to a runtime quantity does not compile:
+//! ```compile_fail
+//! use unitrix::number::{Const, Var};
+//! use unitrix::quantity::{BaseSystem, DynQuantity, Extended, NoPrefix, Si, UnitNames};
+//! use unitrix::quantity::si_units::Dimensionless;
+//!
+//! #[derive(Debug, Clone, Copy)]
+//! struct Information;
+//! impl BaseSystem for Information {
+//!     const BASES: &'static [UnitNames] = &[UnitNames::plain("bit")];
+//! }
+//!
+//! let bits: Si<Var<f64>, Extended<Dimensionless, Information, Const<1>>, NoPrefix> = Si::new(8.0);
+//! let _ = DynQuantity::from(bits);
+//! ```
+//!
+//! 没有写法的槽位指数不为零时无法编译：
+//! A non-zero exponent in a slot without a spelling does not compile:
+//! ```compile_fail
+//! use unitrix::number::{Const, Var};
+//! use unitrix::quantity::{BaseSystem, Extended, NoPrefix, Si, UnitNames};
+//! use unitrix::quantity::si_units::Dimensionless;
+//!
+//! #[derive(Debug, Clone, Copy)]
+//! struct Information;
+//! impl BaseSystem for Information {
+//!     const BASES: &'static [UnitNames] = &[UnitNames::plain("bit")];
+//! }
+//!
+//! let pixels: Si<Var<f64>, Extended<Dimensionless, Information, Const<0>, Const<1>>, NoPrefix> = Si::new(8.0);
+//! let _ = format!("{}", pixels);
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{Add, Sub, Mul, Div, Neg};
+
+use crate::sealed::Sealed;
+use crate::number::{TypedInt, NonZero, Z0, P1, N1, B0, B1};
+use super::{Dimension, Dimensional, UnitNames};
+
+// ========== 基本量纲体系 ==========
+// ========== Base Systems ==========
+
+/// 一组自定义基本量纲
+/// A set of custom base dimensions
+///
+/// `BASES` 依次给出 [`Extended`] 各指数槽位的基本单位写法，至多四个；没有写法的槽位
+/// 指数须为零。两条都在编译期检查。
+/// `BASES` lists the spellings of the base units for the exponent slots of
+/// [`Extended`] in order, at most four; slots without spellings must keep a zero
+/// exponent. Both rules are checked at compile time.
+pub trait BaseSystem: Copy + 'static {
+    /// 基本单位写法 / Base unit spellings
+    const BASES: &'static [UnitNames];
+}
+
+// ========== 扩展量纲 ==========
+// ========== Extended Dimension ==========
+
+/// 在 SI 量纲 `D` 之外附加体系 `S` 的自定义基本量纲指数 `E1`…`E4`
+/// The SI dimension `D` with the exponents `E1`…`E4` of the custom base dimensions of the system `S`
+///
+/// 通常通过别名书写；指数全为零时运算结果还原为 `D` 本身。
+/// Usually written through aliases; results whose exponents are all zero collapse to `D` itself.
+#[derive(Debug, Clone, Copy)]
+pub struct Extended<D, S, E1 = Z0, E2 = Z0, E3 = Z0, E4 = Z0>(D, PhantomData<(S, E1, E2, E3, E4)>);
+
+impl<D, S, E1, E2, E3, E4> Sealed for Extended<D, S, E1, E2, E3, E4>
+where
+    D: Dimensional, S: BaseSystem, E1: TypedInt, E2: TypedInt, E3: TypedInt, E4: TypedInt,
+{
+}
+
+impl<D, S, E1, E2, E3, E4> Dimensional for Extended<D, S, E1, E2, E3, E4>
+where
+    D: Dimensional, S: BaseSystem, E1: TypedInt, E2: TypedInt, E3: TypedInt, E4: TypedInt,
+{
+    const EXPONENTS: [i32; 7] = D::EXPONENTS;
+    const ANGLE: i32 = D::ANGLE;
+    const EXTRA_EXPONENTS: [i32; 4] = {
+        let exponents = [E1::I32, E2::I32, E3::I32, E4::I32];
+        assert!(S::BASES.len() <= 4, "a BaseSystem declares at most four base units");
+        let mut i = S::BASES.len();
+        while i < 4 {
+            assert!(exponents[i] == 0, "an Extended exponent slot has no base unit in its BaseSystem");
+            i += 1;
+        }
+        exponents
+    };
+}
+
+/// 给量纲附加自定义基本量纲的指数 `(E1, E2, E3, E4)`，全为零时不附加
+/// Attaches the custom base exponents `(E1, E2, E3, E4)` to a dimension; all zeros attach nothing
+pub trait AttachBases<D: Dimensional, S: BaseSystem> {
+    /// 结果量纲 / Resulting dimension
+    type Output: Dimensional;
+
+    /// 附加指数 / Attaches the exponents
+    fn attach(dimension: D) -> Self::Output;
+}
+
+impl<D: Dimensional, S: BaseSystem> AttachBases<D, S> for (Z0, Z0, Z0, Z0) {
+    type Output = D;
+
+    fn attach(dimension: D) -> D {
+        dimension
+    }
+}
+
+// 按第一个非零指数的位置与形式逐一实现
+// Implemented by the position and form of the first non-zero exponent
+macro_rules! impl_attach_bases {
+    ($([$($g:tt)*] $nz:ty;)*) => {
+        $(
+            impl<D: Dimensional, S: BaseSystem, E2: TypedInt, E3: TypedInt, E4: TypedInt, $($g)*>
+                AttachBases<D, S> for ($nz, E2, E3, E4)
+            {
+                type Output = Extended<D, S, $nz, E2, E3, E4>;
+
+                fn attach(dimension: D) -> Self::Output {
+                    Extended(dimension, PhantomData)
+                }
+            }
+
+            impl<D: Dimensional, S: BaseSystem, E3: TypedInt, E4: TypedInt, $($g)*>
+                AttachBases<D, S> for (Z0, $nz, E3, E4)
+            {
+                type Output = Extended<D, S, Z0, $nz, E3, E4>;
+
+                fn attach(dimension: D) -> Self::Output {
+                    Extended(dimension, PhantomData)
+                }
+            }
+
+            impl<D: Dimensional, S: BaseSystem, E4: TypedInt, $($g)*>
+                AttachBases<D, S> for (Z0, Z0, $nz, E4)
+            {
+                type Output = Extended<D, S, Z0, Z0, $nz, E4>;
+
+                fn attach(dimension: D) -> Self::Output {
+                    Extended(dimension, PhantomData)
+                }
+            }
+
+            impl<D: Dimensional, S: BaseSystem, $($g)*> AttachBases<D, S> for (Z0, Z0, Z0, $nz) {
+                type Output = Extended<D, S, Z0, Z0, Z0, $nz>;
+
+                fn attach(dimension: D) -> Self::Output {
+                    Extended(dimension, PhantomData)
+                }
+            }
+        )*
+    };
+}
+
+impl_attach_bases! {
+    [] P1;
+    [] N1;
+    [H: NonZero] B0<H>;
+    [H: NonZero] B1<H>;
+}
+
+// ========== 运算 ==========
+// ========== Arithmetic ==========
+
+/// 两组指数之和 / Sum of two exponent sets
+type SumOf<A1, B1, C1, E1, A2, B2, C2, E2> = (
+    <A1 as Add<A2>>::Output,
+    <B1 as Add<B2>>::Output,
+    <C1 as Add<C2>>::Output,
+    <E1 as Add<E2>>::Output,
+);
+
+/// 两组指数之差 / Difference of two exponent sets
+type DiffOf<A1, B1, C1, E1, A2, B2, C2, E2> = (
+    <A1 as Sub<A2>>::Output,
+    <B1 as Sub<B2>>::Output,
+    <C1 as Sub<C2>>::Output,
+    <E1 as Sub<E2>>::Output,
+);
+
+/// 一组指数的相反数 / Negation of an exponent set
+type NegOf<A, B, C, E> = (<A as Neg>::Output, <B as Neg>::Output, <C as Neg>::Output, <E as Neg>::Output);
+
+// 同一体系的扩展量纲相乘除；不同体系之间没有实现
+// Extended dimensions of one system multiply and divide; different systems have no implementation
+impl<D1, A1, B1, C1, E1, D2, A2, B2, C2, E2, S> Mul<Extended<D2, S, A2, B2, C2, E2>> for Extended<D1, S, A1, B1, C1, E1>
+where
+    S: BaseSystem,
+    D1: Mul<D2, Output: Dimensional>,
+    A1: Add<A2>, B1: Add<B2>, C1: Add<C2>, E1: Add<E2>,
+    SumOf<A1, B1, C1, E1, A2, B2, C2, E2>: AttachBases<<D1 as Mul<D2>>::Output, S>,
+{
+    type Output = <SumOf<A1, B1, C1, E1, A2, B2, C2, E2> as AttachBases<<D1 as Mul<D2>>::Output, S>>::Output;
+
+    fn mul(self, rhs: Extended<D2, S, A2, B2, C2, E2>) -> Self::Output {
+        <SumOf<A1, B1, C1, E1, A2, B2, C2, E2>>::attach(self.0 * rhs.0)
+    }
+}
+
+impl<D1, A1, B1, C1, E1, D2, A2, B2, C2, E2, S> Div<Extended<D2, S, A2, B2, C2, E2>> for Extended<D1, S, A1, B1, C1, E1>
+where
+    S: BaseSystem,
+    D1: Div<D2, Output: Dimensional>,
+    A1: Sub<A2>, B1: Sub<B2>, C1: Sub<C2>, E1: Sub<E2>,
+    DiffOf<A1, B1, C1, E1, A2, B2, C2, E2>: AttachBases<<D1 as Div<D2>>::Output, S>,
+{
+    type Output = <DiffOf<A1, B1, C1, E1, A2, B2, C2, E2> as AttachBases<<D1 as Div<D2>>::Output, S>>::Output;
+
+    fn div(self, rhs: Extended<D2, S, A2, B2, C2, E2>) -> Self::Output {
+        <DiffOf<A1, B1, C1, E1, A2, B2, C2, E2>>::attach(self.0 / rhs.0)
+    }
+}
+
+impl<D1, S, A1, B1, C1, E1, M, KG, SE, A, K, MOL, CD> Mul<Dimension<M, KG, SE, A, K, MOL, CD>>
+    for Extended<D1, S, A1, B1, C1, E1>
+where
+    M: TypedInt, KG: TypedInt, SE: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+    S: BaseSystem,
+    D1: Mul<Dimension<M, KG, SE, A, K, MOL, CD>, Output: Dimensional>,
+    A1: TypedInt, B1: TypedInt, C1: TypedInt, E1: TypedInt,
+{
+    type Output = Extended<<D1 as Mul<Dimension<M, KG, SE, A, K, MOL, CD>>>::Output, S, A1, B1, C1, E1>;
+
+    fn mul(self, rhs: Dimension<M, KG, SE, A, K, MOL, CD>) -> Self::Output {
+        Extended(self.0 * rhs, PhantomData)
+    }
+}
+
+impl<D1, S, A1, B1, C1, E1, M, KG, SE, A, K, MOL, CD> Div<Dimension<M, KG, SE, A, K, MOL, CD>>
+    for Extended<D1, S, A1, B1, C1, E1>
+where
+    M: TypedInt, KG: TypedInt, SE: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+    S: BaseSystem,
+    D1: Div<Dimension<M, KG, SE, A, K, MOL, CD>, Output: Dimensional>,
+    A1: TypedInt, B1: TypedInt, C1: TypedInt, E1: TypedInt,
+{
+    type Output = Extended<<D1 as Div<Dimension<M, KG, SE, A, K, MOL, CD>>>::Output, S, A1, B1, C1, E1>;
+
+    fn div(self, rhs: Dimension<M, KG, SE, A, K, MOL, CD>) -> Self::Output {
+        Extended(self.0 / rhs, PhantomData)
+    }
+}
+
+impl<M, KG, SE, A, K, MOL, CD, D2, S, A2, B2, C2, E2> Mul<Extended<D2, S, A2, B2, C2, E2>>
+    for Dimension<M, KG, SE, A, K, MOL, CD>
+where
+    M: TypedInt, KG: TypedInt, SE: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+    S: BaseSystem,
+    Self: Mul<D2, Output: Dimensional>,
+    A2: TypedInt, B2: TypedInt, C2: TypedInt, E2: TypedInt,
+{
+    type Output = Extended<<Self as Mul<D2>>::Output, S, A2, B2, C2, E2>;
+
+    fn mul(self, rhs: Extended<D2, S, A2, B2, C2, E2>) -> Self::Output {
+        Extended(self * rhs.0, PhantomData)
+    }
+}
+
+impl<M, KG, SE, A, K, MOL, CD, D2, S, A2, B2, C2, E2> Div<Extended<D2, S, A2, B2, C2, E2>>
+    for Dimension<M, KG, SE, A, K, MOL, CD>
+where
+    M: TypedInt, KG: TypedInt, SE: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+    S: BaseSystem,
+    Self: Div<D2, Output: Dimensional>,
+    A2: Neg, B2: Neg, C2: Neg, E2: Neg,
+    NegOf<A2, B2, C2, E2>: AttachBases<<Self as Div<D2>>::Output, S>,
+{
+    type Output = <NegOf<A2, B2, C2, E2> as AttachBases<<Self as Div<D2>>::Output, S>>::Output;
+
+    fn div(self, rhs: Extended<D2, S, A2, B2, C2, E2>) -> Self::Output {
+        <NegOf<A2, B2, C2, E2>>::attach(self / rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+    use crate::number::Var;
+    use crate::quantity::{Kilo, Mega, NoPrefix, Si, Style, Count, PlaneAngle, WithAngle};
+    use crate::quantity::si_units::{Dimensionless, FrequencyDimension, LengthDimension, Meter, Second, Torque, Joule, EnergyDimension};
+
+    #[derive(Debug, Clone, Copy)]
+    struct Media;
+
+    impl BaseSystem for Media {
+        const BASES: &'static [UnitNames] = &[
+            UnitNames {
+                symbol: "bit",
+                ascii: "bit",
+                latex: "\\bit",
+                singular: "bit",
+                plural: "bits",
+                chinese: "比特",
+            },
+            UnitNames::plain("px"),
+        ];
+    }
+
+    type Bit<V = f64, Pr = NoPrefix> = Si<Var<V>, Extended<Dimensionless, Media, P1>, Pr>;
+    type Pixel<V = f64, Pr = NoPrefix> = Si<Var<V>, Extended<Dimensionless, Media, Z0, P1>, Pr>;
+    type BitRate<V = f64, Pr = NoPrefix> = Si<Var<V>, Extended<FrequencyDimension, Media, P1>, Pr>;
+    type PixelDensity<V = f64, Pr = NoPrefix> =
+        Si<Var<V>, Extended<<Dimensionless as Div<LengthDimension>>::Output, Media, Z0, P1>, Pr>;
+
+    #[test]
+    fn test_extended_algebra() {
+        let rate: BitRate = <Bit>::new(8.0) / <Second>::new(2.0);
+        assert_eq!(rate.0 .0, 4.0);
+        let bits: Bit = rate * <Second>::new(3.0);
+        assert_eq!(bits.0 .0, 12.0);
+        let ratio: Count = bits / <Bit>::new(4.0);
+        assert_eq!(ratio.into_inner(), 3.0);
+
+        let density: PixelDensity = <Pixel>::new(300.0) / <Meter>::new(0.1);
+        assert_eq!(density.0 .0, 3000.0);
+        let per_bit = <Pixel>::new(6.0) / bits;
+        let back: Pixel = per_bit * <Bit>::new(2.0);
+        assert_eq!(back.0 .0, 1.0);
+        let per_second: Si<Var<f64>, Extended<FrequencyDimension, Media, Z0, N1>, NoPrefix> =
+            <Count>::new(1.0) / (<Pixel>::new(2.0) * <Second>::new(1.0));
+        assert_eq!(per_second.0 .0, 0.5);
+    }
+
+    #[test]
+    fn test_with_angle_and_kind() {
+        // 两种相乘顺序得到同一类型 / Both orders of multiplication give the same type
+        type BitAngle = Si<Var<f64>, WithAngle<Extended<Dimensionless, Media, P1>, P1>, NoPrefix>;
+        let a: BitAngle = <Bit>::new(2.0) * <PlaneAngle>::new(3.0);
+        let b: BitAngle = <PlaneAngle>::new(3.0) * <Bit>::new(2.0);
+        assert_eq!(format!("{}", a + b), "12 bit·rad");
+        let bits: Bit = a / <PlaneAngle>::new(2.0);
+        assert_eq!(bits.0 .0, 3.0);
+        let angle: PlaneAngle = b / <Bit>::new(6.0);
+        assert_eq!(angle.0 .0, 1.0);
+        assert_eq!(format!("{}", <Bit>::new(1.0) / <PlaneAngle>::new(2.0)), "0.5 bit·rad⁻¹");
+
+        // 种类被去掉 / The kind is dropped
+        let energy: Si<Var<f64>, Extended<EnergyDimension, Media, P1>, NoPrefix> = <Torque>::new(2.0) * <Bit>::new(3.0);
+        assert_eq!(format!("{}", energy), "6 bit·m²·kg·s⁻²");
+        let joule: Joule = (<Torque>::new(2.0) * <Bit>::new(3.0)) / <Bit>::new(6.0);
+        assert_eq!(joule.0 .0, 1.0);
+    }
+
+    #[test]
+    fn test_extended_display() {
+        assert_eq!(format!("{}", <Bit>::new(8.0)), "8 bit");
+        assert_eq!(format!("{}", BitRate::<f64, Mega>::new(1.5)), "1.5 Mbit·s⁻¹");
+        assert_eq!(format!("{}", BitRate::<f64, Kilo>::new(2.0).format().solidus(true)), "2 kbit/s");
+        assert_eq!(format!("{}", BitRate::<f64>::new(2.0).format().style(Style::Long)), "2 bits per second");
+        assert_eq!(format!("{}", <PixelDensity>::new(3.0)), "3 px·m⁻¹");
+        assert_eq!(format!("{}", <Pixel>::new(1.0) / <Bit>::new(1.0)), "1 px·bit⁻¹");
+        assert_eq!(format!("{}", <Bit>::new(1.0) * <Meter>::new(2.0) * <Pixel>::new(1.0)), "2 bit·px·m");
+    }
+}
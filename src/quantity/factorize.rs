@@ -0,0 +1,247 @@
+// factorize.rs
+//! 编译期质因数分解
+//! Compile-time prime factorisation
+//!
+//! 将类型级正整数分解为 2、3、5 的幂次，得到对应的 [`Ratio`]。
+//! 含有其它质因数（如 7）的整数没有 `Factorize` 实现，会在编译期报错。
+//! Decomposes a type-level positive integer into powers of 2, 3 and 5 and yields
+//! the matching [`Ratio`]. Integers with any other prime factor (such as 7) have
+//! no `Factorize` implementation and fail to compile.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::number::Const;
+//! use unitrix::quantity::{Ratio, RatioOf};
+//!
+//! // 60 = 5^1 * 3^1 * 2^2
+//! let _: Ratio<Const<1>, Const<0>, Const<1>, Const<2>> = RatioOf::<Const<60>>::default();
+//! ```
+
+use core::ops::Mul;
+
+use crate::number::{Z0, P1, B0, B1, O, I, NonZero, IfB0, IfB1};
+use super::ratio::Ratio;
+
+// 除数与余数使用的具体类型（避免在约束中出现 `Const` 投影）
+// Concrete types used for divisors and remainders (avoids `Const` projections in bounds)
+type Two = B0<P1>;
+type Three = B1<P1>;
+type Four = B0<B0<P1>>;
+type Five = B1<B0<P1>>;
+
+// ========== 小除数的带余除法 ==========
+// ========== Division with Remainder by a Small Divisor ==========
+
+/// 类型级正整数除以小除数 `D`（3 或 5）的商与余数
+/// Quotient and remainder of a type-level positive integer divided by a small divisor `D` (3 or 5)
+///
+/// 从最高位开始逐位计算，与笔算除法相同。
+/// Computed bit by bit from the most significant bit, like long division.
+pub trait SmallDivRem<D> {
+    /// 商 / Quotient
+    type Quot;
+    /// 余数 / Remainder
+    type Rem;
+}
+
+/// 长除法的一步：当前余数 `Self` 左移并移入比特 `Bit`
+/// One step of long division: shift the current remainder `Self` and bring in `Bit`
+pub trait DivStep<D, Bit> {
+    /// 商的比特位 / Quotient bit
+    type QBit;
+    /// 新余数 / New remainder
+    type Rem;
+}
+
+/// 将商的比特位追加到已得的商 `Q` 的低位
+/// Appends a quotient bit to the low end of the partial quotient `Q`
+pub trait PushBit<Q> {
+    /// 新的商 / New quotient
+    type Output;
+}
+
+impl<Q: IfB0> PushBit<Q> for O {
+    type Output = Q::Output;
+}
+
+impl<Q: IfB1> PushBit<Q> for I {
+    type Output = Q::Output;
+}
+
+// P1 = B1<Z0>：从余数0开始移入比特1
+// P1 = B1<Z0>: start from remainder 0 and bring in a 1 bit
+impl<D> SmallDivRem<D> for P1
+where
+    Z0: DivStep<D, I>,
+    <Z0 as DivStep<D, I>>::QBit: PushBit<Z0>,
+{
+    type Quot = <<Z0 as DivStep<D, I>>::QBit as PushBit<Z0>>::Output;
+    type Rem = <Z0 as DivStep<D, I>>::Rem;
+}
+
+impl<D, H: NonZero + SmallDivRem<D>> SmallDivRem<D> for B0<H>
+where
+    H::Rem: DivStep<D, O>,
+    <H::Rem as DivStep<D, O>>::QBit: PushBit<H::Quot>,
+{
+    type Quot = <<H::Rem as DivStep<D, O>>::QBit as PushBit<H::Quot>>::Output;
+    type Rem = <H::Rem as DivStep<D, O>>::Rem;
+}
+
+impl<D, H: NonZero + SmallDivRem<D>> SmallDivRem<D> for B1<H>
+where
+    H::Rem: DivStep<D, I>,
+    <H::Rem as DivStep<D, I>>::QBit: PushBit<H::Quot>,
+{
+    type Quot = <<H::Rem as DivStep<D, I>>::QBit as PushBit<H::Quot>>::Output;
+    type Rem = <H::Rem as DivStep<D, I>>::Rem;
+}
+
+// ----- 除以3：2r + b -> (q, r') -----
+// ----- Divide by 3: 2r + b -> (q, r') -----
+impl DivStep<Three, O> for Z0 { type QBit = O; type Rem = Z0; }         // 0 -> (0, 0)
+impl DivStep<Three, I> for Z0 { type QBit = O; type Rem = P1; }         // 1 -> (0, 1)
+impl DivStep<Three, O> for P1 { type QBit = O; type Rem = Two; }        // 2 -> (0, 2)
+impl DivStep<Three, I> for P1 { type QBit = I; type Rem = Z0; }         // 3 -> (1, 0)
+impl DivStep<Three, O> for Two { type QBit = I; type Rem = P1; }        // 4 -> (1, 1)
+impl DivStep<Three, I> for Two { type QBit = I; type Rem = Two; }       // 5 -> (1, 2)
+
+// ----- 除以5：2r + b -> (q, r') -----
+// ----- Divide by 5: 2r + b -> (q, r') -----
+impl DivStep<Five, O> for Z0 { type QBit = O; type Rem = Z0; }          // 0 -> (0, 0)
+impl DivStep<Five, I> for Z0 { type QBit = O; type Rem = P1; }          // 1 -> (0, 1)
+impl DivStep<Five, O> for P1 { type QBit = O; type Rem = Two; }         // 2 -> (0, 2)
+impl DivStep<Five, I> for P1 { type QBit = O; type Rem = Three; }       // 3 -> (0, 3)
+impl DivStep<Five, O> for Two { type QBit = O; type Rem = Four; }       // 4 -> (0, 4)
+impl DivStep<Five, I> for Two { type QBit = I; type Rem = Z0; }         // 5 -> (1, 0)
+impl DivStep<Five, O> for Three { type QBit = I; type Rem = P1; }       // 6 -> (1, 1)
+impl DivStep<Five, I> for Three { type QBit = I; type Rem = Two; }      // 7 -> (1, 2)
+impl DivStep<Five, O> for Four { type QBit = I; type Rem = Three; }     // 8 -> (1, 3)
+impl DivStep<Five, I> for Four { type QBit = I; type Rem = Four; }      // 9 -> (1, 4)
+
+// ========== 质因数分解 ==========
+// ========== Prime Factorisation ==========
+
+/// 类型运算符：把正整数分解为 `Ratio<Exp5, ExpPi, Exp3, Exp2>`
+/// Type operator: factorises a positive integer into `Ratio<Exp5, ExpPi, Exp3, Exp2>`
+///
+/// 仅对质因数全部属于 {2, 3, 5} 的正整数实现。
+/// Implemented only for positive integers whose prime factors all lie in {2, 3, 5}.
+pub trait Factorize {
+    /// 对应的比例因子 / The matching ratio
+    type Output;
+}
+
+/// 正整数对应的比例因子
+/// The ratio of a positive integer
+///
+/// 超出 [`Const`](crate::number::Const) 范围的整数可用乘积构造，如 86400 = 60 × 60 × 24：
+/// Integers beyond the range of [`Const`](crate::number::Const) can be built as products, e.g. 86400 = 60 × 60 × 24:
+/// `RatioOf<<<Const<60> as Mul<Const<60>>>::Output as Mul<Const<24>>>::Output>`
+pub type RatioOf<N> = <N as Factorize>::Output;
+
+// 1 = 无比例
+// 1 = no ratio
+impl Factorize for P1 {
+    type Output = Ratio<Z0, Z0, Z0, Z0>;
+}
+
+// 偶数：提取一个因子2
+// Even numbers: take out a factor of 2
+impl<H: NonZero + Factorize> Factorize for B0<H>
+where
+    Ratio<Z0, Z0, Z0, P1>: Mul<H::Output>,
+{
+    type Output = <Ratio<Z0, Z0, Z0, P1> as Mul<H::Output>>::Output;
+}
+
+// 大于1的奇数：先试除3，再试除5
+// Odd numbers above 1: try 3 first, then 5
+impl<H: NonZero> Factorize for B1<H>
+where
+    B1<H>: SmallDivRem<Three>,
+    B1<H>: FactorBy3<<B1<H> as SmallDivRem<Three>>::Rem>,
+{
+    type Output = <B1<H> as FactorBy3<<B1<H> as SmallDivRem<Three>>::Rem>>::Output;
+}
+
+/// 根据除以3的余数 `Rem` 继续分解
+/// Continues the factorisation according to the remainder `Rem` of a division by 3
+pub trait FactorBy3<Rem> {
+    /// 对应的比例因子 / The matching ratio
+    type Output;
+}
+
+/// 根据除以5的余数 `Rem` 继续分解
+/// Continues the factorisation according to the remainder `Rem` of a division by 5
+pub trait FactorBy5<Rem> {
+    /// 对应的比例因子 / The matching ratio
+    type Output;
+}
+
+// 能被3整除：提取一个因子3
+// Divisible by 3: take out a factor of 3
+impl<N> FactorBy3<Z0> for N
+where
+    N: SmallDivRem<Three, Quot: Factorize>,
+    Ratio<Z0, Z0, P1, Z0>: Mul<<N::Quot as Factorize>::Output>,
+{
+    type Output = <Ratio<Z0, Z0, P1, Z0> as Mul<<N::Quot as Factorize>::Output>>::Output;
+}
+
+// 不能被3整除：试除5
+// Not divisible by 3: try 5
+impl<N> FactorBy3<P1> for N
+where
+    N: SmallDivRem<Five>,
+    N: FactorBy5<<N as SmallDivRem<Five>>::Rem>,
+{
+    type Output = <N as FactorBy5<<N as SmallDivRem<Five>>::Rem>>::Output;
+}
+
+impl<N> FactorBy3<Two> for N
+where
+    N: SmallDivRem<Five>,
+    N: FactorBy5<<N as SmallDivRem<Five>>::Rem>,
+{
+    type Output = <N as FactorBy5<<N as SmallDivRem<Five>>::Rem>>::Output;
+}
+
+// 能被5整除：提取一个因子5；否则含有不支持的质因数，无实现
+// Divisible by 5: take out a factor of 5; otherwise an unsupported prime remains and there is no impl
+impl<N> FactorBy5<Z0> for N
+where
+    N: SmallDivRem<Five, Quot: Factorize>,
+    Ratio<P1, Z0, Z0, Z0>: Mul<<N::Quot as Factorize>::Output>,
+{
+    type Output = <Ratio<P1, Z0, Z0, Z0> as Mul<<N::Quot as Factorize>::Output>>::Output;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{N1, Const};
+
+    #[test]
+    fn test_small_div_rem() {
+        let _: Z0 = <<P1 as SmallDivRem<Const<3>>>::Quot>::default();
+        let _: P1 = <<P1 as SmallDivRem<Const<3>>>::Rem>::default();
+        let _: Const<20> = <<Const<60> as SmallDivRem<Const<3>>>::Quot>::default();
+        let _: Const<12> = <<Const<64> as SmallDivRem<Const<5>>>::Quot>::default();
+        let _: Const<4> = <<Const<64> as SmallDivRem<Const<5>>>::Rem>::default();
+    }
+
+    #[test]
+    fn test_factorize() {
+        let _: Ratio<Z0, Z0, Z0, Z0> = RatioOf::<P1>::default();
+        let _: Ratio<Z0, Z0, Z0, Const<6>> = RatioOf::<Const<64>>::default();
+        let _: Ratio<Z0, Z0, Const<2>, Z0> = RatioOf::<Const<9>>::default();
+        let _: Ratio<P1, Z0, P1, Const<2>> = RatioOf::<Const<60>>::default();
+        let _: Ratio<Const<2>, Z0, Z0, P1> = RatioOf::<Const<50>>::default();
+
+        // 86400 = 5^2 * 3^3 * 2^7
+        type D = <<Const<60> as Mul<Const<60>>>::Output as Mul<Const<24>>>::Output;
+        let _: Ratio<Const<2>, Z0, Const<3>, Const<7>> = RatioOf::<D>::default();
+        let _: Ratio<N1, Z0, Z0, Z0> = <Ratio<Z0, Z0, Z0, Z0> as core::ops::Div<RatioOf<Const<5>>>>::Output::default();
+    }
+}
@@ -0,0 +1,49 @@
+//! 量纲的单位写法
+//! Unit spellings of dimensions
+//!
+//! [`Dimensional`] 只描述量纲本身（各指数）；显示、解析与运行期量纲所需的写法放在
+//! [`DimensionNames`]：带种类的量纲用种类的单位写法，带自定义基本量纲的量纲给出各自定义
+//! 基本单位的写法。SI量纲与仅带角度的量纲两者都为空。
+//! [`Dimensional`] describes the dimension itself (its exponents); the spellings needed
+//! by display, parsing and runtime dimensions live in [`DimensionNames`]: a kinded
+//! dimension uses the unit spellings of its kind, and a dimension with custom base
+//! dimensions gives the spellings of its custom base units. Both are empty for SI
+//! dimensions and for dimensions that only add an angle.
+
+use crate::number::TypedInt;
+use crate::quantity::{Dimension, Dimensional, WithAngle, Kinded, Kind, Extended, BaseSystem};
+use super::UnitNames;
+
+/// 量纲的单位写法
+/// Unit spellings of a dimension
+pub trait DimensionNames: Dimensional {
+    /// 种类专用的单位写法（见 [`Kinded`]），无种类时为 `None`
+    /// Unit spellings specific to the kind (see [`Kinded`]); `None` without a kind
+    const KIND_NAMES: Option<&'static UnitNames> = None;
+
+    /// 自定义基本量纲的单位写法（见 [`Extended`]），顺序同 [`Dimensional::EXTRA_EXPONENTS`]
+    /// Unit spellings of the custom base dimensions (see [`Extended`]), in the order of
+    /// [`Dimensional::EXTRA_EXPONENTS`]
+    const EXTRA_BASES: &'static [UnitNames] = &[];
+}
+
+impl<M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt>
+    DimensionNames for Dimension<M, KG, S, A, K, MOL, CD>
+{
+}
+
+impl<D: DimensionNames, A: TypedInt> DimensionNames for WithAngle<D, A> {
+    const EXTRA_BASES: &'static [UnitNames] = D::EXTRA_BASES;
+}
+
+impl<D: DimensionNames, K: Kind> DimensionNames for Kinded<D, K> {
+    const KIND_NAMES: Option<&'static UnitNames> = Some(K::NAMES);
+    const EXTRA_BASES: &'static [UnitNames] = D::EXTRA_BASES;
+}
+
+impl<D, S, E1, E2, E3, E4> DimensionNames for Extended<D, S, E1, E2, E3, E4>
+where
+    D: DimensionNames, S: BaseSystem, E1: TypedInt, E2: TypedInt, E3: TypedInt, E4: TypedInt,
+{
+    const EXTRA_BASES: &'static [UnitNames] = S::BASES;
+}
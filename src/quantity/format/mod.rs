@@ -0,0 +1,661 @@
+//! 物理量的文本显示
+//! Textual display of quantities
+//!
+//! 单位字符串完全由类型推出：词头取自 [`Prefixed::EXPONENT`]，单位取自
+//! [`Dimensional::EXPONENTS`]；量纲与专门名称单位（N、J、W 等）一致时显示该名称。
+//! 显示不分配内存，精度（`{:.3}`）作用于数值，宽度与对齐作用于整个字符串。
+//! `Display` 使用 Unicode 符号；[`format`](Si::format) 返回的构建器可选择
+//! ASCII、LaTeX（siunitx）或英文全称，并可用斜线代替负指数，或按 GB 3100 使用中文名称。
+//! The unit string is derived entirely from the types: the prefix comes from
+//! [`Prefixed::EXPONENT`] and the unit from [`Dimensional::EXPONENTS`]; exponents
+//! matching a derived unit with a special name (N, J, W, …) print that name.
+//! Display never allocates; precision (`{:.3}`) applies to the value, width and
+//! alignment to the whole string.
+//! `Display` uses Unicode symbols; the builder returned by [`format`](Si::format)
+//! selects ASCII, LaTeX (siunitx) or long English names, and a solidus in place
+//! of negative exponents, or Chinese names per GB 3100.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{Kilo, Locale, Si, Style};
+//! use unitrix::quantity::si_units::{Acceleration, Newton, Meter};
+//!
+//! let g: Acceleration = Si::new(9.80665);
+//! assert_eq!(format!("{:.2}", g), "9.81 m·s⁻²");
+//! assert_eq!(format!("{:.2}", g.format().style(Style::Ascii).solidus(true)), "9.81 m/s^2");
+//! assert_eq!(format!("{:.2}", g.format().style(Style::Latex)), "\\SI{9.81}{\\metre\\per\\second\\squared}");
+//! assert_eq!(format!("{:.2}", g.format().style(Style::Long)), "9.81 metres per second squared");
+//!
+//! assert_eq!(format!("{:.2}", g.format().locale(Locale::Chinese).style(Style::Long)), "9.81 米每二次方秒");
+//!
+//! let f: Newton<f64, Kilo> = Si::new(1.5);
+//! assert_eq!(format!("{}", f), "1.5 kN");
+//! assert_eq!(format!("{}", f.format().locale(Locale::Chinese)), "1.5 千牛");
+//!
+//! let d: Meter<i32, Kilo> = Si::new(42);
+//! assert_eq!(format!("[{:>8}]", d), "[   42 km]");
+//! ```
+
+mod symbols;
+pub use symbols::*;
+
+mod dimension_names;
+pub use dimension_names::*;
+
+mod ratio_symbols;
+pub use ratio_symbols::*;
+
+mod render;
+use render::{Counter, Layout};
+
+mod parse;
+pub use parse::*;
+
+use core::fmt::{self, Display, Formatter, Write};
+
+use crate::number::{Primitive, Var};
+use super::{Si, Unit, Prefixed, Scaled, ScaleFactor, DynQuantity, DynDimension, DynPrefixSi};
+use super::{Temperature, TemperatureDelta, TemperatureScale, Level, LevelReference, LevelKind};
+
+// ========== 显示风格 ==========
+// ========== Display Styles ==========
+
+/// 显示风格
+/// Display style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+    /// Unicode 符号与上标，如 `9.81 m·s⁻²`（`Display` 的风格）
+    /// Unicode symbols and superscripts, e.g. `9.81 m·s⁻²` (the `Display` style)
+    #[default]
+    Unicode,
+    /// 纯 ASCII，如 `9.81 m*s^-2`
+    /// Plain ASCII, e.g. `9.81 m*s^-2`
+    Ascii,
+    /// LaTeX siunitx，如 `\SI{9.81}{\metre\per\second\squared}`
+    /// LaTeX siunitx, e.g. `\SI{9.81}{\metre\per\second\squared}`
+    Latex,
+    /// 英文全称，如 `9.81 metres per second squared`
+    /// Long English names, e.g. `9.81 metres per second squared`
+    Long,
+}
+
+/// 单位名称与符号所用的语言
+/// Language of unit names and symbols
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// 国际符号与英文名称 / International symbols and English names
+    #[default]
+    English,
+    /// GB 3100 的中文名称；[`Style::Unicode`] 下用省略方括号部分的简称作符号（`牛·米`），
+    /// [`Style::Long`] 下用全称（`牛顿米`、`米每二次方秒`）；ASCII 与 LaTeX 不受影响
+    /// Chinese names per GB 3100; [`Style::Unicode`] uses the short names (bracketed
+    /// characters omitted) as symbols (`牛·米`) and [`Style::Long`] the full names
+    /// (`牛顿米`, `米每二次方秒`); ASCII and LaTeX are unaffected
+    Chinese,
+}
+
+/// 显示选项
+/// Display options
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FormatOptions {
+    /// 显示风格 / Display style
+    pub style: Style,
+    /// 用斜线写出分母（`m/s²`）而非负指数（`m·s⁻²`）；LaTeX 与英文全称不受影响
+    /// Writes the denominator after a solidus (`m/s²`) instead of with negative
+    /// exponents (`m·s⁻²`); LaTeX and long names are unaffected
+    pub solidus: bool,
+    /// 单位名称与符号所用的语言 / Language of unit names and symbols
+    pub locale: Locale,
+}
+
+/// 能按选项写出自身的物理量
+/// A quantity that writes itself according to the options
+pub trait Render {
+    /// 写出数值与单位；`precision` 作用于数值
+    /// Writes the value and unit; `precision` applies to the value
+    fn render(&self, w: &mut dyn Write, precision: Option<usize>, options: &FormatOptions) -> fmt::Result;
+}
+
+/// 带显示选项的物理量，由 `format()` 创建
+/// A quantity with display options, created by `format()`
+#[derive(Debug, Clone, Copy)]
+pub struct Formatted<'a, Q> {
+    quantity: &'a Q,
+    options: FormatOptions,
+}
+
+impl<'a, Q: Render> Formatted<'a, Q> {
+    /// 以默认选项包装物理量
+    /// Wraps a quantity with the default options
+    pub fn new(quantity: &'a Q) -> Self {
+        Formatted { quantity, options: FormatOptions::default() }
+    }
+
+    /// 设置显示风格
+    /// Sets the display style
+    pub fn style(mut self, style: Style) -> Self {
+        self.options.style = style;
+        self
+    }
+
+    /// 设置是否用斜线写出分母
+    /// Sets whether the denominator is written after a solidus
+    pub fn solidus(mut self, solidus: bool) -> Self {
+        self.options.solidus = solidus;
+        self
+    }
+
+    /// 设置单位名称与符号所用的语言
+    /// Sets the language of unit names and symbols
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.options.locale = locale;
+        self
+    }
+
+    /// 当前选项
+    /// The current options
+    pub fn options(&self) -> FormatOptions {
+        self.options
+    }
+}
+
+impl<Q: Render> Display for Formatted<'_, Q> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        write_padded(f, |w| self.quantity.render(w, precision, &self.options))
+    }
+}
+
+/// 按格式化器的宽度、对齐与填充字符写出 `body`
+/// Writes `body` honouring the formatter's width, alignment and fill
+pub(crate) fn write_padded(
+    f: &mut Formatter<'_>,
+    mut body: impl FnMut(&mut dyn Write) -> fmt::Result,
+) -> fmt::Result {
+    let Some(width) = f.width() else {
+        return body(f);
+    };
+    let mut counter = Counter(0);
+    body(&mut counter)?;
+    let fill = width.saturating_sub(counter.0);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, fill),
+        Some(fmt::Alignment::Center) => (fill / 2, fill - fill / 2),
+        _ => (fill, 0),
+    };
+    let c = f.fill();
+    for _ in 0..before {
+        f.write_char(c)?;
+    }
+    body(f)?;
+    for _ in 0..after {
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+/// 以SI符号写出运行期量纲，无量纲时写 `1`
+/// Writes a runtime dimension as SI symbols; `1` when dimensionless
+pub(crate) fn write_dimension(w: &mut dyn Write, dimension: &DynDimension) -> fmt::Result {
+    if *dimension == DynDimension::DIMENSIONLESS {
+        return w.write_char('1');
+    }
+    if let Some(kind) = dimension.kind {
+        return w.write_str(kind.symbol);
+    }
+    let layout = Layout::angular(&dimension.exponents, dimension.angle, 0);
+    for (i, t) in layout.terms().enumerate() {
+        if i > 0 {
+            w.write_char('·')?;
+        }
+        if let Some(p) = t.prefix {
+            w.write_str(p.names.symbol)?;
+        }
+        w.write_str(t.names.symbol)?;
+        if t.power != 1 {
+            render::write_superscript(w, t.power)?;
+        }
+    }
+    Ok(())
+}
+
+// ========== Si ==========
+
+impl<T, D, Pr> Render for Si<Var<T>, D, Pr>
+where
+    T: Primitive + Display,
+    D: DimensionNames,
+    Pr: Prefixed,
+{
+    fn render(&self, w: &mut dyn Write, precision: Option<usize>, options: &FormatOptions) -> fmt::Result {
+        let layout = Layout::dimension::<D>(Pr::EXPONENT);
+        render::render(w, &self.0 .0, precision, &layout, options)
+    }
+}
+
+impl<T, D, Pr> Si<Var<T>, D, Pr>
+where
+    T: Primitive + Display,
+    D: DimensionNames,
+    Pr: Prefixed,
+{
+    /// 以可选风格显示，如 `q.format().style(Style::Latex)`
+    /// Displays in a selectable style, e.g. `q.format().style(Style::Latex)`
+    pub fn format(&self) -> Formatted<'_, Self> {
+        Formatted::new(self)
+    }
+}
+
+impl<T, D, Pr> Display for Si<Var<T>, D, Pr>
+where
+    T: Primitive + Display,
+    D: DimensionNames,
+    Pr: Prefixed,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.format(), f)
+    }
+}
+
+// ========== Unit ==========
+
+// `Unit` 有登记的比例符号时显示该符号（如 `90 min`），否则显示数值、比例系数与SI单位；
+// 不能加词头的符号（如 km/h）带词头时也按比例系数显示
+// A `Unit` with a registered ratio symbol displays it (e.g. `90 min`); otherwise the
+// value, the ratio factor and the SI unit are shown, as they also are for a symbol
+// that takes no prefix (such as km/h) under a prefix
+impl<T, D, Pr, R> Render for Unit<Si<Var<T>, D, Pr>, R>
+where
+    T: Primitive + Display,
+    D: DimensionNames,
+    Pr: Prefixed,
+    R: Scaled + ScaleFactor + RatioSymbol<D>,
+{
+    fn render(&self, w: &mut dyn Write, precision: Option<usize>, options: &FormatOptions) -> fmt::Result {
+        let layout = match R::NAMES {
+            Some(names) if R::PREFIXABLE || Pr::EXPONENT == 0 => Layout::ratio(names, Pr::EXPONENT),
+            _ => Layout::dimension::<D>(Pr::EXPONENT).with_factor(R::factor()),
+        };
+        render::render(w, &(self.0).0 .0, precision, &layout, options)
+    }
+}
+
+impl<T, D, Pr, R> Unit<Si<Var<T>, D, Pr>, R>
+where
+    T: Primitive + Display,
+    D: DimensionNames,
+    Pr: Prefixed,
+    R: Scaled + ScaleFactor + RatioSymbol<D>,
+{
+    /// 以可选风格显示，如 `q.format().style(Style::Long)`
+    /// Displays in a selectable style, e.g. `q.format().style(Style::Long)`
+    pub fn format(&self) -> Formatted<'_, Self> {
+        Formatted::new(self)
+    }
+}
+
+impl<T, D, Pr, R> Display for Unit<Si<Var<T>, D, Pr>, R>
+where
+    T: Primitive + Display,
+    D: DimensionNames,
+    Pr: Prefixed,
+    R: Scaled + ScaleFactor + RatioSymbol<D>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.format(), f)
+    }
+}
+
+// ========== DynPrefixSi ==========
+
+impl<T, D> Render for DynPrefixSi<T, D>
+where
+    T: Display,
+    D: DimensionNames,
+{
+    fn render(&self, w: &mut dyn Write, precision: Option<usize>, options: &FormatOptions) -> fmt::Result {
+        let layout = Layout::dimension::<D>(i32::from(self.prefix));
+        render::render(w, &self.value, precision, &layout, options)
+    }
+}
+
+impl<T: Display, D: DimensionNames> DynPrefixSi<T, D> {
+    /// 以可选风格显示
+    /// Displays in a selectable style
+    pub fn format(&self) -> Formatted<'_, Self> {
+        Formatted::new(self)
+    }
+}
+
+impl<T: Display, D: DimensionNames> Display for DynPrefixSi<T, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.format(), f)
+    }
+}
+
+// ========== 温度 ==========
+// ========== Temperatures ==========
+
+// 温度值与温差使用同一个单位写法
+// Readings and differences share the unit spellings
+impl<T: Display, S: TemperatureScale> Render for Temperature<T, S> {
+    fn render(&self, w: &mut dyn Write, precision: Option<usize>, options: &FormatOptions) -> fmt::Result {
+        render::render(w, &self.value, precision, &Layout::ratio(S::NAMES, 0), options)
+    }
+}
+
+impl<T: Display, S: TemperatureScale> Render for TemperatureDelta<T, S> {
+    fn render(&self, w: &mut dyn Write, precision: Option<usize>, options: &FormatOptions) -> fmt::Result {
+        render::render(w, &self.value, precision, &Layout::ratio(S::NAMES, 0), options)
+    }
+}
+
+impl<T: Display, S: TemperatureScale> Temperature<T, S> {
+    /// 以可选风格显示
+    /// Displays in a selectable style
+    pub fn format(&self) -> Formatted<'_, Self> {
+        Formatted::new(self)
+    }
+}
+
+impl<T: Display, S: TemperatureScale> TemperatureDelta<T, S> {
+    /// 以可选风格显示
+    /// Displays in a selectable style
+    pub fn format(&self) -> Formatted<'_, Self> {
+        Formatted::new(self)
+    }
+}
+
+impl<T: Display, S: TemperatureScale> Display for Temperature<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.format(), f)
+    }
+}
+
+impl<T: Display, S: TemperatureScale> Display for TemperatureDelta<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.format(), f)
+    }
+}
+
+// ========== 级 ==========
+// ========== Levels ==========
+
+impl<R: LevelReference, K: LevelKind> Render for Level<R, K> {
+    fn render(&self, w: &mut dyn Write, precision: Option<usize>, options: &FormatOptions) -> fmt::Result {
+        render::render(w, &self.value, precision, &Layout::ratio(R::NAMES, 0), options)
+    }
+}
+
+impl<R: LevelReference, K: LevelKind> Level<R, K> {
+    /// 以可选风格显示
+    /// Displays in a selectable style
+    pub fn format(&self) -> Formatted<'_, Self> {
+        Formatted::new(self)
+    }
+}
+
+impl<R: LevelReference, K: LevelKind> Display for Level<R, K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.format(), f)
+    }
+}
+
+// ========== DynQuantity ==========
+
+// 与 `Unit` 相同：比例为1时按SI单位显示，有登记符号时显示该符号，否则显示比例系数
+// As for `Unit`: a ratio of one displays the SI unit, a registered ratio its symbol,
+// and any other ratio its factor
+impl<T: Display> Render for DynQuantity<T> {
+    fn render(&self, w: &mut dyn Write, precision: Option<usize>, options: &FormatOptions) -> fmt::Result {
+        let DynDimension { exponents, angle, kind } = self.dimension;
+        let ratio = &self.ratio;
+        let unit = || match kind {
+            Some(names) => Layout::ratio(*names, self.prefix),
+            None => Layout::angular(&exponents, angle, self.prefix),
+        };
+        // 登记表只含不带角度与种类的量纲 / The table only holds dimensions without an angle or a kind
+        let entry = match (angle, kind) {
+            (0, None) => lookup_ratio_entry(&exponents, &ratio.exponents, Some(ratio.primes)),
+            _ => None,
+        };
+        let layout = match entry {
+            _ if ratio.is_one() => unit(),
+            Some(e) if e.prefixable || self.prefix == 0 => Layout::ratio(e.names, self.prefix),
+            _ => unit().with_factor(ratio.factor().to_f64()),
+        };
+        render::render(w, &self.value, precision, &layout, options)
+    }
+}
+
+impl<T: Display> DynQuantity<T> {
+    /// 以可选风格显示
+    /// Displays in a selectable style
+    pub fn format(&self) -> Formatted<'_, Self> {
+        Formatted::new(self)
+    }
+}
+
+impl<T: Display> Display for DynQuantity<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.format(), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+    use crate::quantity::{Dimension, Kilo, Milli, Micro, Nano, Mega, Hours, Minutes, Days, Degrees, Gradians, Radians, Prefix, Ratio, PrimePow, Approx, MeasuredScale};
+    use crate::number::{Const, Z0, P1, N1, B1};
+    use crate::quantity::si_units::*;
+
+    #[test]
+    fn test_si_display() {
+        assert_eq!(format!("{}", <Meter>::new(3.5)), "3.5 m");
+        assert_eq!(format!("{}", Meter::<i32, Kilo>::new(2)), "2 km");
+        assert_eq!(format!("{}", Second::<f64, Micro>::new(4.0)), "4 µs");
+        assert_eq!(format!("{}", Joule::<i32, Mega>::new(7)), "7 MJ");
+        assert_eq!(format!("{}", Watt::<i32>::new(60)), "60 W");
+        assert_eq!(format!("{}", Velocity::<i32>::new(3)), "3 m·s⁻¹");
+        assert_eq!(format!("{}", Area::<i32>::new(3)), "3 m²");
+        assert_eq!(format!("{}", Area::<i32, Kilo>::new(3)), "3×10³ m²");
+        assert_eq!(format!("{}", Si::<Var<f64>, Dimensionless, Prefix<Const<-2>>>::new(5.0)), "5×10⁻²");
+    }
+
+    #[test]
+    fn test_mass_display() {
+        assert_eq!(format!("{}", Kilogram::<i32>::new(2)), "2 kg");
+        assert_eq!(format!("{}", Kilogram::<i32, Milli>::new(2)), "2 g");
+        assert_eq!(format!("{}", Kilogram::<i32, Kilo>::new(2)), "2 Mg");
+        assert_eq!(format!("{}", Density::<i32>::new(2)), "2 kg·m⁻³");
+    }
+
+    #[test]
+    fn test_flags() {
+        let g: Acceleration = Si::new(9.80665);
+        assert_eq!(format!("{:.3}", g), "9.807 m·s⁻²");
+        assert_eq!(format!("{:<10}|", Meter::<i32, Kilo>::new(42)), "42 km     |");
+        assert_eq!(format!("{:*^9}", Meter::<i32, Kilo>::new(42)), "**42 km**");
+    }
+
+    #[test]
+    fn test_unit_display() {
+        assert_eq!(format!("{}", Hours::new(2)), "2 h");
+        assert_eq!(format!("{:.1}", Minutes::<f64>::new(1.5)), "1.5 min");
+        assert_eq!(format!("{}", Degrees::new(90.0)), "90°");
+        assert_eq!(format!("{}", Gradians::new(100.0)), "100 gon");
+        assert_eq!(format!("{:>7}", Days::new(3)), "    3 d");
+
+        // 未登记的比例显示其系数 / An unregistered ratio shows its factor
+        let u: Unit<Meter<i32>, Ratio<P1, Z0, P1, Const<2>>> = Unit::new(3);
+        assert_eq!(format!("{}", u), "3×60 m");
+    }
+
+    #[test]
+    fn test_ratio_symbols() {
+        type Inch = Ratio<Const<-4>, Z0, Z0, Const<-3>, PrimePow<B1<B1<B1<B1<B1<B1<P1>>>>>>, P1>>;
+        assert_eq!(<Inch as RatioSymbol<LengthDimension>>::SYMBOL, Some("in"));
+        assert_eq!(<Inch as RatioSymbol<TimeDimension>>::SYMBOL, None);
+        assert_eq!(<Ratio<Const<-3>, Z0, Z0, Const<-3>> as RatioSymbol<VolumeDimension>>::SYMBOL, Some("L"));
+        assert_eq!(<Ratio<N1, P1, N1, N1> as RatioSymbol<FrequencyDimension>>::SYMBOL, Some("rpm"));
+
+        #[derive(Debug, Clone, Copy, Default)]
+        struct ElectronVolt;
+        impl MeasuredScale for ElectronVolt {
+            const FACTOR: f64 = 1.602_176_634e-19;
+            const NAME: &'static str = "eV";
+        }
+        let e: Unit<Joule<f64, Kilo>, Approx<ElectronVolt>> = Unit::new(2.5);
+        assert_eq!(format!("{}", e), "2.5 keV");
+    }
+
+    #[test]
+    fn test_unprefixable_ratio_display() {
+        // km/h、rpm 与英制单位不加词头，带词头时按比例系数显示
+        // km/h, rpm and imperial units take no prefix and fall back to the factor under one
+        type KilometersPerHour = Ratio<P1, Z0, Const<-2>, N1>;
+        type Inch = Ratio<Const<-4>, Z0, Z0, Const<-3>, PrimePow<B1<B1<B1<B1<B1<B1<P1>>>>>>, P1>>;
+        type Rpm = Ratio<N1, P1, N1, N1>;
+        let prefixable = [
+            <KilometersPerHour as RatioSymbol<VelocityDimension>>::PREFIXABLE,
+            <Ratio<Const<-3>, Z0, Z0, Const<-3>> as RatioSymbol<VolumeDimension>>::PREFIXABLE,
+        ];
+        assert_eq!(prefixable, [false, true]);
+
+        assert_eq!(format!("{}", Unit::<Velocity<i32>, KilometersPerHour>::new(3)), "3 km/h");
+        assert_eq!(format!("{}", Unit::<Velocity<i32, Kilo>, KilometersPerHour>::new(3)), "3×0.2777777777777778 km·s⁻¹");
+        assert_eq!(format!("{}", Unit::<Meter<i32, Kilo>, Inch>::new(3)), "3×0.0254 km");
+        assert_eq!(format!("{}", Unit::<Hertz<i32, Kilo>, Rpm>::new(3)), "3×0.10471975511965977×10³ s⁻¹");
+        assert_eq!(format!("{}", Unit::<Volume<i32, Milli>, Ratio<Const<-3>, Z0, Z0, Const<-3>>>::new(3)), "3 mL");
+
+        let q = DynQuantity::from(Unit::<Velocity<i32, Kilo>, KilometersPerHour>::new(3));
+        assert_eq!(format!("{}", q), "3×0.2777777777777778 km·s⁻¹");
+    }
+
+    #[test]
+    fn test_ascii() {
+        let g = Acceleration::<f64>::new(9.81);
+        assert_eq!(format!("{}", g.format().style(Style::Ascii)), "9.81 m*s^-2");
+        assert_eq!(format!("{}", g.format().style(Style::Ascii).solidus(true)), "9.81 m/s^2");
+        assert_eq!(format!("{}", g.format().solidus(true)), "9.81 m/s²");
+        assert_eq!(format!("{}", Second::<f64, Micro>::new(4.0).format().style(Style::Ascii)), "4 us");
+        assert_eq!(format!("{}", Ohm::<i32, Kilo>::new(3).format().style(Style::Ascii)), "3 kOhm");
+        assert_eq!(format!("{}", Area::<i32, Kilo>::new(3).format().style(Style::Ascii)), "3e3 m^2");
+        assert_eq!(format!("{}", Degrees::new(90.0).format().style(Style::Ascii)), "90 deg");
+        assert_eq!(format!("{:>12}|", g.format().style(Style::Ascii)), " 9.81 m*s^-2|");
+
+        // 分母有多项时加括号，分子为空时写1 / Several denominator terms are parenthesised; an empty numerator is 1
+        let c = Si::<Var<i32>, Dimension<Z0, N1, N1, Z0, Z0, Z0, Z0>, Prefix<Z0>>::new(2);
+        assert_eq!(format!("{}", c.format().style(Style::Ascii).solidus(true)), "2 1/(kg*s)");
+        assert_eq!(format!("{}", Density::<i32>::new(2).format().solidus(true)), "2 kg/m³");
+    }
+
+    #[test]
+    fn test_latex() {
+        let g = Acceleration::<f64>::new(9.81);
+        assert_eq!(format!("{}", g.format().style(Style::Latex)), "\\SI{9.81}{\\metre\\per\\second\\squared}");
+        assert_eq!(format!("{}", Kilogram::<i32, Milli>::new(2).format().style(Style::Latex)), "\\SI{2}{\\gram}");
+        assert_eq!(format!("{}", Newton::<i32, Kilo>::new(2).format().style(Style::Latex)), "\\SI{2}{\\kilo\\newton}");
+        assert_eq!(format!("{}", Area::<i32, Kilo>::new(3).format().style(Style::Latex)), "\\SI{3e3}{\\metre\\squared}");
+        assert_eq!(format!("{}", Hours::new(2).format().style(Style::Latex)), "\\SI{2}{\\hour}");
+        assert_eq!(format!("{}", Gradians::new(50.0).format().style(Style::Latex)), "\\SI{50}{\\gon}");
+        assert_eq!(format!("{}", Unit::<Radians<f64>, Ratio<Z0, P1, Z0, P1>>::new(2.0).format().style(Style::Latex)), "\\SI{2}{\\rev}");
+        assert_eq!(format!("{}", Si::<Var<f64>, Dimensionless, Prefix<Const<-2>>>::new(5.0).format().style(Style::Latex)), "\\num{5e-2}");
+    }
+
+    #[test]
+    fn test_long_names() {
+        let g = Acceleration::<f64>::new(9.81);
+        assert_eq!(format!("{}", g.format().style(Style::Long)), "9.81 metres per second squared");
+        assert_eq!(format!("{}", <Meter>::new(1.0).format().style(Style::Long)), "1 metre");
+        assert_eq!(format!("{}", Meter::<i32, Kilo>::new(3).format().style(Style::Long)), "3 kilometres");
+        assert_eq!(format!("{}", Velocity::<i32>::new(1).format().style(Style::Long)), "1 metre per second");
+        assert_eq!(format!("{}", Volume::<i32>::new(2).format().style(Style::Long)), "2 cubic metres");
+        assert_eq!(format!("{}", Density::<i32>::new(2).format().style(Style::Long)), "2 kilograms per metre cubed");
+        assert_eq!(format!("{}", Minutes::<f64>::new(1.5).format().style(Style::Long)), "1.5 minutes");
+        assert_eq!(format!("{}", Degrees::new(1.0).format().style(Style::Long)), "1 degree");
+    }
+
+    #[test]
+    fn test_chinese() {
+        let zh = Locale::Chinese;
+        assert_eq!(format!("{}", Meter::<i32, Kilo>::new(3).format().locale(zh)), "3 千米");
+        assert_eq!(format!("{}", Kilogram::<i32>::new(2).format().locale(zh)), "2 千克");
+        assert_eq!(format!("{}", Kilogram::<i32, Milli>::new(2).format().locale(zh)), "2 克");
+        assert_eq!(format!("{}", Second::<f64, Micro>::new(4.0).format().locale(zh)), "4 微秒");
+        assert_eq!(format!("{}", Newton::<i32>::new(5).format().locale(zh)), "5 牛");
+        assert_eq!(format!("{}", Newton::<i32>::new(5).format().locale(zh).style(Style::Long)), "5 牛顿");
+        assert_eq!(format!("{}", Second::<i32, Nano>::new(7).format().locale(zh).style(Style::Long)), "7 纳诺秒");
+        assert_eq!(format!("{}", Hours::new(2).format().locale(zh)), "2 时");
+        assert_eq!(format!("{}", Hours::new(2).format().locale(zh).style(Style::Long)), "2 小时");
+        assert_eq!(format!("{}", Degrees::new(90.0).format().locale(zh)), "90 度");
+
+        let g = Acceleration::<f64>::new(9.81);
+        assert_eq!(format!("{}", g.format().locale(zh)), "9.81 米·秒⁻²");
+        assert_eq!(format!("{}", g.format().locale(zh).solidus(true)), "9.81 米/秒²");
+        assert_eq!(format!("{}", g.format().locale(zh).style(Style::Long)), "9.81 米每二次方秒");
+        assert_eq!(format!("{}", Density::<i32>::new(2).format().locale(zh).style(Style::Long)), "2 千克每立方米");
+        assert_eq!(format!("{}", Area::<i32>::new(3).format().locale(zh).style(Style::Long)), "3 平方米");
+
+        let v: Unit<Velocity<f64>, Ratio<P1, Z0, Const<-2>, N1>> = Unit::new(36.0);
+        assert_eq!(format!("{}", v.format().locale(zh).style(Style::Long)), "36 千米每小时");
+
+        // ASCII 与 LaTeX 不受语言影响 / ASCII and LaTeX ignore the locale
+        assert_eq!(format!("{}", g.format().locale(zh).style(Style::Ascii)), "9.81 m*s^-2");
+    }
+
+    #[test]
+    fn test_layout_capacity() {
+        // 七个SI基本单位都出现时，角度项插在正指数项之后，负指数项整体后移
+        // With all seven SI base units, the angle follows the positive powers and the
+        // negative powers all shift back
+        let exponents = [1, 1, -1, 1, -1, 1, -1];
+        let layout = Layout::angular(&exponents, 1, 0);
+        let powers: std::vec::Vec<i32> = layout.terms().map(|t| t.power).collect();
+        assert_eq!(powers, [1, 1, 1, 1, 1, -1, -1, -1]);
+
+        // 7 + 1 + 4 项正好填满 / 7 + 1 + 4 terms fill the layout exactly
+        let bases = [UnitNames::plain("a"), UnitNames::plain("b"), UnitNames::plain("c"), UnitNames::plain("d")];
+        let layout = Layout::extended(&exponents, -1, &bases, &[1, -1, 2, -2], 0);
+        assert_eq!(layout.terms().count(), 12);
+    }
+
+    #[test]
+    fn test_chinese_tables() {
+        // GB 3100 表1、表3、表5 中的名称 / Names from GB 3100 tables 1, 3 and 5
+        let base: [&str; 7] = ["米", "千克", "秒", "安[培]", "开[尔文]", "摩[尔]", "坎[德拉]"];
+        for (unit, name) in BASE_UNITS.iter().zip(base) {
+            assert_eq!(unit.chinese, name);
+        }
+        assert_eq!(find_named_unit(&[1, 1, -2, 0, 0, 0, 0]).unwrap().names.chinese, "牛[顿]");
+        assert_eq!(find_named_unit(&[2, 1, -3, -2, 0, 0, 0]).unwrap().names.chinese, "欧[姆]");
+        let prefixes = [(3, "千"), (-3, "毫"), (-6, "微"), (-9, "纳[诺]"), (6, "兆"), (9, "吉[咖]"), (-1, "分"), (-2, "厘")];
+        for (exp, name) in prefixes {
+            assert_eq!(find_prefix(exp).unwrap().unwrap().names.chinese, name);
+        }
+
+        // 每个名称非空且方括号成对 / Every name is non-empty with balanced brackets
+        let balanced = |name: &str| {
+            let mut open = false;
+            for c in name.chars() {
+                match c {
+                    '[' if !open => open = true,
+                    ']' if open => open = false,
+                    '[' | ']' => return false,
+                    _ => {}
+                }
+            }
+            !open && !name.is_empty() && !name.starts_with("[]")
+        };
+        let all = BASE_UNITS.iter()
+            .chain(NAMED_UNITS.iter().map(|u| &u.names))
+            .chain(PREFIXES.iter().map(|p| &p.names))
+            .chain(RATIO_SYMBOLS.iter().map(|e| &e.names));
+        for names in all {
+            assert!(balanced(names.chinese), "{}", names.chinese);
+        }
+    }
+}
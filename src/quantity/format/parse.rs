@@ -0,0 +1,705 @@
+//! 从字符串解析物理量
+//! Parsing quantities from strings
+//!
+//! 解析器与显示共用同一套符号表（[`BASE_UNITS`]、[`NAMED_UNITS`]、[`PREFIXES`]、
+//! [`RATIO_SYMBOLS`]），因此 `Display` 与 ASCII 风格的输出都能原样解析回来。
+//! 支持乘积（`·`、`*`）、商（`/`，可带括号）、指数（`^-2` 或上标 `⁻²`）、
+//! 词头与专门名称单位；数值后可跟 `e3`、`×10³` 或 `×60` 等系数。
+//! 种类专用的单位（[`KIND_UNITS`] 中的 `Bq`、`Sv` 与整体书写的 `N·m`/`N.m`）单独出现时带上种类，
+//! 因此 `"5 J"` 不能解析为力矩，`"3 Bq"` 也不能解析为频率；`N*m`、`m·N` 等乘积不带种类。
+//! 解析得到运行期的 [`ParsedQuantity`]，再通过 `TryFrom` 检查量纲并换算为静态类型的
+//! `Si` / `Unit`；`Si` 与 `Unit` 也直接实现了 `FromStr`。
+//! The parser shares the symbol tables of the display ([`BASE_UNITS`], [`NAMED_UNITS`],
+//! [`PREFIXES`], [`RATIO_SYMBOLS`]), so both `Display` and ASCII-style output parse
+//! back unchanged. Products (`·`, `*`), quotients (`/`, with parentheses), powers
+//! (`^-2` or superscript `⁻²`), prefixes and units with special names are supported;
+//! the value may be followed by a factor such as `e3`, `×10³` or `×60`.
+//! A unit specific to a kind (`Bq`, `Sv` and `N·m`/`N.m` written as a whole, from
+//! [`KIND_UNITS`]) standing alone carries the kind, so `"5 J"` does not parse as a
+//! torque and `"3 Bq"` does not parse as a frequency; products such as `N*m` and
+//! `m·N` carry no kind.
+//! `rad` 与 `sr` 带角度指数，只能解析为带角度的量（如 [`PlaneAngle`](crate::quantity::PlaneAngle)）
+//! 或普通无量纲的量（如 [`Radian`](crate::quantity::si_units::Radian)、
+//! [`Steradian`](crate::quantity::si_units::Steradian)，它们按SI仍是无量纲的）；
+//! 后者解析时去掉角度。
+//! `rad` and `sr` carry the angle exponent and parse only into quantities with an angle
+//! (such as [`PlaneAngle`](crate::quantity::PlaneAngle)) or plain dimensionless ones
+//! (such as [`Radian`](crate::quantity::si_units::Radian) and
+//! [`Steradian`](crate::quantity::si_units::Steradian), which stay dimensionless as in
+//! the SI); the latter drop the angle while parsing.
+//! Parsing yields a runtime [`ParsedQuantity`], which `TryFrom` checks against the
+//! dimension of a statically typed `Si` / `Unit` and converts; `Si` and `Unit` also
+//! implement `FromStr` directly.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{Milli, ParsedQuantity, ParseError};
+//! use unitrix::quantity::si_units::{Acceleration, Ampere, Meter};
+//!
+//! let g: Acceleration = "9.81 m/s^2".parse().unwrap();
+//! assert_eq!(g.0 .0, 9.81);
+//!
+//! let i: Ampere<i32, Milli> = "250 mA".parse().unwrap();
+//! assert_eq!(i.0 .0, 250);
+//!
+//! let q: ParsedQuantity<f64> = "1.5e3 N·m".parse().unwrap();
+//! assert_eq!(q.dimension.exponents, [2, 1, -2, 0, 0, 0, 0]);
+//!
+//! let err = "3 s".parse::<Meter>().unwrap_err();
+//! assert_eq!(format!("{}", err), "dimension mismatch: expected m, found s");
+//! assert!(matches!(err, ParseError::DimensionMismatch { .. }));
+//! ```
+
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use crate::number::{Primitive, Scale, DefaultRounding, Var};
+use crate::quantity::{Si, Unit, Prefixed, Scaled, Conversion, Factor, DynRatio, DynDimension};
+use super::write_dimension;
+use super::{DimensionNames, UnitNames, BASE_UNITS, GRAM, RADIAN, STERADIAN, DIMENSIONLESS, NAMED_UNITS, KIND_UNITS, PREFIXES, RATIO_SYMBOLS};
+
+// ========== 错误 ==========
+// ========== Errors ==========
+
+/// 解析或换算错误
+/// Parse or conversion error
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParseError {
+    /// 数值无法解析 / The value cannot be parsed
+    InvalidNumber,
+    /// 第 `at` 字节处的单位无法识别 / Unknown unit at byte `at`
+    UnknownUnit {
+        /// 字节位置 / Byte offset
+        at: usize,
+    },
+    /// 第 `at` 字节处的语法错误 / Syntax error at byte `at`
+    Syntax {
+        /// 字节位置 / Byte offset
+        at: usize,
+    },
+    /// 量纲与目标类型不符 / The dimension does not match the target type
+    DimensionMismatch {
+        /// 目标类型的量纲 / Dimension of the target type
+        expected: DynDimension,
+        /// 解析得到的量纲 / Dimension that was parsed
+        found: DynDimension,
+    },
+    /// 换算到目标类型时溢出 / Overflow while converting to the target type
+    Overflow,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::InvalidNumber => f.write_str("invalid number"),
+            ParseError::UnknownUnit { at } => write!(f, "unknown unit at byte {}", at),
+            ParseError::Syntax { at } => write!(f, "unexpected character at byte {}", at),
+            ParseError::DimensionMismatch { expected, found } => {
+                f.write_str("dimension mismatch: expected ")?;
+                write_dimension(f, expected)?;
+                f.write_str(", found ")?;
+                write_dimension(f, found)
+            }
+            ParseError::Overflow => f.write_str("value overflows the target type"),
+        }
+    }
+}
+
+// ========== 运行期物理量 ==========
+// ========== Runtime Quantity ==========
+
+/// 解析得到的运行期物理量：数值 × `factor` × 10^`prefix`，单位为量纲对应的SI单位
+/// A runtime quantity from the parser: value × `factor` × 10^`prefix` in the coherent
+/// SI unit of the dimension
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ParsedQuantity<T> {
+    /// 书写的数值（不含 `e` 指数）/ The value as written (without its `e` exponent)
+    pub value: T,
+    /// 量纲（含 `rad`、`sr` 的角度指数与单独出现的种类单位的种类）
+    /// Dimension (including the angle exponent of `rad` and `sr`, and the kind of a lone kind unit)
+    pub dimension: DynDimension,
+    /// 10的幂次，来自词头与 `e`、`×10ⁿ` / Power of ten from prefixes, `e` and `×10ⁿ`
+    pub prefix: i32,
+    /// 比例单位与显式系数之积 / Product of the ratio units and explicit factors
+    pub factor: Factor,
+}
+
+impl<T: Primitive + Scale> ParsedQuantity<T> {
+    /// 检查量纲并换算到词头 `prefix`、比例 `ratio` 下的数值
+    /// Checks the dimension and converts the value to the prefix `prefix` and ratio `ratio`
+    fn value_in(&self, dimension: DynDimension, prefix: i32, ratio: Factor) -> Result<T, ParseError> {
+        // 无量纲的目标（`Radian`、`Steradian`、`Count` 同为无量纲）接受任意角度
+        // A dimensionless target (`Radian`, `Steradian` and `Count` are all dimensionless) accepts any angle
+        let found = if dimension == DynDimension::DIMENSIONLESS {
+            DynDimension { angle: 0, ..self.dimension }
+        } else {
+            self.dimension
+        };
+        if found != dimension {
+            return Err(ParseError::DimensionMismatch { expected: dimension, found: self.dimension });
+        }
+        let shift = self.prefix.checked_sub(prefix).ok_or(ParseError::Overflow)?;
+        let factor = self.factor * Factor::power_of_ten(shift) * ratio.recip();
+        factor.checked_apply::<T, DefaultRounding>(self.value).ok_or(ParseError::Overflow)
+    }
+}
+
+impl<T: FromStr> FromStr for ParsedQuantity<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Parser { s, pos: 0 }.quantity()
+    }
+}
+
+impl<T, D, Pr> TryFrom<ParsedQuantity<T>> for Si<Var<T>, D, Pr>
+where
+    T: Primitive + Scale,
+    D: DimensionNames,
+    Pr: Prefixed,
+{
+    type Error = ParseError;
+
+    fn try_from(q: ParsedQuantity<T>) -> Result<Self, ParseError> {
+        let value = q.value_in(DynDimension::of::<D>(), Pr::EXPONENT, Factor::Exact(1, 1))?;
+        Ok(Si(Var(value), PhantomData))
+    }
+}
+
+impl<T, D, Pr, R> TryFrom<ParsedQuantity<T>> for Unit<Si<Var<T>, D, Pr>, R>
+where
+    T: Primitive + Scale,
+    D: DimensionNames,
+    Pr: Prefixed,
+    R: Scaled + Conversion,
+{
+    type Error = ParseError;
+
+    fn try_from(q: ParsedQuantity<T>) -> Result<Self, ParseError> {
+        let value = q.value_in(DynDimension::of::<D>(), Pr::EXPONENT, R::conversion_factor())?;
+        Ok(Unit(Si(Var(value), PhantomData), PhantomData))
+    }
+}
+
+impl<T, D, Pr> FromStr for Si<Var<T>, D, Pr>
+where
+    T: Primitive + Scale + FromStr,
+    D: DimensionNames,
+    Pr: Prefixed,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        s.parse::<ParsedQuantity<T>>()?.try_into()
+    }
+}
+
+impl<T, D, Pr, R> FromStr for Unit<Si<Var<T>, D, Pr>, R>
+where
+    T: Primitive + Scale + FromStr,
+    D: DimensionNames,
+    Pr: Prefixed,
+    R: Scaled + Conversion,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        s.parse::<ParsedQuantity<T>>()?.try_into()
+    }
+}
+
+// ========== 单位 ==========
+// ========== Units ==========
+
+/// 单位表达式的一部分：量纲、10的幂次与系数
+/// Part of a unit expression: dimension, power of ten and factor
+#[derive(Clone, Copy)]
+struct Scaling {
+    dimension: DynDimension,
+    prefix: i32,
+    factor: Factor,
+}
+
+impl Scaling {
+    const ONE: Scaling = Scaling { dimension: DynDimension::DIMENSIONLESS, prefix: 0, factor: Factor::Exact(1, 1) };
+
+    fn unit(exponents: [i32; 7], prefix: i32, factor: Factor) -> Self {
+        Scaling { dimension: DynDimension::new(exponents, 0), prefix, factor }
+    }
+
+    fn angle(angle: i32) -> Self {
+        Scaling { dimension: DynDimension::new(DIMENSIONLESS, angle), ..Scaling::ONE }
+    }
+
+    /// 乘以 `rhs` 的 `power` 次幂；指数或10的幂次超出 `i32` 时返回 [`ParseError::Overflow`]。
+    /// 只有单独一项、指数为1时保留种类
+    /// Multiplies by `rhs` to the power `power`; [`ParseError::Overflow`] when an
+    /// exponent or the power of ten leaves the `i32` range. The kind survives only for a
+    /// single term of power 1
+    fn mul_pow(&mut self, rhs: Scaling, power: i32) -> Result<(), ParseError> {
+        let scaled = |acc: i32, r: i32| r.checked_mul(power).and_then(|r| acc.checked_add(r)).ok_or(ParseError::Overflow);
+        let mut dimension = self.dimension;
+        dimension.kind = if dimension == DynDimension::DIMENSIONLESS && power == 1 { rhs.dimension.kind } else { None };
+        for (e, r) in dimension.exponents.iter_mut().zip(rhs.dimension.exponents) {
+            *e = scaled(*e, r)?;
+        }
+        dimension.angle = scaled(dimension.angle, rhs.dimension.angle)?;
+        self.prefix = scaled(self.prefix, rhs.prefix)?;
+        self.dimension = dimension;
+        self.factor = self.factor * rhs.factor.powi(power);
+        Ok(())
+    }
+}
+
+/// 不带词头的单位
+/// A unit without a prefix
+fn bare_unit(token: &str, prefixed: bool) -> Option<Scaling> {
+    let spelled = |names: &UnitNames| names.symbol == token || names.ascii == token;
+    for (i, names) in BASE_UNITS.iter().enumerate() {
+        // 千克不再加词头 / The kilogram takes no further prefix
+        if spelled(names) && !(prefixed && i == 1) {
+            let mut exponents = DIMENSIONLESS;
+            exponents[i] = 1;
+            return Some(Scaling::unit(exponents, 0, Factor::Exact(1, 1)));
+        }
+    }
+    if spelled(&GRAM) {
+        return Some(Scaling::unit([0, 1, 0, 0, 0, 0, 0], -3, Factor::Exact(1, 1)));
+    }
+    if let Some(unit) = NAMED_UNITS.iter().find(|u| spelled(&u.names)) {
+        return Some(Scaling::unit(unit.exponents, 0, Factor::Exact(1, 1)));
+    }
+    if let Some(unit) = KIND_UNITS.iter().find(|u| spelled(&u.names)) {
+        let dimension = DynDimension::new(unit.exponents, 0).with_kind(&unit.names);
+        return Some(Scaling { dimension, ..Scaling::ONE });
+    }
+    // 弧度与球面度带角度指数（见 `WithAngle`）
+    // The radian and the steradian carry the angle exponent (see `WithAngle`)
+    if spelled(&RADIAN) {
+        return Some(Scaling::angle(1));
+    }
+    if spelled(&STERADIAN) {
+        return Some(Scaling::angle(2));
+    }
+    // 不能加词头的比例单位（如 km/h、in）不接受词头
+    // Ratio units that take no prefix (such as km/h and in) reject one
+    RATIO_SYMBOLS
+        .iter()
+        .find(|e| spelled(&e.names) && (e.prefixable || !prefixed))
+        .map(|e| Scaling::unit(e.dimension, 0, DynRatio { exponents: e.exponents, primes: e.primes }.factor()))
+}
+
+/// 单位（可带词头）；整个记号是单位时优先于词头拆分，故 `min`、`cd`、`Pa` 不会被拆开
+/// A unit, possibly prefixed; a whole-token match wins over a prefix split, so
+/// `min`, `cd` and `Pa` are never split
+fn unit(token: &str) -> Option<Scaling> {
+    if let Some(unit) = bare_unit(token, false) {
+        return Some(unit);
+    }
+    for prefix in PREFIXES {
+        // µ（U+00B5）与 μ（U+03BC）都接受 / Both µ (U+00B5) and μ (U+03BC) are accepted
+        let spellings = [prefix.names.symbol, prefix.names.ascii, if prefix.exponent == -6 { "μ" } else { "" }];
+        for spelling in spellings.into_iter().filter(|s| !s.is_empty()) {
+            if let Some(mut unit) = token.strip_prefix(spelling).and_then(|rest| bare_unit(rest, true)) {
+                unit.prefix += prefix.exponent;
+                return Some(unit);
+            }
+        }
+    }
+    None
+}
+
+// ========== 解析器 ==========
+// ========== Parser ==========
+
+const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+fn superscript_digit(c: char) -> Option<i32> {
+    SUPERSCRIPTS.iter().position(|&d| d == c).map(|d| d as i32)
+}
+
+fn is_multiply(c: char) -> bool {
+    matches!(c, '·' | '⋅' | '*')
+}
+
+/// 单位记号的结束字符
+/// Characters that end a unit token
+fn ends_token(c: char) -> bool {
+    c.is_whitespace()
+        || c.is_ascii_digit()
+        || is_multiply(c)
+        || matches!(c, '/' | '^' | '(' | ')' | '⁻' | '⁺')
+        || superscript_digit(c).is_some()
+}
+
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.bump();
+        }
+        &self.s[start..self.pos]
+    }
+
+    fn syntax(&self) -> ParseError {
+        ParseError::Syntax { at: self.pos }
+    }
+
+    /// 带符号的十进制整数
+    /// A signed decimal integer
+    fn integer(&mut self) -> Result<i32, ParseError> {
+        let start = self.pos;
+        if !self.eat('-') {
+            self.eat('+');
+        }
+        self.take_while(|c| c.is_ascii_digit());
+        self.s[start..self.pos].parse().map_err(|_| ParseError::Syntax { at: start })
+    }
+
+    /// 上标整数，如 `⁻²`
+    /// A superscript integer, e.g. `⁻²`
+    fn superscript(&mut self) -> Option<i32> {
+        let negative = self.eat('⁻');
+        if !negative {
+            self.eat('⁺');
+        }
+        let mut n: i32 = 0;
+        let mut digits = 0;
+        while let Some(d) = self.peek().and_then(superscript_digit) {
+            self.bump();
+            n = n.saturating_mul(10).saturating_add(d);
+            digits += 1;
+        }
+        (digits > 0).then_some(if negative { -n } else { n })
+    }
+
+    /// 单位后的指数，没有时为1
+    /// The power after a unit; 1 when absent
+    fn power(&mut self) -> Result<i32, ParseError> {
+        if self.eat('^') {
+            return self.integer();
+        }
+        match self.peek() {
+            Some(c) if c == '⁻' || c == '⁺' || superscript_digit(c).is_some() => {
+                self.superscript().ok_or_else(|| self.syntax())
+            }
+            _ => Ok(1),
+        }
+    }
+
+    /// 从 `start` 起的十进制数：尾数（含 `start` 起已读入的符号）与 `e` 指数分开返回
+    /// A decimal number from `start`: the mantissa (including any sign already read
+    /// from `start`) and the `e` exponent are returned separately
+    fn number(&mut self, start: usize) -> Result<(&'a str, i32), ParseError> {
+        let digits = self.pos;
+        self.take_while(|c| c.is_ascii_digit());
+        if self.eat('.') {
+            self.take_while(|c| c.is_ascii_digit());
+        }
+        if !self.s[digits..self.pos].bytes().any(|b| b.is_ascii_digit()) {
+            return Err(ParseError::InvalidNumber);
+        }
+        let mantissa = &self.s[start..self.pos];
+        // 只有 `e` 后跟数字时才是指数，`5 eV` 中的 `e` 属于单位
+        // `e` is an exponent only when digits follow; in `5 eV` it belongs to the unit
+        let rest = &self.s[self.pos..];
+        let exponent = rest.strip_prefix(['e', 'E']).map(|r| r.strip_prefix(['-', '+']).unwrap_or(r));
+        if exponent.is_some_and(|d| d.starts_with(|c: char| c.is_ascii_digit())) {
+            self.bump();
+            return Ok((mantissa, self.integer()?));
+        }
+        Ok((mantissa, 0))
+    }
+
+    /// 物理量：数值、可选系数与单位表达式
+    /// A quantity: the value, optional factors and the unit expression
+    fn quantity<T: FromStr>(&mut self) -> Result<ParsedQuantity<T>, ParseError> {
+        self.skip_whitespace();
+        let start = self.pos;
+        let negative = self.eat('-');
+        if !negative {
+            self.eat('+');
+        }
+        let (mantissa, exp) = self.number(start)?;
+        let value = mantissa.parse().map_err(|_| ParseError::InvalidNumber)?;
+        let mut scaling = Scaling::ONE;
+        scaling.prefix = exp;
+
+        // 数值后的系数：`×10³`、`×60`、`*60`
+        // Factors after the value: `×10³`, `×60`, `*60`
+        while matches!(self.peek(), Some('×' | '*')) {
+            let at = self.pos;
+            self.bump();
+            let rest = &self.s[self.pos..];
+            if rest.starts_with("10") && rest[2..].starts_with(|c| c == '⁻' || c == '⁺' || superscript_digit(c).is_some()) {
+                self.pos += 2;
+                let exp = self.superscript().ok_or_else(|| self.syntax())?;
+                scaling.prefix = scaling.prefix.checked_add(exp).ok_or(ParseError::Overflow)?;
+                continue;
+            }
+            let (digits, exp) = self.number(self.pos).map_err(|_| ParseError::Syntax { at })?;
+            let factor = match digits.parse::<i128>() {
+                Ok(n) => Factor::Exact(n, 1),
+                Err(_) => Factor::Float(digits.parse().map_err(|_| ParseError::Syntax { at })?),
+            };
+            scaling.factor = scaling.factor * factor;
+            scaling.prefix = scaling.prefix.checked_add(exp).ok_or(ParseError::Overflow)?;
+        }
+
+        self.skip_whitespace();
+        // 整体是一个单位时优先（如种类单位 `kN·m`），否则按表达式解析
+        // A whole-unit match wins (such as the kind unit `kN·m`); otherwise parse an expression
+        if self.peek().is_some() {
+            match unit(self.s[self.pos..].trim_end()) {
+                Some(whole) => {
+                    scaling.mul_pow(whole, 1)?;
+                    self.pos = self.s.len();
+                }
+                None => self.product(&mut scaling)?,
+            }
+        }
+        self.skip_whitespace();
+        if self.peek().is_some() {
+            return Err(self.syntax());
+        }
+        Ok(ParsedQuantity { value, dimension: scaling.dimension, prefix: scaling.prefix, factor: scaling.factor })
+    }
+
+    /// 以 `·`、`*` 相乘、以 `/` 相除的因子序列；`/` 只作用于其后的一个因子
+    /// A sequence of factors multiplied by `·` or `*` and divided by `/`; a `/` applies
+    /// only to the single factor after it
+    fn product(&mut self, acc: &mut Scaling) -> Result<(), ParseError> {
+        let mut sign = 1;
+        loop {
+            self.factor(acc, sign)?;
+            match self.peek() {
+                Some(c) if is_multiply(c) => sign = 1,
+                Some('/') => sign = -1,
+                _ => return Ok(()),
+            }
+            self.bump();
+        }
+    }
+
+    /// 括号内的表达式、分子位置的 `1`，或带指数的单位
+    /// A parenthesised expression, a `1` in numerator position, or a unit with its power
+    fn factor(&mut self, acc: &mut Scaling, sign: i32) -> Result<(), ParseError> {
+        if self.eat('(') {
+            let mut inner = Scaling::ONE;
+            self.product(&mut inner)?;
+            if !self.eat(')') {
+                return Err(self.syntax());
+            }
+            let power = self.power()?;
+            return acc.mul_pow(inner, power.checked_mul(sign).ok_or(ParseError::Overflow)?);
+        }
+        if self.eat('1') {
+            return Ok(());
+        }
+        let at = self.pos;
+        let token = self.take_while(|c| !ends_token(c));
+        if token.is_empty() {
+            return Err(self.syntax());
+        }
+        let unit = unit(token).ok_or(ParseError::UnknownUnit { at })?;
+        let power = self.power()?;
+        acc.mul_pow(unit, power.checked_mul(sign).ok_or(ParseError::Overflow)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+    use crate::number::{Const, Z0, P1, N1};
+    use crate::quantity::{Dimension, Dimensional, Kilo, Milli, Micro, Prefix, Ratio, Hours, Minutes, Degrees, Style, Render, Formatted};
+    use crate::quantity::{PlaneAngle, SolidAngle, ArcLength, Count, Radians};
+    use crate::quantity::si_units::*;
+
+    type KilometersPerHour = Unit<Velocity<f64>, Ratio<P1, Z0, Const<-2>, N1>>;
+
+    #[test]
+    fn test_parse() {
+        let g: Acceleration = "9.81 m/s^2".parse().unwrap();
+        assert_eq!(g.0 .0, 9.81);
+        let g: Acceleration = "9.81 m·s⁻²".parse().unwrap();
+        assert_eq!(g.0 .0, 9.81);
+        let i: Ampere<i32, Milli> = "250 mA".parse().unwrap();
+        assert_eq!(i.0 .0, 250);
+        let i: Ampere<f64> = "250 mA".parse().unwrap();
+        assert_eq!(i.0 .0, 0.25);
+        let w: Joule<f64, Kilo> = "1.5e3 J".parse().unwrap();
+        assert_eq!(w.0 .0, 1.5);
+        let v: Velocity<f64> = "36 km/h".parse().unwrap();
+        assert_eq!(v.0 .0, 10.0);
+        let v: KilometersPerHour = "3 km/h".parse().unwrap();
+        assert_eq!((v.0).0 .0, 3.0);
+        let m: Kilogram<i32, Milli> = "2 g".parse().unwrap();
+        assert_eq!(m.0 .0, 2);
+        let t: Second<i64, Micro> = "4 us".parse().unwrap();
+        assert_eq!(t.0 .0, 4);
+        let t: Second<i64, Micro> = "4 μs".parse().unwrap();
+        assert_eq!(t.0 .0, 4);
+        let min: Minutes = "90 s".parse().unwrap();
+        assert_eq!((min.0).0 .0, 2);
+
+        // 整个记号是单位时不拆出词头 / A whole-token unit is never split into a prefix
+        let q: ParsedQuantity<i32> = "3 min".parse().unwrap();
+        assert_eq!((q.dimension.exponents, q.prefix, q.factor), (TimeDimension::EXPONENTS, 0, Factor::Exact(60, 1)));
+        let q: ParsedQuantity<i32> = "3 cd".parse().unwrap();
+        assert_eq!(q.dimension.exponents, [0, 0, 0, 0, 0, 0, 1]);
+        let q: ParsedQuantity<i32> = "3 mm".parse().unwrap();
+        assert_eq!((q.dimension.exponents, q.prefix), (LengthDimension::EXPONENTS, -3));
+        let q: ParsedQuantity<f64> = "2 1/(kg*s)".parse().unwrap();
+        assert_eq!(q.dimension.exponents, [0, -1, -1, 0, 0, 0, 0]);
+        let q: ParsedQuantity<f64> = "0.5".parse().unwrap();
+        assert_eq!(q.dimension, DynDimension::DIMENSIONLESS);
+
+        // 弧度与球面度带角度指数 / The radian and the steradian carry the angle exponent
+        let q: ParsedQuantity<f64> = "2 mrad/s".parse().unwrap();
+        assert_eq!((q.dimension, q.prefix), (DynDimension::new([0, 0, -1, 0, 0, 0, 0], 1), -3));
+        let q: ParsedQuantity<f64> = "2 sr".parse().unwrap();
+        assert_eq!(q.dimension, DynDimension::new(DIMENSIONLESS, 2));
+    }
+
+    #[test]
+    fn test_parse_dimensionless_angles() {
+        // `Radian`、`Steradian` 按SI是无量纲的，解析时去掉角度
+        // `Radian` and `Steradian` are dimensionless as in the SI and drop the angle when parsed
+        assert_eq!("1.5 rad".parse::<Radian>(), Ok(Radian::new(1.5)));
+        assert_eq!("1.5 rad".parse::<Radians>(), Ok(Radians::new(1.5)));
+        assert_eq!("2 mrad".parse::<Radian<f64, Milli>>(), Ok(Radian::new(2.0)));
+        assert_eq!("2 sr".parse::<Steradian>(), Ok(Steradian::new(2.0)));
+        assert_eq!("0.5 rad".parse::<Count>(), Ok(Count::new(0.5)));
+        assert_eq!("90 °".parse::<Degrees>().map(|d| (d.0).0 .0), Ok(90.0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("abc m".parse::<Meter>(), Err(ParseError::InvalidNumber));
+        assert_eq!("1.5 m".parse::<Meter<i32>>(), Err(ParseError::InvalidNumber));
+        assert_eq!("3 xyz".parse::<Meter>(), Err(ParseError::UnknownUnit { at: 2 }));
+        assert_eq!("3 mkg".parse::<Kilogram>(), Err(ParseError::UnknownUnit { at: 2 }));
+
+        // 种类不同的量不能互相解析 / Quantities of different kinds do not parse as each other
+        let err = "5 J".parse::<Torque>().unwrap_err();
+        assert_eq!(format!("{}", err), "dimension mismatch: expected N·m, found J");
+        assert!("5 N·m".parse::<Joule>().is_err());
+        assert!("5 N.m".parse::<Joule>().is_err());
+        // 乘积不论顺序都不带种类 / Products carry no kind in either order
+        assert_eq!("5 N*m".parse::<Joule>(), Ok(Joule::new(5.0)));
+        assert_eq!("5 m*N".parse::<Joule>(), Ok(Joule::new(5.0)));
+        assert_eq!("5 m·N".parse::<Joule>(), Ok(Joule::new(5.0)));
+        assert!("5 N*m".parse::<Torque>().is_err());
+        assert!("5 m*N".parse::<Torque>().is_err());
+        assert_eq!("5 kN.m".parse::<Torque<f64, Kilo>>(), Ok(Torque::new(5.0)));
+        assert!("3 Bq".parse::<Hertz>().is_err());
+        assert!("3 s^-1".parse::<Becquerel>().is_err());
+        assert!("20 mSv".parse::<Gray<f64, Milli>>().is_err());
+        assert_eq!("3 kkm/h".parse::<ParsedQuantity<f64>>(), Err(ParseError::UnknownUnit { at: 2 }));
+        assert_eq!("3 kin".parse::<ParsedQuantity<f64>>(), Err(ParseError::UnknownUnit { at: 2 }));
+        assert_eq!("3 m)".parse::<Meter>(), Err(ParseError::Syntax { at: 3 }));
+        assert_eq!("2 km".parse::<Meter<i8>>(), Err(ParseError::Overflow));
+
+        // 指数与词头的累加溢出时报错，而不是 panic 或长时间计算
+        // Exponents and prefixes that overflow are reported instead of panicking or hanging
+        assert_eq!("1 m^2147483647*m".parse::<ParsedQuantity<f64>>(), Err(ParseError::Overflow));
+        assert_eq!("1 Ym^100000000".parse::<ParsedQuantity<f64>>(), Err(ParseError::Overflow));
+        assert_eq!("1 m^-2147483648/m".parse::<ParsedQuantity<f64>>(), Err(ParseError::Overflow));
+        assert_eq!("1 min^2147483647".parse::<ParsedQuantity<f64>>().map(|q| q.dimension.exponents[2]), Ok(i32::MAX));
+        assert_eq!("1e2000000000 m".parse::<Meter>(), Ok(Meter::new(f64::INFINITY)));
+
+        let err = "9.81 m/s".parse::<Acceleration>().unwrap_err();
+        assert_eq!(err, ParseError::DimensionMismatch {
+            expected: DynDimension::new([1, 0, -2, 0, 0, 0, 0], 0),
+            found: DynDimension::new([1, 0, -1, 0, 0, 0, 0], 0),
+        });
+        assert_eq!(format!("{}", err), "dimension mismatch: expected m·s⁻², found m·s⁻¹");
+        let err = "2 kg".parse::<Si<Var<f64>, Dimensionless, Prefix<Z0>>>().unwrap_err();
+        assert_eq!(format!("{}", err), "dimension mismatch: expected 1, found kg");
+
+        // 带角度的目标不接受纯比值，也不混用平面角与立体角
+        // Targets with an angle reject plain ratios and keep plane and solid angles apart
+        let err = "0.5".parse::<PlaneAngle>().unwrap_err();
+        assert_eq!(format!("{}", err), "dimension mismatch: expected rad, found 1");
+        assert!("2 sr".parse::<PlaneAngle>().is_err());
+        let err = "2 rad·m".parse::<Count>().unwrap_err();
+        assert_eq!(format!("{}", err), "dimension mismatch: expected 1, found m·rad");
+    }
+
+    /// 各风格的输出都能解析回原值
+    /// The output of every parseable style parses back to the original value
+    fn round_trip<Q, V>(q: Q, value: impl Fn(&Q) -> V)
+    where
+        Q: Render + FromStr<Err = ParseError>,
+        V: PartialEq + core::fmt::Debug,
+    {
+        let styles = [(Style::Unicode, false), (Style::Unicode, true), (Style::Ascii, false), (Style::Ascii, true)];
+        for (style, solidus) in styles {
+            let text = format!("{}", Formatted::new(&q).style(style).solidus(solidus));
+            let parsed: Q = text.parse().unwrap_or_else(|e| panic!("{}: {}", text, e));
+            assert_eq!(value(&parsed), value(&q), "{}", text);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        round_trip(Acceleration::<f64>::new(9.81), |q| q.0 .0);
+        round_trip(Meter::<i32, Kilo>::new(42), |q| q.0 .0);
+        round_trip(Kilogram::<i32>::new(2), |q| q.0 .0);
+        round_trip(Kilogram::<i32, Milli>::new(2), |q| q.0 .0);
+        round_trip(Kilogram::<i32, Kilo>::new(2), |q| q.0 .0);
+        round_trip(Density::<i32>::new(2), |q| q.0 .0);
+        round_trip(Newton::<f64, Kilo>::new(1.5), |q| q.0 .0);
+        round_trip(Torque::<f64, Kilo>::new(1.5), |q| q.0 .0);
+        round_trip(<Becquerel>::new(3.0), |q| q.0 .0);
+        round_trip(Sievert::<f64, Milli>::new(20.0), |q| q.0 .0);
+        round_trip(Ohm::<i32, Kilo>::new(3), |q| q.0 .0);
+        round_trip(Second::<f64, Micro>::new(4.0), |q| q.0 .0);
+        round_trip(Area::<i32, Kilo>::new(3), |q| q.0 .0);
+        round_trip(Si::<Var<i32>, Dimension<Z0, N1, N1, Z0, Z0, Z0, Z0>, Prefix<Z0>>::new(2), |q| q.0 .0);
+        round_trip(Si::<Var<f64>, Dimensionless, Prefix<Const<-2>>>::new(5.0), |q| q.0 .0);
+        round_trip(Hours::new(2), |q| (q.0).0 .0);
+        round_trip(Minutes::<f64>::new(1.5), |q| (q.0).0 .0);
+        round_trip(Degrees::new(90.0), |q| (q.0).0 .0);
+        round_trip(PlaneAngle::<f64>::new(1.5), |q| q.0 .0);
+        round_trip(PlaneAngle::<f64, Milli>::new(3.0), |q| q.0 .0);
+        round_trip(SolidAngle::<f64>::new(2.0), |q| q.0 .0);
+        round_trip(<ArcLength>::new(1.0) / <Second>::new(1.0), |q| q.0 .0);
+        round_trip(KilometersPerHour::new(3.0), |q| (q.0).0 .0);
+        round_trip(Unit::<Meter<i32>, Ratio<P1, Z0, P1, Const<2>>>::new(3), |q| (q.0).0 .0);
+    }
+}
@@ -0,0 +1,204 @@
+//! 以比例缩放的单位的符号
+//! Symbols of ratio-scaled units
+//!
+//! [`RatioSymbol<D>`] 把比例类型与其在量纲 `D` 下的单位符号关联起来：`Ratio` 在编译期查
+//! [`RATIO_SYMBOLS`] 表，`Approx` 使用其 [`MeasuredScale::NAME`]。
+//! 查不到符号时为 `None`，显示时退化为打印比例系数。
+//! 表外的单位（如周、海里）由用户实现 [`UnitLabel`]，再以 [`Labeled`] 作为比例逐个登记。
+//! [`RatioSymbol<D>`] associates a ratio type with its unit symbol in the dimension
+//! `D`: a `Ratio` looks itself up in the [`RATIO_SYMBOLS`] table at compile time and
+//! an `Approx` uses its [`MeasuredScale::NAME`]. When there is no symbol the result
+//! is `None` and display falls back to printing the ratio's factor.
+//! Units outside the table (a week, a nautical mile, …) opt in one at a time by
+//! implementing [`UnitLabel`] and using [`Labeled`] as the ratio.
+//!
+//! 表中 siunitx 未预定义的单位，其 LaTeX 宏需在导言区声明：
+//! Units in the table that siunitx does not predefine need their LaTeX macros
+//! declared in the preamble:
+//!
+//! ```latex
+//! \DeclareSIUnit\gon{gon}
+//! \DeclareSIUnit\rev{rev}
+//! \DeclareSIUnit\rpm{rpm}
+//! \DeclareSIUnit\inch{in}
+//! \DeclareSIUnit\foot{ft}
+//! ```
+
+use crate::number::TypedInt;
+use crate::quantity::{Dimensional, Ratio, PrimeList, Approx, MeasuredScale, Labeled, UnitLabel};
+use super::UnitNames;
+use super::symbols::names;
+
+/// 比例单位符号表的登记项
+/// Entry of the ratio unit symbol table
+#[derive(Debug, Clone, Copy)]
+pub struct RatioEntry {
+    /// 量纲指数（m, kg, s, A, K, mol, cd）/ Dimensional exponents (m, kg, s, A, K, mol, cd)
+    pub dimension: [i32; 7],
+    /// 5、π、3、2 的指数 / Exponents of 5, π, 3 and 2
+    pub exponents: [i32; 4],
+    /// 其它质因数之积的精确分数 / Exact fraction of the other prime factors
+    pub primes: (i128, i128),
+    /// 写法 / Spellings
+    pub names: UnitNames,
+    /// 能否加词头：已含词头或本身是复合单位的（如 km/h、rpm）与英制单位不加
+    /// Whether a prefix may attach: not for units that already hold a prefix or are
+    /// compound (such as km/h and rpm), nor for imperial units
+    pub prefixable: bool,
+}
+
+const TIME: [i32; 7] = [0, 0, 1, 0, 0, 0, 0];
+const ANGLE: [i32; 7] = [0; 7];
+const FREQUENCY: [i32; 7] = [0, 0, -1, 0, 0, 0, 0];
+const LENGTH: [i32; 7] = [1, 0, 0, 0, 0, 0, 0];
+const AREA: [i32; 7] = [2, 0, 0, 0, 0, 0, 0];
+const VOLUME: [i32; 7] = [3, 0, 0, 0, 0, 0, 0];
+const MASS: [i32; 7] = [0, 1, 0, 0, 0, 0, 0];
+const VELOCITY: [i32; 7] = [1, 0, -1, 0, 0, 0, 0];
+const PRESSURE: [i32; 7] = [-1, 1, -2, 0, 0, 0, 0];
+
+const fn entry(dimension: [i32; 7], exponents: [i32; 4], primes: (i128, i128), names: UnitNames) -> RatioEntry {
+    RatioEntry { dimension, exponents, primes, names, prefixable: true }
+}
+
+/// 不加词头的登记项 / An entry that takes no prefix
+const fn fixed(dimension: [i32; 7], exponents: [i32; 4], primes: (i128, i128), names: UnitNames) -> RatioEntry {
+    RatioEntry { prefixable: false, ..entry(dimension, exponents, primes, names) }
+}
+
+/// 比例单位符号表（GB 3100 表6 的可与SI并用的单位及常用单位）
+/// Ratio unit symbol table (units accepted for use with the SI per GB 3100 table 6, plus common units)
+pub const RATIO_SYMBOLS: &[RatioEntry] = &[
+    // 时间 / Time
+    entry(TIME, [1, 0, 1, 2], (1, 1), names("min", "min", "\\minute", "minute", "minutes", "分")),
+    entry(TIME, [2, 0, 2, 4], (1, 1), names("h", "h", "\\hour", "hour", "hours", "[小]时")),
+    entry(TIME, [2, 0, 3, 7], (1, 1), names("d", "d", "\\day", "day", "days", "日")),
+    // 平面角 / Plane angle
+    entry(ANGLE, [-1, 1, -2, -2], (1, 1), names("°", "deg", "\\degree", "degree", "degrees", "度")),
+    entry(ANGLE, [-2, 1, -3, -4], (1, 1), names("′", "arcmin", "\\arcminute", "arcminute", "arcminutes", "[角]分")),
+    entry(ANGLE, [-3, 1, -4, -6], (1, 1), names("″", "arcsec", "\\arcsecond", "arcsecond", "arcseconds", "[角]秒")),
+    entry(ANGLE, [-2, 1, 0, -3], (1, 1), names("gon", "gon", "\\gon", "gon", "gons", "百分度")),
+    entry(ANGLE, [0, 1, 0, 1], (1, 1), names("rev", "rev", "\\rev", "revolution", "revolutions", "转")),
+    // 转速 / Rotational speed
+    fixed(FREQUENCY, [-1, 1, -1, -1], (1, 1), names("rpm", "rpm", "\\rpm", "revolution per minute", "revolutions per minute", "转每分")),
+    // 长度、面积、体积 / Length, area, volume
+    fixed(LENGTH, [-4, 0, 0, -3], (127, 1), names("in", "in", "\\inch", "inch", "inches", "英寸")),
+    fixed(LENGTH, [-4, 0, 1, -1], (127, 1), names("ft", "ft", "\\foot", "foot", "feet", "英尺")),
+    entry(AREA, [4, 0, 0, 4], (1, 1), names("ha", "ha", "\\hectare", "hectare", "hectares", "公顷")),
+    entry(VOLUME, [-3, 0, 0, -3], (1, 1), names("L", "L", "\\litre", "litre", "litres", "升")),
+    // 质量 / Mass
+    entry(MASS, [3, 0, 0, 3], (1, 1), names("t", "t", "\\tonne", "tonne", "tonnes", "吨")),
+    // 速度 / Velocity
+    fixed(VELOCITY, [1, 0, -2, -1], (1, 1), names("km/h", "km/h", "\\kilo\\metre\\per\\hour", "kilometre per hour", "kilometres per hour", "千米每[小]时")),
+    // 压力 / Pressure
+    entry(PRESSURE, [5, 0, 0, 5], (1, 1), names("bar", "bar", "\\bar", "bar", "bars", "巴")),
+];
+
+const fn same<const N: usize>(a: &[i32; N], b: &[i32; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// 在 [`RATIO_SYMBOLS`] 中查找登记项（编译期可用）
+/// Looks up the entry in [`RATIO_SYMBOLS`] (usable at compile time)
+pub const fn lookup_ratio_entry(dimension: &[i32; 7], exponents: &[i32; 4], primes: Option<(i128, i128)>) -> Option<&'static RatioEntry> {
+    let Some((num, den)) = primes else {
+        return None;
+    };
+    let mut i = 0;
+    while i < RATIO_SYMBOLS.len() {
+        let e = &RATIO_SYMBOLS[i];
+        if same(&e.dimension, dimension) && same(&e.exponents, exponents) && e.primes.0 == num && e.primes.1 == den {
+            return Some(e);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// 在 [`RATIO_SYMBOLS`] 中查找写法（编译期可用）
+/// Looks up the spellings in [`RATIO_SYMBOLS`] (usable at compile time)
+pub const fn lookup_ratio_symbol(dimension: &[i32; 7], exponents: &[i32; 4], primes: Option<(i128, i128)>) -> Option<UnitNames> {
+    match lookup_ratio_entry(dimension, exponents, primes) {
+        Some(e) => Some(e.names),
+        None => None,
+    }
+}
+
+/// 比例在量纲 `D` 下的单位符号
+/// Unit symbol of a ratio in the dimension `D`
+pub trait RatioSymbol<D: Dimensional> {
+    /// 写法，未登记时为 `None` / The spellings; `None` when not registered
+    const NAMES: Option<UnitNames>;
+
+    /// 能否加词头（见 [`RatioEntry::prefixable`]）
+    /// Whether a prefix may attach (see [`RatioEntry::prefixable`])
+    const PREFIXABLE: bool = true;
+
+    /// 符号，未登记时为 `None` / The symbol; `None` when not registered
+    const SYMBOL: Option<&'static str> = match Self::NAMES {
+        Some(names) => Some(names.symbol),
+        None => None,
+    };
+}
+
+impl<D, E5, EPi, E3, E2, Primes> RatioSymbol<D> for Ratio<E5, EPi, E3, E2, Primes>
+where
+    D: Dimensional,
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+{
+    const NAMES: Option<UnitNames> = match ratio_entry::<D>(&[E5::I32, EPi::I32, E3::I32, E2::I32], Primes::EXACT) {
+        Some(e) => Some(e.names),
+        None => None,
+    };
+    const PREFIXABLE: bool = match ratio_entry::<D>(&[E5::I32, EPi::I32, E3::I32, E2::I32], Primes::EXACT) {
+        Some(e) => e.prefixable,
+        None => true,
+    };
+}
+
+/// 比例在量纲 `D` 下的登记项
+/// The entry of a ratio in the dimension `D`
+const fn ratio_entry<D: Dimensional>(exponents: &[i32; 4], primes: Option<(i128, i128)>) -> Option<&'static RatioEntry> {
+    // 登记表只含SI量纲，带自定义基本量纲时不查表
+    // The table holds SI dimensions only, so custom base dimensions skip the lookup
+    if same(&D::EXTRA_EXPONENTS, &[0; 4]) {
+        lookup_ratio_entry(&D::EXPONENTS, exponents, primes)
+    } else {
+        None
+    }
+}
+
+// 带名称的比例使用其名称
+// A named ratio uses its name
+impl<D: Dimensional, L: UnitLabel, R> RatioSymbol<D> for Labeled<L, R> {
+    const NAMES: Option<UnitNames> = Some(L::NAMES);
+}
+
+// 不含额外精确比例的实测单位使用其名称
+// A measured unit without an extra exact ratio uses its name
+impl<D, M, E5, EPi, E3, E2, Primes> RatioSymbol<D> for Approx<M, Ratio<E5, EPi, E3, E2, Primes>>
+where
+    D: Dimensional,
+    M: MeasuredScale,
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+{
+    const NAMES: Option<UnitNames> = match Ratio::<E5, EPi, E3, E2, Primes>::EXACT {
+        Some((1, 1)) if EPi::I32 == 0 && !M::NAME.is_empty() => Some(UnitNames::plain(M::NAME)),
+        _ => None,
+    };
+}
@@ -0,0 +1,506 @@
+//! 显示引擎：把数值与单位布局按风格写出
+//! Rendering engine: writes a value and its unit layout in a given style
+
+use core::fmt::{self, Display, Write};
+
+use super::{DimensionNames, Style, Locale, FormatOptions};
+use super::symbols::{UnitNames, PrefixNames, BASE_UNITS, GRAM, RADIAN, STERADIAN, DIMENSIONLESS, find_prefix, find_named_unit};
+
+// ========== 单位布局 ==========
+// ========== Unit Layout ==========
+
+/// 单位中的一项：词头、单位与指数
+/// One term of a unit: prefix, unit and power
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Term {
+    pub prefix: Option<&'static PrefixNames>,
+    pub names: UnitNames,
+    pub power: i32,
+}
+
+/// 单位项数的上限：7个SI基本单位、角度与4个自定义基本单位
+/// Maximum number of unit terms: the 7 SI base units, the angle and 4 custom base units
+const MAX_TERMS: usize = 7 + 1 + 4;
+
+/// 数值之外需要写出的全部信息
+/// Everything written after the value
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Layout {
+    /// 单位各项，先正指数后负指数 / Unit terms, positive powers first
+    pub terms: [Option<Term>; MAX_TERMS],
+    /// 未能并入单位的10的幂次 / Power of ten that could not merge into the unit
+    pub scale: i32,
+    /// 未登记比例的系数 / Factor of an unregistered ratio
+    pub factor: Option<f64>,
+}
+
+impl Layout {
+    fn empty() -> Self {
+        Layout { terms: [None; MAX_TERMS], scale: 0, factor: None }
+    }
+
+    fn push(&mut self, term: Term) {
+        let slot = self.terms.iter_mut().find(|t| t.is_none()).expect("unit layout exceeds MAX_TERMS terms");
+        *slot = Some(term);
+    }
+
+    pub(crate) fn terms(&self) -> impl Iterator<Item = &Term> + '_ {
+        self.terms.iter().flatten()
+    }
+
+    fn is_dimensionless(&self) -> bool {
+        self.terms[0].is_none()
+    }
+
+    /// 由量纲指数与词头构造（词头尽量并入第一项）
+    /// Builds the layout from exponents and prefix (the prefix merges into the first term when possible)
+    pub(crate) fn si(exponents: &[i32; 7], prefix: i32) -> Self {
+        let mut layout = Layout::empty();
+        if let Some(named) = find_named_unit(exponents) {
+            match find_prefix(prefix) {
+                Some(p) => layout.push(Term { prefix: p, names: named.names, power: 1 }),
+                None => {
+                    layout.scale = prefix;
+                    layout.push(Term { prefix: None, names: named.names, power: 1 });
+                }
+            }
+            return layout;
+        }
+        let positive = exponents.iter().enumerate().filter(|(_, e)| **e > 0);
+        let negative = exponents.iter().enumerate().filter(|(_, e)| **e < 0);
+        for (i, &power) in positive.chain(negative) {
+            if !layout.is_dimensionless() {
+                layout.push(Term { prefix: None, names: BASE_UNITS[i], power });
+                continue;
+            }
+            // 词头只并入指数为1的首项；千克的词头加在克上
+            // The prefix merges only into a first term of power 1; kilogram prefixes attach to the gram
+            let merged = match (i, power) {
+                (1, 1) => find_prefix(prefix + 3).map(|p| (p, GRAM)),
+                (_, 1) => find_prefix(prefix).map(|p| (p, BASE_UNITS[i])),
+                _ => (prefix == 0).then_some((None, BASE_UNITS[i])),
+            };
+            match merged {
+                Some((prefix, names)) => layout.push(Term { prefix, names, power }),
+                None => {
+                    layout.scale = prefix;
+                    layout.push(Term { prefix: None, names: BASE_UNITS[i], power });
+                }
+            }
+        }
+        if layout.is_dimensionless() {
+            layout.scale = prefix;
+        }
+        layout
+    }
+
+    /// 带角度伪量纲的SI单位：角度项写在正指数项之后，指数为2的纯角度写作 sr
+    /// An SI unit with the angle pseudo-dimension: the angle term follows the positive
+    /// powers, and a bare angle of power 2 is written sr
+    pub(crate) fn angular(exponents: &[i32; 7], angle: i32, prefix: i32) -> Self {
+        if angle == 0 {
+            return Layout::si(exponents, prefix);
+        }
+        if *exponents == DIMENSIONLESS && (angle == 1 || angle == 2) {
+            return Layout::ratio(if angle == 1 { RADIAN } else { STERADIAN }, prefix);
+        }
+        let mut layout = Layout::si(exponents, prefix);
+        let at = if angle > 0 {
+            layout.terms().take_while(|t| t.power > 0).count()
+        } else {
+            layout.terms().count()
+        };
+        let len = layout.terms().count();
+        assert!(len < MAX_TERMS, "unit layout exceeds MAX_TERMS terms");
+        layout.terms.copy_within(at..len, at + 1);
+        layout.terms[at] = Some(Term { prefix: None, names: RADIAN, power: angle });
+        layout
+    }
+
+    /// 带自定义基本量纲的单位：自定义基本单位的正指数项在前（词头并入其首项），其后为SI
+    /// 基本单位的正指数项、角度项、SI 基本单位的负指数项与自定义基本单位的负指数项
+    /// A unit with custom base dimensions: the positive custom base terms come first (the
+    /// prefix merges into the first of them), followed by the positive SI base terms, the
+    /// angle term, the negative SI base terms and the negative custom base terms
+    pub(crate) fn extended(exponents: &[i32; 7], angle: i32, bases: &[UnitNames], extra: &[i32; 4], prefix: i32) -> Self {
+        let custom = || bases.iter().zip(extra).filter(|(_, e)| **e != 0);
+        if custom().next().is_none() {
+            return Layout::angular(exponents, angle, prefix);
+        }
+        let si = || BASE_UNITS.iter().zip(exponents).filter(|(_, e)| **e != 0);
+        let mut layout = Layout::empty();
+        for (&names, &power) in custom().filter(|(_, e)| **e > 0) {
+            if !layout.is_dimensionless() {
+                layout.push(Term { prefix: None, names, power });
+                continue;
+            }
+            let merged = if power == 1 { find_prefix(prefix) } else { (prefix == 0).then_some(None) };
+            match merged {
+                Some(prefix) => layout.push(Term { prefix, names, power }),
+                None => {
+                    layout.scale = prefix;
+                    layout.push(Term { prefix: None, names, power });
+                }
+            }
+        }
+        if layout.is_dimensionless() {
+            layout.scale = prefix;
+        }
+        for (&names, &power) in si().filter(|(_, e)| **e > 0) {
+            layout.push(Term { prefix: None, names, power });
+        }
+        if angle > 0 {
+            layout.push(Term { prefix: None, names: RADIAN, power: angle });
+        }
+        for (&names, &power) in si().filter(|(_, e)| **e < 0) {
+            layout.push(Term { prefix: None, names, power });
+        }
+        if angle < 0 {
+            layout.push(Term { prefix: None, names: RADIAN, power: angle });
+        }
+        for (&names, &power) in custom().filter(|(_, e)| **e < 0) {
+            layout.push(Term { prefix: None, names, power });
+        }
+        layout
+    }
+
+    /// 静态量纲 `D` 的单位：有种类时用种类的写法，否则按指数写出
+    /// The unit of the static dimension `D`: the kind's spelling when it has one, otherwise the exponents
+    pub(crate) fn dimension<D: DimensionNames>(prefix: i32) -> Self {
+        match D::KIND_NAMES {
+            Some(names) => Layout::ratio(*names, prefix),
+            None => Layout::extended(&D::EXPONENTS, D::ANGLE, D::EXTRA_BASES, &D::EXTRA_EXPONENTS, prefix),
+        }
+    }
+
+    /// 附加未登记比例的系数
+    /// Attaches the factor of an unregistered ratio
+    pub(crate) fn with_factor(mut self, factor: f64) -> Self {
+        self.factor = Some(factor);
+        self
+    }
+
+    /// 有登记符号的比例单位
+    /// A ratio unit with a registered symbol
+    pub(crate) fn ratio(names: UnitNames, prefix: i32) -> Self {
+        let mut layout = Layout::empty();
+        match find_prefix(prefix) {
+            Some(p) => layout.push(Term { prefix: p, names, power: 1 }),
+            None => {
+                layout.scale = prefix;
+                layout.push(Term { prefix: None, names, power: 1 });
+            }
+        }
+        layout
+    }
+}
+
+// ========== 写出 ==========
+// ========== Writing ==========
+
+/// 统计写出的字符数，用于按宽度填充
+/// Counts the written characters, used for width padding
+pub(crate) struct Counter(pub usize);
+
+impl Write for Counter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// 记录数值的文本是否为 ±1，用于英文名称的单复数
+/// Records whether the value's text is ±1, for singular or plural English names
+struct OneProbe {
+    state: u8, // 0 起始, 1 已见1, 2 小数点后全为0, 3 否 / 0 start, 1 seen 1, 2 zero decimals, 3 no
+}
+
+impl Write for OneProbe {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            self.state = match (self.state, c) {
+                (0, '-' | '+') => 0,
+                (0, '1') => 1,
+                (1, '.') => 2,
+                (2, '0') => 2,
+                _ => 3,
+            };
+        }
+        Ok(())
+    }
+}
+
+fn is_one<T: Display>(value: &T) -> bool {
+    let mut probe = OneProbe { state: 0 };
+    write!(probe, "{}", value).is_ok() && matches!(probe.state, 1 | 2)
+}
+
+/// 以上标数字写出整数
+/// Writes an integer in superscript digits
+pub(crate) fn write_superscript(w: &mut dyn Write, n: i32) -> fmt::Result {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    if n < 0 {
+        w.write_char('⁻')?;
+    }
+    let n = n.unsigned_abs();
+    let mut div = 1;
+    while n / div >= 10 {
+        div *= 10;
+    }
+    while div > 0 {
+        w.write_char(DIGITS[(n / div % 10) as usize])?;
+        div /= 10;
+    }
+    Ok(())
+}
+
+/// 按精度写出数值
+/// Writes the value with the given precision
+fn write_value<T: Display>(w: &mut dyn Write, value: &T, precision: Option<usize>) -> fmt::Result {
+    match precision {
+        Some(p) => write!(w, "{:.*}", p, value),
+        None => write!(w, "{}", value),
+    }
+}
+
+/// 紧贴数值书写、不加空格的单位符号（角的度、分、秒）
+/// Unit symbols written directly after the value without a space (degree, minute and second of arc)
+fn unspaced(symbol: &str) -> bool {
+    matches!(symbol, "°" | "′" | "″")
+}
+
+/// 按风格写出数值与单位
+/// Writes the value and the unit in the given style
+pub(crate) fn render<T: Display>(
+    w: &mut dyn Write,
+    value: &T,
+    precision: Option<usize>,
+    layout: &Layout,
+    options: &FormatOptions,
+) -> fmt::Result {
+    let chinese = options.locale == Locale::Chinese;
+    match options.style {
+        Style::Latex => render_latex(w, value, precision, layout),
+        style => {
+            write_value(w, value, precision)?;
+            if let Some(factor) = layout.factor {
+                match style {
+                    Style::Ascii => write!(w, "*{}", factor)?,
+                    Style::Long if !chinese => write!(w, " times {}", factor)?,
+                    _ => write!(w, "×{}", factor)?,
+                }
+            }
+            if layout.scale != 0 {
+                match style {
+                    Style::Ascii => write!(w, "e{}", layout.scale)?,
+                    Style::Long if !chinese => write!(w, "e{}", layout.scale)?,
+                    _ => {
+                        w.write_str("×10")?;
+                        write_superscript(w, layout.scale)?;
+                    }
+                }
+            }
+            let Some(first) = layout.terms().next() else {
+                return Ok(());
+            };
+            if style != Style::Unicode || chinese || !unspaced(first.names.symbol) {
+                w.write_char(' ')?;
+            }
+            match style {
+                Style::Long if chinese => write_chinese_long(w, layout),
+                Style::Long => write_long(w, layout, is_one(value)),
+                Style::Ascii => write_symbols(w, layout, Spelling::Ascii, options.solidus),
+                _ if chinese => write_symbols(w, layout, Spelling::Chinese, options.solidus),
+                _ => write_symbols(w, layout, Spelling::Unicode, options.solidus),
+            }
+        }
+    }
+}
+
+/// 单位符号的写法
+/// Spelling of unit symbols
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Spelling {
+    Unicode,
+    Ascii,
+    /// 中文简称 / Short Chinese names
+    Chinese,
+}
+
+impl Spelling {
+    fn write(self, w: &mut dyn Write, names: &UnitNames) -> fmt::Result {
+        match self {
+            Spelling::Unicode => w.write_str(names.symbol),
+            Spelling::Ascii => w.write_str(names.ascii),
+            Spelling::Chinese => write_chinese(w, names.chinese, false),
+        }
+    }
+}
+
+/// 写出中文名称：全称保留方括号内的字，简称省略之
+/// Writes a Chinese name: the full name keeps the bracketed characters, the short name omits them
+fn write_chinese(w: &mut dyn Write, name: &str, full: bool) -> fmt::Result {
+    let mut optional = false;
+    for c in name.chars() {
+        match c {
+            '[' => optional = true,
+            ']' => optional = false,
+            c if full || !optional => w.write_char(c)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// 以符号写出单位
+/// Writes the unit as symbols
+fn write_symbols(w: &mut dyn Write, layout: &Layout, spelling: Spelling, solidus: bool) -> fmt::Result {
+    let ascii = spelling == Spelling::Ascii;
+    let term = |w: &mut dyn Write, t: &Term, power: i32| -> fmt::Result {
+        if let Some(p) = t.prefix {
+            spelling.write(w, &p.names)?;
+        }
+        spelling.write(w, &t.names)?;
+        match power {
+            1 => Ok(()),
+            _ if ascii => write!(w, "^{}", power),
+            _ => write_superscript(w, power),
+        }
+    };
+    let separator = if ascii { '*' } else { '·' };
+    let denominators = layout.terms().filter(|t| t.power < 0).count();
+    if !solidus || denominators == 0 {
+        for (i, t) in layout.terms().enumerate() {
+            if i > 0 {
+                w.write_char(separator)?;
+            }
+            term(w, t, t.power)?;
+        }
+        return Ok(());
+    }
+    let mut numerators = layout.terms().filter(|t| t.power > 0).peekable();
+    if numerators.peek().is_none() {
+        w.write_char('1')?;
+    }
+    for (i, t) in numerators.enumerate() {
+        if i > 0 {
+            w.write_char(separator)?;
+        }
+        term(w, t, t.power)?;
+    }
+    w.write_char('/')?;
+    if denominators > 1 {
+        w.write_char('(')?;
+    }
+    for (i, t) in layout.terms().filter(|t| t.power < 0).enumerate() {
+        if i > 0 {
+            w.write_char(separator)?;
+        }
+        term(w, t, -t.power)?;
+    }
+    if denominators > 1 {
+        w.write_char(')')?;
+    }
+    Ok(())
+}
+
+/// 以英文全称写出单位，如 "metres per second squared"
+/// Writes the unit with long English names, e.g. "metres per second squared"
+fn write_long(w: &mut dyn Write, layout: &Layout, singular: bool) -> fmt::Result {
+    let last_numerator = layout.terms().filter(|t| t.power > 0).count();
+    for (i, t) in layout.terms().enumerate() {
+        if i > 0 {
+            w.write_char(' ')?;
+        }
+        if t.power < 0 {
+            w.write_str("per ")?;
+        } else {
+            match t.power {
+                2 => w.write_str("square ")?,
+                3 => w.write_str("cubic ")?,
+                _ => {}
+            }
+        }
+        if let Some(p) = t.prefix {
+            w.write_str(p.names.singular)?;
+        }
+        // 只有分子的最后一项随数值变为复数 / Only the last numerator term follows the value's number
+        let plural = !singular && t.power > 0 && i + 1 == last_numerator;
+        w.write_str(if plural { t.names.plural } else { t.names.singular })?;
+        match t.power.abs() {
+            1 => {}
+            2 if t.power < 0 => w.write_str(" squared")?,
+            3 if t.power < 0 => w.write_str(" cubed")?,
+            2 | 3 => {}
+            n => write!(w, " to the power {}", n)?,
+        }
+    }
+    Ok(())
+}
+
+/// 以中文全称写出单位，如 "米每二次方秒"
+/// 按 GB 3100，乘积不加连词，除号读作"每"且只出现一次；长度的二次、三次幂称"平方""立方"，
+/// 其余称"二次方""三次方"。
+/// Writes the unit with full Chinese names, e.g. "米每二次方秒"
+/// Following GB 3100, products take no conjunction and the division reads "每" only
+/// once; the second and third powers of length are "平方" and "立方", other powers
+/// "二次方", "三次方".
+fn write_chinese_long(w: &mut dyn Write, layout: &Layout) -> fmt::Result {
+    const NUMERALS: [&str; 10] = ["零", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+    let mut per = false;
+    for t in layout.terms() {
+        if t.power < 0 && !per {
+            w.write_str("每")?;
+            per = true;
+        }
+        match (t.power.unsigned_abs(), t.names == BASE_UNITS[0]) {
+            (1, _) => {}
+            (2, true) => w.write_str("平方")?,
+            (3, true) => w.write_str("立方")?,
+            (n @ 2..=9, _) => {
+                w.write_str(NUMERALS[n as usize])?;
+                w.write_str("次方")?;
+            }
+            (n, _) => write!(w, "{}次方", n)?,
+        }
+        if let Some(p) = t.prefix {
+            write_chinese(w, p.names.chinese, true)?;
+        }
+        write_chinese(w, t.names.chinese, true)?;
+    }
+    Ok(())
+}
+
+/// 以 siunitx 写出，如 `\SI{9.81}{\metre\per\second\squared}`
+/// Writes with siunitx, e.g. `\SI{9.81}{\metre\per\second\squared}`
+fn render_latex<T: Display>(w: &mut dyn Write, value: &T, precision: Option<usize>, layout: &Layout) -> fmt::Result {
+    w.write_str(if layout.is_dimensionless() { "\\num{" } else { "\\SI{" })?;
+    write_value(w, value, precision)?;
+    if let Some(factor) = layout.factor {
+        write!(w, " x {}", factor)?;
+    }
+    if layout.scale != 0 {
+        write!(w, "e{}", layout.scale)?;
+    }
+    w.write_char('}')?;
+    if layout.is_dimensionless() {
+        return Ok(());
+    }
+    w.write_char('{')?;
+    for t in layout.terms() {
+        if t.power < 0 {
+            w.write_str("\\per")?;
+        }
+        if let Some(p) = t.prefix {
+            w.write_str(p.names.latex)?;
+        }
+        w.write_str(t.names.latex)?;
+        match t.power.abs() {
+            1 => {}
+            2 => w.write_str("\\squared")?,
+            3 => w.write_str("\\cubed")?,
+            n => write!(w, "\\tothe{{{}}}", n)?,
+        }
+    }
+    w.write_char('}')
+}
@@ -0,0 +1,231 @@
+//! 单位与词头的符号表
+//! Symbol tables for units and prefixes
+//!
+//! 量纲指数的顺序为 m, kg, s, A, K, mol, cd，与 [`Dimensional::EXPONENTS`](crate::quantity::Dimensional::EXPONENTS) 一致。
+//! 每个单位与词头都给出各显示风格所用的写法（见 [`Style`](super::Style)），
+//! 中文名称照录 GB 3100 各表，便于逐项核对。
+//! Dimensional exponents are ordered m, kg, s, A, K, mol, cd, matching
+//! [`Dimensional::EXPONENTS`](crate::quantity::Dimensional::EXPONENTS).
+//! Every unit and prefix lists its spelling for each display style (see [`Style`](super::Style));
+//! the Chinese names are copied from the GB 3100 tables so they can be checked entry by entry.
+
+/// 单位在各显示风格下的写法
+/// Spellings of a unit in each display style
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitNames {
+    /// Unicode 符号 / Unicode symbol
+    pub symbol: &'static str,
+    /// ASCII 符号 / ASCII symbol
+    pub ascii: &'static str,
+    /// LaTeX siunitx 宏 / LaTeX siunitx macro
+    pub latex: &'static str,
+    /// 英文单数名称 / English singular name
+    pub singular: &'static str,
+    /// 英文复数名称 / English plural name
+    pub plural: &'static str,
+    /// 中文名称，方括号内的字可省略，如 `牛[顿]`（GB 3100 的写法）
+    /// Chinese name; characters in square brackets may be omitted, e.g. `牛[顿]` (as written in GB 3100)
+    pub chinese: &'static str,
+}
+
+impl UnitNames {
+    /// 全部风格都使用同一个写法
+    /// The same spelling in every style
+    pub const fn plain(name: &'static str) -> Self {
+        UnitNames { symbol: name, ascii: name, latex: name, singular: name, plural: name, chinese: name }
+    }
+}
+
+pub(super) const fn names(
+    symbol: &'static str,
+    ascii: &'static str,
+    latex: &'static str,
+    singular: &'static str,
+    plural: &'static str,
+    chinese: &'static str,
+) -> UnitNames {
+    UnitNames { symbol, ascii, latex, singular, plural, chinese }
+}
+
+/// 国际单位制基本单位（GB 3100 表1）
+/// The SI base units (GB 3100 table 1)
+pub const BASE_UNITS: [UnitNames; 7] = [
+    names("m", "m", "\\metre", "metre", "metres", "米"),
+    names("kg", "kg", "\\kilogram", "kilogram", "kilograms", "千克"),
+    names("s", "s", "\\second", "second", "seconds", "秒"),
+    names("A", "A", "\\ampere", "ampere", "amperes", "安[培]"),
+    names("K", "K", "\\kelvin", "kelvin", "kelvins", "开[尔文]"),
+    names("mol", "mol", "\\mole", "mole", "moles", "摩[尔]"),
+    names("cd", "cd", "\\candela", "candela", "candelas", "坎[德拉]"),
+];
+
+/// 克（质量的词头加在克上）
+/// The gram (mass prefixes attach to the gram)
+pub const GRAM: UnitNames = names("g", "g", "\\gram", "gram", "grams", "克");
+
+/// 弧度（角度伪量纲，见 `WithAngle`）
+/// The radian (angle pseudo-dimension, see `WithAngle`)
+pub const RADIAN: UnitNames = names("rad", "rad", "\\radian", "radian", "radians", "弧度");
+
+/// 球面度（角度伪量纲指数为2）
+/// The steradian (angle pseudo-dimension exponent 2)
+pub const STERADIAN: UnitNames = names("sr", "sr", "\\steradian", "steradian", "steradians", "球面度");
+
+/// 牛\[顿\]米（力矩，区别于焦耳）
+///
+/// 只有整体书写的 `N·m`（ASCII 为 UCUM 式的 `N.m`）带力矩种类；`N*m`、`m·N` 等
+/// 乘积是普通的能量量纲。
+/// The newton metre (torque, as opposed to the joule)
+///
+/// Only `N·m` written as a whole (the UCUM-style `N.m` in ASCII) carries the torque
+/// kind; products such as `N*m` and `m·N` have the plain energy dimension.
+pub const NEWTON_METRE: UnitNames =
+    names("N·m", "N.m", "\\newton\\metre", "newton metre", "newton metres", "牛[顿]米");
+
+/// 贝可\[勒尔\]（放射性活度，区别于赫兹）
+/// The becquerel (activity, as opposed to the hertz)
+pub const BECQUEREL: UnitNames = names("Bq", "Bq", "\\becquerel", "becquerel", "becquerels", "贝可[勒尔]");
+
+/// 希\[沃特\]（剂量当量，区别于戈瑞）
+/// The sievert (dose equivalent, as opposed to the gray)
+pub const SIEVERT: UnitNames = names("Sv", "Sv", "\\sievert", "sievert", "sieverts", "希[沃特]");
+
+/// 摄氏度（温度值与温差共用）
+/// The degree Celsius (shared by temperature readings and differences)
+pub const DEGREE_CELSIUS: UnitNames =
+    names("°C", "degC", "\\degreeCelsius", "degree Celsius", "degrees Celsius", "摄氏度");
+
+/// 华氏度
+///
+/// siunitx 没有预定义华氏度，LaTeX 输出的 `\fahrenheit` 需要在导言区声明：
+/// `\DeclareSIUnit\fahrenheit{\degree F}`。
+/// The degree Fahrenheit
+///
+/// siunitx predefines no degree Fahrenheit, so the `\fahrenheit` in LaTeX output
+/// needs a declaration in the preamble: `\DeclareSIUnit\fahrenheit{\degree F}`.
+pub const DEGREE_FAHRENHEIT: UnitNames =
+    names("°F", "degF", "\\fahrenheit", "degree Fahrenheit", "degrees Fahrenheit", "华氏度");
+
+/// 兰氏度
+///
+/// LaTeX 输出的 `\rankine` 需要声明：`\DeclareSIUnit\rankine{\degree R}`。
+/// The degree Rankine
+///
+/// The `\rankine` in LaTeX output needs a declaration:
+/// `\DeclareSIUnit\rankine{\degree R}`.
+pub const DEGREE_RANKINE: UnitNames =
+    names("°R", "degR", "\\rankine", "degree Rankine", "degrees Rankine", "兰氏度");
+
+/// 无量纲的量纲指数
+/// Exponents of a dimensionless quantity
+pub const DIMENSIONLESS: [i32; 7] = [0; 7];
+
+/// 具有专门名称的导出单位
+/// Derived unit with a special name
+#[derive(Debug, Clone, Copy)]
+pub struct NamedUnit {
+    /// 写法 / Spellings
+    pub names: UnitNames,
+    /// 量纲指数 / Dimensional exponents
+    pub exponents: [i32; 7],
+}
+
+const fn named(names: UnitNames, exponents: [i32; 7]) -> NamedUnit {
+    NamedUnit { names, exponents }
+}
+
+/// 具有专门名称的导出单位（GB 3100 表3）
+/// 量纲相同的单位（如 Hz 与 Bq、Gy 与 Sv）无法由量纲区分，因此不在表中；
+/// 带种类的单位见 [`KIND_UNITS`]。
+/// Derived units with special names (GB 3100 table 3)
+/// Units sharing a dimension (Hz and Bq, Gy and Sv) cannot be told apart by their
+/// exponents and are therefore left out; the units of kinds are in [`KIND_UNITS`].
+pub const NAMED_UNITS: &[NamedUnit] = &[
+    named(names("N", "N", "\\newton", "newton", "newtons", "牛[顿]"), [1, 1, -2, 0, 0, 0, 0]),
+    named(names("Pa", "Pa", "\\pascal", "pascal", "pascals", "帕[斯卡]"), [-1, 1, -2, 0, 0, 0, 0]),
+    named(names("J", "J", "\\joule", "joule", "joules", "焦[耳]"), [2, 1, -2, 0, 0, 0, 0]),
+    named(names("W", "W", "\\watt", "watt", "watts", "瓦[特]"), [2, 1, -3, 0, 0, 0, 0]),
+    named(names("C", "C", "\\coulomb", "coulomb", "coulombs", "库[仑]"), [0, 0, 1, 1, 0, 0, 0]),
+    named(names("V", "V", "\\volt", "volt", "volts", "伏[特]"), [2, 1, -3, -1, 0, 0, 0]),
+    named(names("F", "F", "\\farad", "farad", "farads", "法[拉]"), [-2, -1, 4, 2, 0, 0, 0]),
+    named(names("Ω", "Ohm", "\\ohm", "ohm", "ohms", "欧[姆]"), [2, 1, -3, -2, 0, 0, 0]),
+    named(names("S", "S", "\\siemens", "siemens", "siemens", "西[门子]"), [-2, -1, 3, 2, 0, 0, 0]),
+    named(names("Wb", "Wb", "\\weber", "weber", "webers", "韦[伯]"), [2, 1, -2, -1, 0, 0, 0]),
+    named(names("T", "T", "\\tesla", "tesla", "teslas", "特[斯拉]"), [0, 1, -2, -1, 0, 0, 0]),
+    named(names("H", "H", "\\henry", "henry", "henries", "亨[利]"), [2, 1, -2, -2, 0, 0, 0]),
+    named(names("kat", "kat", "\\katal", "katal", "katals", "开[塔尔]"), [0, 0, -1, 0, 0, 1, 0]),
+];
+
+/// 量的种类专用的单位（见 [`Kinded`](crate::quantity::Kinded)），解析时带上种类
+/// Units specific to a quantity kind (see [`Kinded`](crate::quantity::Kinded)); the parser reads them with the kind
+pub const KIND_UNITS: &[NamedUnit] = &[
+    named(NEWTON_METRE, [2, 1, -2, 0, 0, 0, 0]),
+    named(BECQUEREL, [0, 0, -1, 0, 0, 0, 0]),
+    named(SIEVERT, [2, 0, -2, 0, 0, 0, 0]),
+];
+
+/// SI词头
+/// SI prefix
+#[derive(Debug, Clone, Copy)]
+pub struct PrefixNames {
+    /// 10的幂次 / Power of ten
+    pub exponent: i32,
+    /// 写法（复数名称不用）/ Spellings (the plural is unused)
+    pub names: UnitNames,
+}
+
+const fn prefix(
+    exponent: i32,
+    symbol: &'static str,
+    ascii: &'static str,
+    latex: &'static str,
+    name: &'static str,
+    chinese: &'static str,
+) -> PrefixNames {
+    PrefixNames { exponent, names: names(symbol, ascii, latex, name, name, chinese) }
+}
+
+/// SI词头（GB 3100 表5）
+/// SI prefixes (GB 3100 table 5)
+pub const PREFIXES: &[PrefixNames] = &[
+    prefix(30, "Q", "Q", "\\quetta", "quetta", "昆[它]"),
+    prefix(27, "R", "R", "\\ronna", "ronna", "容[那]"),
+    prefix(24, "Y", "Y", "\\yotta", "yotta", "尧[它]"),
+    prefix(21, "Z", "Z", "\\zetta", "zetta", "泽[它]"),
+    prefix(18, "E", "E", "\\exa", "exa", "艾[可萨]"),
+    prefix(15, "P", "P", "\\peta", "peta", "拍[它]"),
+    prefix(12, "T", "T", "\\tera", "tera", "太[拉]"),
+    prefix(9, "G", "G", "\\giga", "giga", "吉[咖]"),
+    prefix(6, "M", "M", "\\mega", "mega", "兆"),
+    prefix(3, "k", "k", "\\kilo", "kilo", "千"),
+    prefix(2, "h", "h", "\\hecto", "hecto", "百"),
+    prefix(1, "da", "da", "\\deca", "deca", "十"),
+    prefix(-1, "d", "d", "\\deci", "deci", "分"),
+    prefix(-2, "c", "c", "\\centi", "centi", "厘"),
+    prefix(-3, "m", "m", "\\milli", "milli", "毫"),
+    prefix(-6, "µ", "u", "\\micro", "micro", "微"),
+    prefix(-9, "n", "n", "\\nano", "nano", "纳[诺]"),
+    prefix(-12, "p", "p", "\\pico", "pico", "皮[可]"),
+    prefix(-15, "f", "f", "\\femto", "femto", "飞[母托]"),
+    prefix(-18, "a", "a", "\\atto", "atto", "阿[托]"),
+    prefix(-21, "z", "z", "\\zepto", "zepto", "仄[普托]"),
+    prefix(-24, "y", "y", "\\yocto", "yocto", "幺[科托]"),
+    prefix(-27, "r", "r", "\\ronto", "ronto", "柔[托]"),
+    prefix(-30, "q", "q", "\\quecto", "quecto", "亏[科托]"),
+];
+
+/// 10的 `exp` 次幂对应的词头；外层 `None` 表示没有对应词头，`Some(None)` 表示无需词头（`exp` 为0）
+/// The prefix for 10^`exp`; the outer `None` means there is no such prefix and
+/// `Some(None)` means no prefix is needed (`exp` is 0)
+pub fn find_prefix(exp: i32) -> Option<Option<&'static PrefixNames>> {
+    if exp == 0 {
+        return Some(None);
+    }
+    PREFIXES.iter().find(|p| p.exponent == exp).map(Some)
+}
+
+/// 量纲指数对应的专门名称单位
+/// The derived unit with a special name for these exponents
+pub fn find_named_unit(exponents: &[i32; 7]) -> Option<&'static NamedUnit> {
+    NAMED_UNITS.iter().find(|u| u.exponents == *exponents)
+}
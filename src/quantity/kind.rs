@@ -0,0 +1,426 @@
+//! 量的种类：量纲相同但含义不同的量
+//! Quantity kinds: quantities sharing a dimension but not a meaning
+//!
+//! 力矩与能量（N·m 与 J）、戈瑞与希沃特、赫兹与贝可勒尔量纲相同，只看量纲会被悄悄相加。
+//! [`Kinded<D, K>`] 给量纲 `D` 附加种类标记 `K`，种类不同的量不能相加减；相乘除时按
+//! [`KindMul`] / [`KindDiv`] 规则表决定结果的种类，与无种类的量相乘除则得到默认（无种类）
+//! 的量。[`reinterpret_kind`](Si::reinterpret_kind) 显式地更换或去掉种类。
+//! Torque and energy (N·m and J), the gray and the sievert, and the hertz and the
+//! becquerel share a dimension and would be added silently by dimension alone.
+//! [`Kinded<D, K>`] tags the dimension `D` with the kind `K`, and quantities of
+//! different kinds cannot be added or subtracted. In multiplication and division the
+//! [`KindMul`] / [`KindDiv`] rule table decides the kind of the result, and a factor
+//! without a kind yields the default (kindless) quantity.
+//! [`reinterpret_kind`](Si::reinterpret_kind) changes or drops the kind explicitly.
+//!
+//! 内置种类两两之间的乘除都已登记（结果不带种类），例如力矩乘活度得到普通的量。
+//! 规则表有意不设“其余组合无种类”的通配规则：稳定版 Rust 不允许通配实现与具体规则并存，
+//! 而具体规则才能让有意义的组合得到带种类的结果。因此自定义种类与其它种类相乘除时需要
+//! 自己登记规则，否则无法编译；也可以先用 `reinterpret_kind::<NoKind>()` 去掉其中一方的种类。
+//! Every pair of built-in kinds is registered for multiplication and division (with
+//! a kindless result), so a torque times an activity is a plain quantity.
+//! The rule table deliberately has no catch-all "every other pair is kindless" rule:
+//! stable Rust does not let a blanket impl coexist with specific rules, and specific
+//! rules are what let a meaningful pair produce a kinded result. A custom kind
+//! therefore needs its own rules to multiply or divide with other kinds and does not
+//! compile otherwise; alternatively drop the kind of one side with
+//! `reinterpret_kind::<NoKind>()` first.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{NoKind, TorqueKind};
+//! use unitrix::quantity::si_units::{Joule, Meter, Newton, Torque};
+//!
+//! let t: Torque = (<Newton>::new(3.0) * <Meter>::new(0.5)).reinterpret_kind::<TorqueKind>();
+//! assert_eq!(format!("{}", t), "1.5 N·m");
+//!
+//! let e: Joule = t.reinterpret_kind::<NoKind>();
+//! assert_eq!(format!("{}", e), "1.5 J");
+//! ```
+//!
+//! 力矩与能量相加无法编译：
+//! Adding a torque to an energy does not compile:
+//! ```compile_fail
+//! use unitrix::quantity::si_units::{Joule, Torque};
+//!
+//! let _ = <Torque>::new(1.0) + <Joule>::new(1.0);
+//! ```
+//!
+//! 内置种类之间相乘得到无种类的量：
+//! Built-in kinds multiply into a kindless quantity:
+//! ```rust
+//! use unitrix::quantity::{Si, NoPrefix};
+//! use unitrix::quantity::si_units::{Becquerel, Torque, PowerDimension};
+//! use unitrix::number::Var;
+//!
+//! let _: Si<Var<f64>, PowerDimension, NoPrefix> = <Torque>::new(1.0) * <Becquerel>::new(1.0);
+//! ```
+//!
+//! 未登记规则的自定义种类与其它种类相乘无法编译：
+//! A custom kind without registered rules does not multiply with another kind:
+//! ```compile_fail
+//! use unitrix::quantity::{Kind, Kinded, Si, NoPrefix, UnitNames};
+//! use unitrix::quantity::si_units::{Torque, EnergyDimension};
+//! use unitrix::number::Var;
+//!
+//! #[derive(Debug, Clone, Copy, Default)]
+//! struct HeatKind;
+//! impl Kind for HeatKind {
+//!     const NAMES: &'static UnitNames = &UnitNames::plain("J(heat)");
+//! }
+//!
+//! let heat: Si<Var<f64>, Kinded<EnergyDimension, HeatKind>, NoPrefix> = Si::new(1.0);
+//! let _ = heat * <Torque>::new(1.0);
+//! ```
+//! ```rust
+//! # use unitrix::quantity::{Kind, Kinded, Si, NoPrefix, UnitNames};
+//! # use unitrix::quantity::si_units::{Torque, EnergyDimension};
+//! # use unitrix::number::Var;
+//! # #[derive(Debug, Clone, Copy, Default)]
+//! # struct HeatKind;
+//! # impl Kind for HeatKind {
+//! #     const NAMES: &'static UnitNames = &UnitNames::plain("J(heat)");
+//! # }
+//! use unitrix::quantity::NoKind;
+//!
+//! let heat: Si<Var<f64>, Kinded<EnergyDimension, HeatKind>, NoPrefix> = Si::new(1.0);
+//! let _ = heat.reinterpret_kind::<NoKind>() * <Torque>::new(1.0);
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{Mul, Div};
+
+use crate::sealed::Sealed;
+use crate::number::{TypedInt, Primitive, Var};
+use super::{Dimension, Dimensional, Prefixed, Si, Unit, WithAngle, Extended, BaseSystem};
+use super::format::{UnitNames, NEWTON_METRE, BECQUEREL, SIEVERT};
+
+// ========== 种类 ==========
+// ========== Kinds ==========
+
+/// 量的种类标记
+/// Marker of a quantity kind
+///
+/// `NAMES` 给出该种类专用的单位写法（如 Bq），用于显示、解析，并在运行期区分种类
+/// （见 [`DynDimension`](super::DynDimension)）。
+/// `NAMES` gives the unit spellings specific to the kind (such as Bq), used to display
+/// and parse the quantity and to tell kinds apart at runtime (see
+/// [`DynDimension`](super::DynDimension)).
+pub trait Kind: Copy + Default + 'static {
+    /// 单位写法 / Unit spellings
+    const NAMES: &'static UnitNames;
+}
+
+/// 默认种类：不附加标记
+/// The default kind: no tag attached
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoKind;
+
+/// 力矩（N·m，区别于能量 J）/ Torque (N·m, as opposed to the energy J)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TorqueKind;
+
+/// 放射性活度（Bq，区别于频率 Hz）/ Activity (Bq, as opposed to the frequency Hz)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ActivityKind;
+
+/// 剂量当量（Sv，区别于吸收剂量 Gy）/ Dose equivalent (Sv, as opposed to the absorbed dose Gy)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DoseEquivalentKind;
+
+impl Kind for TorqueKind {
+    const NAMES: &'static UnitNames = &NEWTON_METRE;
+}
+
+impl Kind for ActivityKind {
+    const NAMES: &'static UnitNames = &BECQUEREL;
+}
+
+impl Kind for DoseEquivalentKind {
+    const NAMES: &'static UnitNames = &SIEVERT;
+}
+
+/// 种类或默认种类，决定附加后的量纲
+/// A kind or the default kind, deciding the tagged dimension
+pub trait KindOrNone {
+    /// 附加种类后的量纲 / The dimension with the kind attached
+    type Attached<D: Dimensional>: Dimensional;
+
+    /// 附加种类 / Attaches the kind
+    fn attach<D: Dimensional>(dimension: D) -> Self::Attached<D>;
+}
+
+impl KindOrNone for NoKind {
+    type Attached<D: Dimensional> = D;
+
+    fn attach<D: Dimensional>(dimension: D) -> D {
+        dimension
+    }
+}
+
+impl<K: Kind> KindOrNone for K {
+    type Attached<D: Dimensional> = Kinded<D, K>;
+
+    fn attach<D: Dimensional>(dimension: D) -> Kinded<D, K> {
+        Kinded(dimension, PhantomData)
+    }
+}
+
+// ========== 规则表 ==========
+// ========== Rule Table ==========
+
+/// 两种类相乘所得的种类
+/// The kind of a product of two kinds
+pub trait KindMul<Rhs> {
+    /// 结果种类 / Resulting kind
+    type Output: KindOrNone;
+}
+
+/// 两种类相除所得的种类
+/// The kind of a quotient of two kinds
+pub trait KindDiv<Rhs> {
+    /// 结果种类 / Resulting kind
+    type Output: KindOrNone;
+}
+
+// 同种类相乘除得默认种类（如 Bq/Bq 是纯比值）
+// Products and quotients of one kind are kindless (Bq/Bq is a plain ratio)
+impl<K: Kind> KindMul<K> for K {
+    type Output = NoKind;
+}
+
+impl<K: Kind> KindDiv<K> for K {
+    type Output = NoKind;
+}
+
+// 内置种类两两相乘除得默认种类（如剂量系数 Sv/Bq、力矩乘活度）
+// Products and quotients of two different built-in kinds are kindless (such as the
+// dose coefficient Sv/Bq, or a torque times an activity)
+macro_rules! impl_builtin_kind_pairs {
+    () => {};
+    ($first:ty $(, $rest:ty)*) => {
+        $(
+            impl KindMul<$rest> for $first {
+                type Output = NoKind;
+            }
+
+            impl KindMul<$first> for $rest {
+                type Output = NoKind;
+            }
+
+            impl KindDiv<$rest> for $first {
+                type Output = NoKind;
+            }
+
+            impl KindDiv<$first> for $rest {
+                type Output = NoKind;
+            }
+        )*
+        impl_builtin_kind_pairs!($($rest),*);
+    };
+}
+
+impl_builtin_kind_pairs!(TorqueKind, ActivityKind, DoseEquivalentKind);
+
+// ========== 带种类的量纲 ==========
+// ========== Dimension with a Kind ==========
+
+/// 附加种类 `K` 的量纲 `D`
+/// The dimension `D` tagged with the kind `K`
+#[derive(Debug, Clone, Copy)]
+pub struct Kinded<D, K>(D, PhantomData<K>);
+
+impl<D: Dimensional, K: Kind> Sealed for Kinded<D, K> {}
+
+impl<D: Dimensional, K: Kind> Dimensional for Kinded<D, K> {
+    const EXPONENTS: [i32; 7] = D::EXPONENTS;
+    const ANGLE: i32 = D::ANGLE;
+    const EXTRA_EXPONENTS: [i32; 4] = D::EXTRA_EXPONENTS;
+}
+
+/// 去掉种类后的量纲
+/// The dimension without its kind
+pub trait KindBase: Dimensional {
+    /// 无种类的量纲 / The kindless dimension
+    type Base: Dimensional;
+}
+
+impl<M, KG, S, A, K, MOL, CD> KindBase for Dimension<M, KG, S, A, K, MOL, CD>
+where
+    M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt,
+{
+    type Base = Self;
+}
+
+impl<D: Dimensional, A: TypedInt> KindBase for WithAngle<D, A> {
+    type Base = Self;
+}
+
+impl<D: Dimensional, K: Kind> KindBase for Kinded<D, K> {
+    type Base = D;
+}
+
+// ========== 运算 ==========
+// ========== Arithmetic ==========
+
+impl<D1, K1, D2, K2> Mul<Kinded<D2, K2>> for Kinded<D1, K1>
+where
+    D1: Mul<D2, Output: Dimensional>,
+    K1: KindMul<K2>,
+{
+    type Output = <<K1 as KindMul<K2>>::Output as KindOrNone>::Attached<<D1 as Mul<D2>>::Output>;
+
+    fn mul(self, rhs: Kinded<D2, K2>) -> Self::Output {
+        <K1 as KindMul<K2>>::Output::attach(self.0 * rhs.0)
+    }
+}
+
+impl<D1, K1, D2, K2> Div<Kinded<D2, K2>> for Kinded<D1, K1>
+where
+    D1: Div<D2, Output: Dimensional>,
+    K1: KindDiv<K2>,
+{
+    type Output = <<K1 as KindDiv<K2>>::Output as KindOrNone>::Attached<<D1 as Div<D2>>::Output>;
+
+    fn div(self, rhs: Kinded<D2, K2>) -> Self::Output {
+        <K1 as KindDiv<K2>>::Output::attach(self.0 / rhs.0)
+    }
+}
+
+// 与无种类的量相乘除时结果为默认种类
+// With a kindless factor the result has the default kind
+macro_rules! impl_kindless_ops {
+    ($([$($g:tt)*] $other:ty;)*) => {
+        $(
+            impl<D1, K1, $($g)*> Mul<$other> for Kinded<D1, K1>
+            where
+                D1: Mul<$other>,
+            {
+                type Output = <D1 as Mul<$other>>::Output;
+
+                fn mul(self, rhs: $other) -> Self::Output {
+                    self.0 * rhs
+                }
+            }
+
+            impl<D1, K1, $($g)*> Div<$other> for Kinded<D1, K1>
+            where
+                D1: Div<$other>,
+            {
+                type Output = <D1 as Div<$other>>::Output;
+
+                fn div(self, rhs: $other) -> Self::Output {
+                    self.0 / rhs
+                }
+            }
+
+            impl<D2, K2, $($g)*> Mul<Kinded<D2, K2>> for $other
+            where
+                $other: Mul<D2>,
+            {
+                type Output = <$other as Mul<D2>>::Output;
+
+                fn mul(self, rhs: Kinded<D2, K2>) -> Self::Output {
+                    self * rhs.0
+                }
+            }
+
+            impl<D2, K2, $($g)*> Div<Kinded<D2, K2>> for $other
+            where
+                $other: Div<D2>,
+            {
+                type Output = <$other as Div<D2>>::Output;
+
+                fn div(self, rhs: Kinded<D2, K2>) -> Self::Output {
+                    self / rhs.0
+                }
+            }
+        )*
+    };
+}
+
+impl_kindless_ops! {
+    [M: TypedInt, KG: TypedInt, S: TypedInt, A: TypedInt, K: TypedInt, MOL: TypedInt, CD: TypedInt]
+        Dimension<M, KG, S, A, K, MOL, CD>;
+    [D: Dimensional, A: TypedInt] WithAngle<D, A>;
+    [D: Dimensional, S: BaseSystem, E1: TypedInt, E2: TypedInt, E3: TypedInt, E4: TypedInt]
+        Extended<D, S, E1, E2, E3, E4>;
+}
+
+// ========== 显式更换种类 ==========
+// ========== Explicit Kind Changes ==========
+
+/// 量纲 `D` 改为种类 `K` 后的量纲
+/// The dimension `D` with its kind changed to `K`
+pub type Rekinded<D, K> = <K as KindOrNone>::Attached<<D as KindBase>::Base>;
+
+impl<T: Primitive, D: KindBase, Pr: Prefixed> Si<Var<T>, D, Pr> {
+    /// 把量重新解释为种类 `K`（`NoKind` 去掉种类），数值不变
+    /// Reinterprets the quantity as the kind `K` (`NoKind` drops the kind); the value is unchanged
+    pub fn reinterpret_kind<K: KindOrNone>(self) -> Si<Var<T>, Rekinded<D, K>, Pr> {
+        Si(self.0, PhantomData)
+    }
+}
+
+impl<T: Primitive, D: KindBase, Pr: Prefixed, R> Unit<Si<Var<T>, D, Pr>, R> {
+    /// 把量重新解释为种类 `K`（`NoKind` 去掉种类），数值与比例不变
+    /// Reinterprets the quantity as the kind `K` (`NoKind` drops the kind); value and ratio are unchanged
+    #[allow(clippy::type_complexity)]
+    pub fn reinterpret_kind<K: KindOrNone>(self) -> Unit<Si<Var<T>, Rekinded<D, K>, Pr>, R> {
+        Unit(self.0.reinterpret_kind::<K>(), PhantomData)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+    use crate::quantity::{Kilo, Milli, PlaneAngle, Style};
+    use crate::quantity::si_units::*;
+
+    #[test]
+    fn test_kind_algebra() {
+        let t: Torque = <Torque>::new(2.0) + <Torque>::new(1.0);
+        let e: Joule = t * <PlaneAngle>::new(2.0).without_angle();
+        assert_eq!(e.0 .0, 6.0);
+        let f: Newton = t / <Meter>::new(3.0);
+        assert_eq!(f.0 .0, 1.0);
+        let ratio: Si<Var<f64>, Dimensionless, _> = t / <Torque>::new(3.0);
+        assert_eq!(ratio.into_inner(), 1.0);
+
+        let a: Becquerel = <Becquerel>::new(4.0);
+        let hz: Hertz = a.reinterpret_kind::<NoKind>();
+        let back: Becquerel = hz.reinterpret_kind::<ActivityKind>();
+        assert_eq!(back.0 .0, 4.0);
+        let count: Si<Var<f64>, Dimensionless, _> = a * <Second>::new(2.0);
+        assert_eq!(count.into_inner(), 8.0);
+
+        let dose: Sievert = (<Joule>::new(2.0) / <Kilogram>::new(4.0)).reinterpret_kind::<DoseEquivalentKind>();
+        let gy: Gray = dose.reinterpret_kind::<NoKind>();
+        assert_eq!(gy.0 .0, 0.5);
+        let coefficient = dose / <Becquerel>::new(2.0);
+        assert_eq!(format!("{}", coefficient), "0.25 m²·s⁻¹");
+
+        // 内置种类两两相乘除都不带种类 / Every pair of built-in kinds is kindless
+        let p: Watt = <Torque>::new(2.0) * <Becquerel>::new(3.0);
+        assert_eq!(p.0 .0, 6.0);
+        let q = <Torque>::new(6.0) / <Becquerel>::new(3.0);
+        assert_eq!(format!("{}", q), "2 m²·kg·s⁻¹");
+        let r = <Becquerel>::new(2.0) * <Sievert>::new(3.0);
+        assert_eq!(format!("{}", r), "6 m²·s⁻³");
+        let s = <Sievert>::new(3.0) * <Torque>::new(2.0);
+        assert_eq!(format!("{}", s), "6 m⁴·kg·s⁻⁴");
+    }
+
+    #[test]
+    fn test_kind_display() {
+        assert_eq!(format!("{}", <Torque>::new(1.5)), "1.5 N·m");
+        assert_eq!(format!("{}", Torque::<f64, Kilo>::new(2.0)), "2 kN·m");
+        assert_eq!(format!("{}", <Becquerel>::new(3.0)), "3 Bq");
+        assert_eq!(format!("{}", Sievert::<f64, Milli>::new(20.0)), "20 mSv");
+        assert_eq!(format!("{}", <Joule>::new(1.5)), "1.5 J");
+        assert_eq!(format!("{}", <Torque>::new(2.0).format().style(Style::Long)), "2 newton metres");
+    }
+}
@@ -0,0 +1,216 @@
+// labeled.rs
+//! 带自定义名称的精确比例
+//! Exact ratios with a user-supplied name
+//!
+//! 内置的比例单位符号表只覆盖常用单位。[`UnitLabel`] 由用户为自己的标记类型实现，
+//! 再用 [`Labeled`] 把它与精确比例组合后作为 `Unit<S, R>` 的 `R` 使用，显示时即使用该名称。
+//! 换算与 `R` 完全相同；与其它比例相乘除后名称不再保留，结果按普通比例处理。
+//!
+//! The built-in table of ratio unit symbols covers common units only. Implement
+//! [`UnitLabel`] on a marker type of your own and combine it with an exact ratio
+//! through [`Labeled`]; used as the `R` of `Unit<S, R>`, the unit then displays
+//! under that name. Conversions are exactly those of `R`; multiplying or dividing
+//! by another ratio drops the name and the result is treated as a plain ratio.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use core::ops::Mul;
+//! use unitrix::number::Const;
+//! use unitrix::quantity::{Labeled, UnitLabel, UnitNames, Unit, Seconds, Day, Ratio, PrimePow};
+//!
+//! #[derive(Debug, Clone, Copy, Default)]
+//! struct WeekLabel;
+//! impl UnitLabel for WeekLabel {
+//!     const NAMES: UnitNames = UnitNames {
+//!         symbol: "wk", ascii: "wk", latex: "wk",
+//!         singular: "week", plural: "weeks", chinese: "周",
+//!     };
+//! }
+//!
+//! type Seven = Ratio<Const<0>, Const<0>, Const<0>, Const<0>, PrimePow<Const<7>, Const<1>>>;
+//! type Week = Labeled<WeekLabel, <Day as Mul<Seven>>::Output>;
+//! let w: Unit<Seconds<i64>, Week> = Unit::new(2);
+//! assert_eq!(format!("{}", w), "2 wk");
+//! assert_eq!(w.to_si().0 .0, 1_209_600);
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{Mul, Div};
+
+use crate::sealed::Sealed;
+use crate::number::TypedInt;
+use super::ratio::{Ratio, PrimeList, Scaled};
+use super::measured::{Approx, ScaleFactor};
+use super::UnitNames;
+
+/// 比例单位的名称
+/// Name of a ratio unit
+///
+/// 由用户为自己的标记类型实现；`NAMES` 给出各显示风格下的写法。
+/// Implemented on a marker type of your own; `NAMES` gives the spelling in each display style.
+pub trait UnitLabel: Copy + Default + 'static {
+    /// 写法 / Spellings
+    const NAMES: UnitNames;
+}
+
+/// 带名称的比例：名称 `L` 与比例 `R`
+/// Named ratio: the name `L` attached to the ratio `R`
+///
+/// 作为 `Unit<S, R>` 的 `R` 使用；只与同名比例相加减，与其它比例的乘除结果为 `R` 的乘除结果。
+/// Used as the `R` of `Unit<S, R>`; it only adds to and subtracts from the same
+/// named ratio, and multiplying or dividing gives the product or quotient of `R`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Labeled<L, R>(PhantomData<(L, R)>);
+
+impl<L, R> Labeled<L, R> {
+    pub fn new() -> Self {
+        Labeled(PhantomData)
+    }
+}
+
+impl<L: UnitLabel, R: Scaled> Sealed for Labeled<L, R> {}
+impl<L: UnitLabel, R: Scaled> Scaled for Labeled<L, R> {}
+
+impl<L: UnitLabel, R: ScaleFactor> ScaleFactor for Labeled<L, R> {
+    fn factor() -> f64 {
+        R::factor()
+    }
+}
+
+// ========== 与其它比例的组合 ==========
+// ========== Composition with Other Ratios ==========
+
+// Labeled * X（名称不保留）
+// Labeled * X (the name is dropped)
+impl<L, R, Rhs> Mul<Rhs> for Labeled<L, R>
+where
+    R: Mul<Rhs> + Default,
+{
+    type Output = <R as Mul<Rhs>>::Output;
+
+    fn mul(self, rhs: Rhs) -> Self::Output {
+        R::default() * rhs
+    }
+}
+
+// Labeled / X（名称不保留）
+// Labeled / X (the name is dropped)
+impl<L, R, Rhs> Div<Rhs> for Labeled<L, R>
+where
+    R: Div<Rhs> + Default,
+{
+    type Output = <R as Div<Rhs>>::Output;
+
+    fn div(self, rhs: Rhs) -> Self::Output {
+        R::default() / rhs
+    }
+}
+
+// Ratio * Labeled
+impl<L, R, E5, EPi, E3, E2, Primes> Mul<Labeled<L, R>> for Ratio<E5, EPi, E3, E2, Primes>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+    Ratio<E5, EPi, E3, E2, Primes>: Mul<R>,
+    R: Default,
+{
+    type Output = <Ratio<E5, EPi, E3, E2, Primes> as Mul<R>>::Output;
+
+    fn mul(self, _: Labeled<L, R>) -> Self::Output {
+        self * R::default()
+    }
+}
+
+// Ratio / Labeled
+impl<L, R, E5, EPi, E3, E2, Primes> Div<Labeled<L, R>> for Ratio<E5, EPi, E3, E2, Primes>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+    Ratio<E5, EPi, E3, E2, Primes>: Div<R>,
+    R: Default,
+{
+    type Output = <Ratio<E5, EPi, E3, E2, Primes> as Div<R>>::Output;
+
+    fn div(self, _: Labeled<L, R>) -> Self::Output {
+        self / R::default()
+    }
+}
+
+// Approx * Labeled
+impl<M, R0, L, R> Mul<Labeled<L, R>> for Approx<M, R0>
+where
+    Approx<M, R0>: Mul<R>,
+    R: Default,
+{
+    type Output = <Approx<M, R0> as Mul<R>>::Output;
+
+    fn mul(self, _: Labeled<L, R>) -> Self::Output {
+        self * R::default()
+    }
+}
+
+// Approx / Labeled
+impl<M, R0, L, R> Div<Labeled<L, R>> for Approx<M, R0>
+where
+    Approx<M, R0>: Div<R>,
+    R: Default,
+{
+    type Output = <Approx<M, R0> as Div<R>>::Output;
+
+    fn div(self, _: Labeled<L, R>) -> Self::Output {
+        self / R::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+    use crate::number::{Const, Var, Z0, P1};
+    use crate::quantity::{Si, Unit, NoPrefix, Seconds, Day, Days, PrimePow, NoRatio};
+
+    #[derive(Debug, Clone, Copy, Default)]
+    struct WeekLabel;
+    impl UnitLabel for WeekLabel {
+        const NAMES: UnitNames = UnitNames::plain("wk");
+    }
+
+    type Seven = Ratio<Z0, Z0, Z0, Z0, PrimePow<Const<7>, P1>>;
+    type Week = Labeled<WeekLabel, <Day as Mul<Seven>>::Output>;
+    type Weeks = Unit<Seconds<i64>, Week>;
+
+    #[test]
+    fn test_named_display() {
+        assert_eq!(format!("{}", Weeks::new(3)), "3 wk");
+        // 同名比例相加保留名称 / Adding the same named ratio keeps the name
+        assert_eq!(format!("{}", Weeks::new(1) + Weeks::new(2)), "3 wk");
+    }
+
+    #[test]
+    fn test_named_conversion() {
+        assert_eq!(Weeks::new(2).to_si().0 .0, 1_209_600);
+        let days: Days = Weeks::new(2).convert_to();
+        assert_eq!((days.0).0 .0, 14);
+        let weeks: Weeks = Days::new(21).convert_to();
+        assert_eq!((weeks.0).0 .0, 3);
+    }
+
+    #[test]
+    fn test_compose_drops_name() {
+        let _: NoRatio = <Week as Div<Week>>::Output::default();
+        let _: Day = <Week as Div<Seven>>::Output::default();
+
+        // 周 / 天 = 7，结果为普通比例
+        // Week / day = 7, giving a plain ratio
+        let r: Unit<Si<Var<i64>, _, NoPrefix>, Seven> = Weeks::new(4) / Days::new(2);
+        assert_eq!((r.0).0 .0, 2);
+    }
+}
@@ -0,0 +1,435 @@
+//! 对数量：分贝与奈培
+//! Logarithmic quantities: decibels and nepers
+//!
+//! 级 [`Level<R, K>`] 以分贝保存线性量与参考值 `R` 之比的对数。`K` 区分功率类量
+//! （[`PowerQuantity`]，10·lg）与根功率类量（[`RootPowerQuantity`]，20·lg），参考值
+//! 由 [`LevelReference`] 给出（1 mW 得 dBm，20 µPa 得 dB SPL，1 V 得 dBV）。
+//! 级加上增益（参考值为 [`Unity`] 的级）相当于线性值相乘，两级相减得增益；
+//! 两级相乘没有意义，因此无法编译。
+//! A [`Level<R, K>`] stores, in decibels, the logarithm of a linear quantity over the
+//! reference `R`. `K` tells power quantities ([`PowerQuantity`], 10·lg) from
+//! root-power quantities ([`RootPowerQuantity`], 20·lg), and [`LevelReference`]
+//! supplies the reference (1 mW gives dBm, 20 µPa dB SPL and 1 V dBV).
+//! Adding a gain (a level referenced to [`Unity`]) multiplies the linear values and
+//! the difference of two levels is a gain; multiplying two levels is meaningless and
+//! does not compile.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{Dbm, Gain, Milli, Si};
+//! use unitrix::quantity::si_units::Watt;
+//!
+//! let tx: Dbm = Watt::<f64, Milli>::new(100.0).into();
+//! assert!((tx.value - 20.0).abs() < 1e-12);
+//!
+//! let rx = tx + Gain::new(-3.0) + Gain::new(13.0);
+//! let w: Watt = rx.to_si();
+//! assert!((w.0 .0 - 1.0).abs() < 1e-12);
+//! assert_eq!(format!("{:.1}", rx), "30.0 dBm");
+//! ```
+//!
+//! 两级相乘无法编译：
+//! Multiplying two levels does not compile:
+//! ```compile_fail
+//! use unitrix::quantity::Dbm;
+//!
+//! let _ = Dbm::new(10.0) * Dbm::new(20.0);
+//! ```
+
+use core::f64::consts::{LN_10, LN_2, SQRT_2};
+use core::marker::PhantomData;
+use core::ops::{Add, Sub, Neg};
+
+use crate::sealed::Sealed;
+use crate::number::Var;
+use super::{Si, Dimensional, Prefixed};
+use super::ratio::powi_f64;
+use super::format::UnitNames;
+use super::si_units::{Dimensionless, PowerDimension, PressureDimension, VoltageDimension};
+
+// ========== 量的类别 ==========
+// ========== Quantity Kinds ==========
+
+/// 功率类或根功率类量
+/// Power or root-power quantity
+pub trait LevelKind: Sealed + Copy + Default + 'static {
+    /// 每十倍线性比的分贝数（功率类为10，根功率类为20）
+    /// Decibels per decade of the linear ratio (10 for power quantities, 20 for root-power quantities)
+    const DB_PER_DECADE: f64;
+}
+
+/// 功率类量（功率、能量、强度）：L = 10·lg(P/P₀) dB
+/// Power quantity (power, energy, intensity): L = 10·lg(P/P₀) dB
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Default)]
+pub struct PowerQuantity;
+
+/// 根功率类量（电压、声压、场强）：L = 20·lg(F/F₀) dB
+/// Root-power quantity (voltage, sound pressure, field strength): L = 20·lg(F/F₀) dB
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Default)]
+pub struct RootPowerQuantity;
+
+impl Sealed for PowerQuantity {}
+impl Sealed for RootPowerQuantity {}
+
+impl LevelKind for PowerQuantity {
+    const DB_PER_DECADE: f64 = 10.0;
+}
+
+impl LevelKind for RootPowerQuantity {
+    const DB_PER_DECADE: f64 = 20.0;
+}
+
+// ========== 参考值 ==========
+// ========== References ==========
+
+/// 级的参考值
+/// Reference value of a level
+///
+/// `VALUE` 是以一贯SI单位（不含词头）表示的参考值，`NAMES` 是级的单位写法。
+/// `VALUE` is the reference in coherent SI units (without prefix), and `NAMES` the
+/// spellings of the level's unit.
+pub trait LevelReference: Copy + Default + 'static {
+    /// 线性量的量纲 / Dimension of the linear quantity
+    type Dimension: Dimensional;
+    /// 参考值 / Reference value
+    const VALUE: f64;
+    /// 单位写法 / Unit spellings
+    const NAMES: UnitNames;
+}
+
+/// 无量纲参考值1：级即增益或衰减（dB）
+/// The dimensionless reference 1: the level is a gain or loss (dB)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Default)]
+pub struct Unity;
+
+/// 参考功率 1 mW（dBm）/ Reference power 1 mW (dBm)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Default)]
+pub struct OneMilliwatt;
+
+/// 参考声压 20 µPa（dB SPL）/ Reference sound pressure 20 µPa (dB SPL)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Default)]
+pub struct TwentyMicropascals;
+
+/// 参考电压 1 V（dBV）/ Reference voltage 1 V (dBV)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Default)]
+pub struct OneVolt;
+
+impl LevelReference for Unity {
+    type Dimension = Dimensionless;
+    const VALUE: f64 = 1.0;
+    const NAMES: UnitNames = UnitNames {
+        symbol: "dB", ascii: "dB", latex: "\\decibel",
+        singular: "decibel", plural: "decibels", chinese: "分贝",
+    };
+}
+
+impl LevelReference for OneMilliwatt {
+    type Dimension = PowerDimension;
+    const VALUE: f64 = 1e-3;
+    const NAMES: UnitNames = UnitNames {
+        symbol: "dBm", ascii: "dBm", latex: "dBm",
+        singular: "decibel-milliwatt", plural: "decibel-milliwatts", chinese: "分贝毫瓦",
+    };
+}
+
+impl LevelReference for TwentyMicropascals {
+    type Dimension = PressureDimension;
+    const VALUE: f64 = 20e-6;
+    const NAMES: UnitNames = UnitNames {
+        symbol: "dB SPL", ascii: "dB SPL", latex: "dB~SPL",
+        singular: "decibel SPL", plural: "decibels SPL", chinese: "分贝声压级",
+    };
+}
+
+impl LevelReference for OneVolt {
+    type Dimension = VoltageDimension;
+    const VALUE: f64 = 1.0;
+    const NAMES: UnitNames = UnitNames {
+        symbol: "dBV", ascii: "dBV", latex: "dBV",
+        singular: "decibel-volt", plural: "decibel-volts", chinese: "分贝伏",
+    };
+}
+
+// ========== 级 ==========
+// ========== Level ==========
+
+/// 以分贝表示、相对参考值 `R` 的 `K` 类量的级
+/// The level of a `K` quantity relative to the reference `R`, in decibels
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Level<R: LevelReference, K: LevelKind> {
+    /// 分贝值 / Value in decibels
+    pub value: f64,
+    _marker: PhantomData<(R, K)>,
+}
+
+/// 增益或衰减（dB）/ Gain or loss (dB)
+pub type Gain<K = PowerQuantity> = Level<Unity, K>;
+/// 功率电平 dBm / Power level dBm
+pub type Dbm = Level<OneMilliwatt, PowerQuantity>;
+/// 声压级 dB SPL / Sound pressure level dB SPL
+pub type DbSpl = Level<TwentyMicropascals, RootPowerQuantity>;
+/// 电压电平 dBV / Voltage level dBV
+pub type Dbv = Level<OneVolt, RootPowerQuantity>;
+
+/// 1 Np 对应的分贝数：20/ln 10
+/// Decibels in one neper: 20/ln 10
+const DB_PER_NEPER: f64 = 20.0 / LN_10;
+
+impl<R: LevelReference, K: LevelKind> Level<R, K> {
+    /// 以分贝值创建 / Creates the level from decibels
+    pub const fn new(db: f64) -> Self {
+        Level { value: db, _marker: PhantomData }
+    }
+
+    /// 以奈培值创建 / Creates the level from nepers
+    pub fn from_nepers(np: f64) -> Self {
+        Level::new(np * DB_PER_NEPER)
+    }
+
+    /// 奈培值 / Value in nepers
+    pub fn nepers(self) -> f64 {
+        self.value / DB_PER_NEPER
+    }
+
+    /// 由线性量与参考值之比创建；比为零时为 −∞ dB，为负时为 NaN
+    /// Creates the level from the linear ratio to the reference; a zero ratio gives
+    /// −∞ dB and a negative one NaN
+    pub fn from_ratio(ratio: f64) -> Self {
+        Level::new(K::DB_PER_DECADE * ln_f64(ratio) / LN_10)
+    }
+
+    /// 线性量与参考值之比 / Linear ratio to the reference
+    pub fn ratio(self) -> f64 {
+        exp_f64(self.value / K::DB_PER_DECADE * LN_10)
+    }
+
+    /// 由线性量创建 / Creates the level from the linear quantity
+    pub fn from_si<Pr: Prefixed>(q: Si<Var<f64>, R::Dimension, Pr>) -> Self {
+        Level::from_ratio(q.0 .0 * powi_f64(10.0, Pr::EXPONENT) / R::VALUE)
+    }
+
+    /// 换算为线性量 / Converts to the linear quantity
+    pub fn to_si<Pr: Prefixed>(self) -> Si<Var<f64>, R::Dimension, Pr> {
+        Si(Var(self.ratio() * R::VALUE / powi_f64(10.0, Pr::EXPONENT)), PhantomData)
+    }
+
+    /// 相对另一级的增益，即 `self - reference`
+    /// The gain over another level, i.e. `self - reference`
+    pub fn gain_over(self, reference: Self) -> Level<Unity, K> {
+        Level::new(self.value - reference.value)
+    }
+
+    /// 功率叠加（非相干源）：线性功率相加后再取级
+    /// Power sum (incoherent sources): the linear powers are added, then the level is taken
+    pub fn power_sum(self, rhs: Self) -> Self {
+        let power = |l: Self| exp_f64(l.value / 10.0 * LN_10);
+        Level::new(10.0 * ln_f64(power(self) + power(rhs)) / LN_10)
+    }
+}
+
+impl<R: LevelReference, K: LevelKind, Pr: Prefixed> From<Si<Var<f64>, R::Dimension, Pr>> for Level<R, K> {
+    fn from(q: Si<Var<f64>, R::Dimension, Pr>) -> Self {
+        Level::from_si(q)
+    }
+}
+
+// ========== 运算 ==========
+// ========== Arithmetic ==========
+
+/// 级 + 增益 = 级（线性值相乘）/ Level + gain = level (the linear values multiply)
+impl<R: LevelReference, K: LevelKind> Add<Level<Unity, K>> for Level<R, K> {
+    type Output = Self;
+
+    fn add(self, rhs: Level<Unity, K>) -> Self {
+        Level::new(self.value + rhs.value)
+    }
+}
+
+/// 级 - 增益 = 级（线性值相除）/ Level − gain = level (the linear values divide)
+impl<R: LevelReference, K: LevelKind> Sub<Level<Unity, K>> for Level<R, K> {
+    type Output = Self;
+
+    fn sub(self, rhs: Level<Unity, K>) -> Self {
+        Level::new(self.value - rhs.value)
+    }
+}
+
+// 级之差 = 增益。参考值为 `Unity` 时与上面的 `Sub<Level<Unity, K>>` 重合，因此只对
+// 内置的其他参考值实现运算符；自定义参考值使用 `gain_over`。
+// Difference of two levels = gain. For `Unity` this coincides with
+// `Sub<Level<Unity, K>>` above, so the operator is implemented for the other built-in
+// references only; custom references use `gain_over`.
+macro_rules! impl_level_difference {
+    ($($r:ty),*) => {
+        $(
+            impl<K: LevelKind> Sub for Level<$r, K> {
+                type Output = Level<Unity, K>;
+
+                fn sub(self, rhs: Self) -> Level<Unity, K> {
+                    self.gain_over(rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_level_difference!(OneMilliwatt, TwentyMicropascals, OneVolt);
+
+impl<K: LevelKind> Neg for Level<Unity, K> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Level::new(-self.value)
+    }
+}
+
+// ========== 数学函数 ==========
+// ========== Math Functions ==========
+
+// `core` 没有 ln/exp，以下实现误差在几个 ulp 以内
+// `core` has no ln/exp; the implementations below are accurate to a few ulp
+
+/// 2 的整数次幂（`k` 在正规数范围内）/ 2 to an integer power (`k` in the normal range)
+fn pow2(k: i32) -> f64 {
+    f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+/// 自然对数 / Natural logarithm
+fn ln_f64(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return x;
+    }
+    // x = m·2^e，m 在 [√½, √2) 内，ln m = 2·artanh s，s = (m-1)/(m+1)
+    // x = m·2^e with m in [√½, √2); ln m = 2·artanh s with s = (m-1)/(m+1)
+    let (x, mut e) = if x < f64::MIN_POSITIVE { (x * pow2(54), -54) } else { (x, 0) };
+    let bits = x.to_bits();
+    e += ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let mut m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+    if m > SQRT_2 {
+        m /= 2.0;
+        e += 1;
+    }
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+    let mut series = 0.0;
+    for n in (0..12).rev() {
+        series = series * s2 + 1.0 / (2 * n + 1) as f64;
+    }
+    2.0 * s * series + e as f64 * LN_2
+}
+
+/// 自然指数 / Natural exponential
+fn exp_f64(x: f64) -> f64 {
+    // ln 2 拆成高低两部分以减小约化误差
+    // ln 2 split into high and low parts to reduce the reduction error
+    const LN_2_HI: f64 = 6.931_471_803_691_238e-1;
+    const LN_2_LO: f64 = 1.908_214_929_270_587_7e-10;
+    if x.is_nan() {
+        return x;
+    }
+    if x > 709.782_712_893_384 {
+        return f64::INFINITY;
+    }
+    if x < -745.133_219_101_941_1 {
+        return 0.0;
+    }
+    // x = k·ln 2 + r，|r| ≤ ½ln 2
+    // x = k·ln 2 + r with |r| ≤ ½ln 2
+    let k = (x / LN_2 + if x < 0.0 { -0.5 } else { 0.5 }) as i32;
+    let r = (x - k as f64 * LN_2_HI) - k as f64 * LN_2_LO;
+    let mut series = 1.0;
+    for n in (1..=13).rev() {
+        series = 1.0 + series * r / n as f64;
+    }
+    // 分两步乘 2^k，避免次正规数与上溢
+    // Multiply by 2^k in two steps to cover subnormals and avoid overflow
+    if k > 1023 {
+        series * pow2(1023) * pow2(k - 1023)
+    } else if k < -1022 {
+        series * pow2(-1022) * pow2(k + 1022)
+    } else {
+        series * pow2(k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+    use crate::quantity::{Milli, Micro, Style};
+    use crate::quantity::si_units::{Pascal, Volt, Watt};
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-12 * b.abs().max(1.0)
+    }
+
+    #[test]
+    fn test_math() {
+        assert_eq!(ln_f64(1.0), 0.0);
+        assert_eq!(exp_f64(0.0), 1.0);
+        assert!(close(ln_f64(10.0), LN_10));
+        assert!(close(ln_f64(0.5), -LN_2));
+        assert!(close(exp_f64(1.0), core::f64::consts::E));
+        assert!(close(exp_f64(-20.0), 2.061_153_622_438_558e-9));
+        assert!(close(ln_f64(1e-310), -713.801_378_828_154_2));
+        assert!(close(exp_f64(ln_f64(123.456)), 123.456));
+        assert!(ln_f64(-1.0).is_nan());
+        assert_eq!(ln_f64(0.0), f64::NEG_INFINITY);
+        assert_eq!(exp_f64(1000.0), f64::INFINITY);
+        assert_eq!(exp_f64(-1000.0), 0.0);
+    }
+
+    #[test]
+    fn test_references() {
+        let p: Dbm = Watt::<f64, Milli>::new(1.0).into();
+        assert!(close(p.value, 0.0));
+        assert!(close(Dbm::from_si(<Watt>::new(1.0)).value, 30.0));
+
+        let spl: DbSpl = Pascal::<f64, Micro>::new(20.0).into();
+        assert!(close(spl.value, 0.0));
+        let spl: DbSpl = <Pascal>::new(1.0).into();
+        assert!((spl.value - 93.979_400_086_720_38).abs() < 1e-9);
+
+        let v: Dbv = Volt::<f64, Milli>::new(100.0).into();
+        assert!(close(v.value, -20.0));
+        let back: Volt<f64, Milli> = v.to_si();
+        assert!(close(back.0 .0, 100.0));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let out = Dbm::new(10.0) + Gain::new(20.0);
+        assert!(close(out.to_si::<Milli>().0 .0, 1000.0));
+        let loss: Gain = Dbm::new(10.0) - Dbm::new(13.0);
+        assert_eq!(loss.value, -3.0);
+        assert_eq!((-loss).value, 3.0);
+        assert_eq!(DbSpl::new(94.0).gain_over(DbSpl::new(90.0)).value, 4.0);
+        assert_eq!((Dbm::new(10.0) - Gain::new(10.0)).value, 0.0);
+
+        // 根功率类：+6 dB 约为电压加倍
+        // Root-power: +6 dB is roughly twice the voltage
+        let doubled = Dbv::new(0.0) + Gain::<RootPowerQuantity>::from_ratio(2.0);
+        assert!(close(doubled.to_si::<crate::quantity::NoPrefix>().0 .0, 2.0));
+        assert!((Dbm::new(10.0).power_sum(Dbm::new(10.0)).value - 13.010_299_956_639_813).abs() < 1e-9);
+
+        assert!(close(Gain::<RootPowerQuantity>::from_nepers(1.0).ratio(), core::f64::consts::E));
+        assert!(close(Gain::<PowerQuantity>::new(20.0).nepers(), 20.0 / DB_PER_NEPER));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Dbm::new(30.0)), "30 dBm");
+        assert_eq!(format!("{:.1}", DbSpl::new(94.0)), "94.0 dB SPL");
+        assert_eq!(format!("{}", Gain::<PowerQuantity>::new(-3.0)), "-3 dB");
+        assert_eq!(format!("{}", Dbv::new(-6.0).format().style(Style::Long)), "-6 decibel-volts");
+        assert_eq!(format!("{}", Gain::<PowerQuantity>::new(3.0).format().style(Style::Latex)), "\\SI{3}{\\decibel}");
+    }
+}
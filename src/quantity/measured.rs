@@ -0,0 +1,303 @@
+// measured.rs
+//! 实测（非精确）比例因子
+//! Measured (inexact) scale factors
+//!
+//! 电子伏特、道尔顿、天文单位（历史定义）等单位的换算系数来自测量，无法用 [`Ratio`] 精确表示。
+//! [`MeasuredScale`] 以 `f64` 常量给出该系数，再用 [`Approx`] 包装后作为 `Unit<S, R>` 的 `R`。
+//! `Approx` 与精确的 `Ratio` 相乘除后仍是 `Approx`，永远不会化简为精确比例，
+//! 因此其换算方法都带有 `approx` 字样。
+//!
+//! Units such as the electronvolt, the dalton or the (historical) astronomical unit
+//! have measured conversion factors that [`Ratio`] cannot express exactly.
+//! [`MeasuredScale`] provides the factor as an `f64` constant, and [`Approx`] wraps
+//! it for use as the `R` of `Unit<S, R>`. Multiplying or dividing an `Approx` by an
+//! exact `Ratio` yields another `Approx`; it never collapses back into an exact
+//! ratio, so every conversion through it is named `approx`.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{MeasuredScale, Approx, Unit, Si, Dimension, NoPrefix};
+//! use unitrix::number::{Var, Const};
+//!
+//! #[derive(Debug, Clone, Copy, Default)]
+//! struct ElectronVolt;
+//! impl MeasuredScale for ElectronVolt {
+//!     const FACTOR: f64 = 1.602_176_634e-19;
+//!     const NAME: &'static str = "eV";
+//! }
+//!
+//! type Energy = Dimension<Const<2>, Const<1>, Const<-2>, Const<0>, Const<0>, Const<0>, Const<0>>;
+//! let e: Unit<Si<Var<f64>, Energy, NoPrefix>, Approx<ElectronVolt>> = Unit::new(2.0);
+//! let joules = e.to_si_approx();
+//! assert!((joules.0 .0 - 3.204_353_268e-19).abs() < 1e-28);
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{Mul, Div};
+
+use crate::sealed::Sealed;
+use crate::number::{TypedInt, Var};
+use super::ratio::{Ratio, NoRatio, PrimeList, Scaled};
+use super::{Si, Unit, Dimensional, Prefixed};
+
+/// 实测比例因子
+/// Measured scale factor
+///
+/// `FACTOR` 是一个该单位折合多少个SI单位（不含词头），`NAME` 是单位名称。
+/// `FACTOR` is the number of SI units (without prefix) in one such unit, and `NAME` is its name.
+pub trait MeasuredScale: Copy + Default + 'static {
+    /// 换算系数 / Conversion factor
+    const FACTOR: f64;
+    /// 名称 / Name
+    const NAME: &'static str;
+}
+
+/// 两个实测比例因子的乘积
+/// Product of two measured scale factors
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeasuredMul<A, B>(PhantomData<(A, B)>);
+
+/// 实测比例因子的倒数
+/// Reciprocal of a measured scale factor
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MeasuredInv<A>(PhantomData<A>);
+
+impl<A: MeasuredScale, B: MeasuredScale> MeasuredScale for MeasuredMul<A, B> {
+    const FACTOR: f64 = A::FACTOR * B::FACTOR;
+    const NAME: &'static str = "";
+}
+
+impl<A: MeasuredScale> MeasuredScale for MeasuredInv<A> {
+    const FACTOR: f64 = 1.0 / A::FACTOR;
+    const NAME: &'static str = "";
+}
+
+/// 近似比例：实测因子 `M` 与精确比例 `R` 的乘积
+/// Approximate ratio: the product of the measured factor `M` and the exact ratio `R`
+///
+/// 作为 `Unit<S, R>` 的 `R` 使用，与 `Ratio` 的乘除结果仍为 `Approx`。
+/// Used as the `R` of `Unit<S, R>`; multiplying or dividing by a `Ratio` stays an `Approx`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Approx<M, R = NoRatio>(PhantomData<(M, R)>);
+
+impl<M, R> Approx<M, R> {
+    pub fn new() -> Self {
+        Approx(PhantomData)
+    }
+}
+
+impl<M: MeasuredScale, R: ScaleFactor> Sealed for Approx<M, R> {}
+impl<M: MeasuredScale, R: ScaleFactor> Scaled for Approx<M, R> {}
+
+// ========== 运行时换算系数 ==========
+// ========== Runtime Conversion Factor ==========
+
+/// 比例的运行时换算系数
+/// Runtime conversion factor of a ratio
+///
+/// 对 `Ratio` 而言是精确值的 `f64` 近似；对 `Approx` 而言本身就是近似值。
+/// For a `Ratio` this is the `f64` rounding of an exact value; for an `Approx` it is approximate by nature.
+pub trait ScaleFactor {
+    /// 换算系数 / Conversion factor
+    fn factor() -> f64;
+}
+
+impl<E5, EPi, E3, E2, Primes> ScaleFactor for Ratio<E5, EPi, E3, E2, Primes>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+{
+    fn factor() -> f64 {
+        Self::FACTOR_F64
+    }
+}
+
+impl<M: MeasuredScale, R: ScaleFactor> ScaleFactor for Approx<M, R> {
+    fn factor() -> f64 {
+        M::FACTOR * R::factor()
+    }
+}
+
+// ========== 与精确比例的组合 ==========
+// ========== Composition with Exact Ratios ==========
+
+// Approx * Ratio
+impl<M, R, E5, EPi, E3, E2, Primes> Mul<Ratio<E5, EPi, E3, E2, Primes>> for Approx<M, R>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+    R: Mul<Ratio<E5, EPi, E3, E2, Primes>>,
+{
+    type Output = Approx<M, <R as Mul<Ratio<E5, EPi, E3, E2, Primes>>>::Output>;
+
+    fn mul(self, _: Ratio<E5, EPi, E3, E2, Primes>) -> Self::Output {
+        Approx::new()
+    }
+}
+
+// Approx / Ratio
+impl<M, R, E5, EPi, E3, E2, Primes> Div<Ratio<E5, EPi, E3, E2, Primes>> for Approx<M, R>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+    R: Div<Ratio<E5, EPi, E3, E2, Primes>>,
+{
+    type Output = Approx<M, <R as Div<Ratio<E5, EPi, E3, E2, Primes>>>::Output>;
+
+    fn div(self, _: Ratio<E5, EPi, E3, E2, Primes>) -> Self::Output {
+        Approx::new()
+    }
+}
+
+// Ratio * Approx
+impl<M, R, E5, EPi, E3, E2, Primes> Mul<Approx<M, R>> for Ratio<E5, EPi, E3, E2, Primes>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+    Ratio<E5, EPi, E3, E2, Primes>: Mul<R>,
+{
+    type Output = Approx<M, <Ratio<E5, EPi, E3, E2, Primes> as Mul<R>>::Output>;
+
+    fn mul(self, _: Approx<M, R>) -> Self::Output {
+        Approx::new()
+    }
+}
+
+// Ratio / Approx
+impl<M, R, E5, EPi, E3, E2, Primes> Div<Approx<M, R>> for Ratio<E5, EPi, E3, E2, Primes>
+where
+    E5: TypedInt,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt,
+    Primes: PrimeList,
+    Ratio<E5, EPi, E3, E2, Primes>: Div<R>,
+{
+    type Output = Approx<MeasuredInv<M>, <Ratio<E5, EPi, E3, E2, Primes> as Div<R>>::Output>;
+
+    fn div(self, _: Approx<M, R>) -> Self::Output {
+        Approx::new()
+    }
+}
+
+// Approx * Approx
+impl<M1, R1, M2, R2> Mul<Approx<M2, R2>> for Approx<M1, R1>
+where
+    R1: Mul<R2>,
+{
+    type Output = Approx<MeasuredMul<M1, M2>, <R1 as Mul<R2>>::Output>;
+
+    fn mul(self, _: Approx<M2, R2>) -> Self::Output {
+        Approx::new()
+    }
+}
+
+// Approx / Approx（即使 M1 与 M2 相同也不约去，结果仍为近似）
+// Approx / Approx (not cancelled even when M1 equals M2; the result stays approximate)
+impl<M1, R1, M2, R2> Div<Approx<M2, R2>> for Approx<M1, R1>
+where
+    R1: Div<R2>,
+{
+    type Output = Approx<MeasuredMul<M1, MeasuredInv<M2>>, <R1 as Div<R2>>::Output>;
+
+    fn div(self, _: Approx<M2, R2>) -> Self::Output {
+        Approx::new()
+    }
+}
+
+// ========== 近似换算 ==========
+// ========== Approximate Conversions ==========
+
+macro_rules! impl_approx_conversions {
+    ($($t:ty),*) => {
+        $(
+            impl<D, Pr, M, R> Unit<Si<Var<$t>, D, Pr>, Approx<M, R>>
+            where
+                D: Dimensional,
+                Pr: Prefixed,
+                M: MeasuredScale,
+                R: ScaleFactor,
+            {
+                /// 近似换算为同词头的SI量
+                /// Approximately converts to the SI quantity with the same prefix
+                pub fn to_si_approx(self) -> Si<Var<$t>, D, Pr> {
+                    Si::new(((self.0).0 .0 as f64 * <Approx<M, R>>::factor()) as $t)
+                }
+
+                /// 由同词头的SI量近似换算
+                /// Approximately converts from the SI quantity with the same prefix
+                pub fn from_si_approx(si: Si<Var<$t>, D, Pr>) -> Self {
+                    Self::new((si.0 .0 as f64 / <Approx<M, R>>::factor()) as $t)
+                }
+            }
+        )*
+    };
+}
+
+impl_approx_conversions!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{Z0, P1, Const};
+    use crate::quantity::{Dimension, NoPrefix};
+
+    #[derive(Debug, Clone, Copy, Default)]
+    struct Dalton;
+    impl MeasuredScale for Dalton {
+        const FACTOR: f64 = 1.660_539_066_60e-27;
+        const NAME: &'static str = "Da";
+    }
+
+    type Mass = Dimension<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+    type One = Dimension<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+    type Kilo = Ratio<Const<3>, Z0, Z0, Const<3>>;
+    type AtomicMass = Unit<Si<Var<f64>, Mass, NoPrefix>, Approx<Dalton>>;
+
+    fn close(a: f64, b: f64) -> bool {
+        ((a - b) / b).abs() < 1e-12
+    }
+
+    #[test]
+    fn test_compose_with_ratio() {
+        // 千道尔顿 = Da * 10^3，仍是近似比例
+        // Kilodalton = Da * 10^3, still approximate
+        let _: Approx<Dalton, Kilo> = <Approx<Dalton> as Mul<Kilo>>::Output::default();
+        let _: Approx<Dalton, Kilo> = <Kilo as Mul<Approx<Dalton>>>::Output::default();
+        assert!(close(<Approx<Dalton, Kilo>>::factor(), 1.660_539_066_60e-24));
+    }
+
+    #[test]
+    fn test_ratio_factor() {
+        assert_eq!(Kilo::factor(), 1000.0);
+        assert_eq!(<Ratio<P1, Z0, P1, Const<2>>>::factor(), 60.0);
+        assert_eq!(NoRatio::factor(), 1.0);
+    }
+
+    #[test]
+    fn test_unit_conversion() {
+        let m: AtomicMass = Unit::new(12.0);
+        let kg = m.to_si_approx();
+        assert!(close(kg.0 .0, 12.0 * 1.660_539_066_60e-27));
+
+        let back = AtomicMass::from_si_approx(kg);
+        assert!(close((back.0).0 .0, 12.0));
+
+        // 与精确单位相乘后比例仍为 Approx
+        // Multiplying with an exact unit keeps the ratio approximate
+        let k: Unit<Si<Var<f64>, One, NoPrefix>, Kilo> = Unit::new(1.0);
+        let _: Unit<Si<Var<f64>, Mass, NoPrefix>, Approx<Dalton, Kilo>> = m * k;
+    }
+}
@@ -23,8 +23,45 @@ pub use si::*;
 mod ratio;
 pub use ratio::*;
 
+mod factorize;
+pub use factorize::*;
+
+mod measured;
+pub use measured::*;
+
+mod labeled;
+pub use labeled::*;
+
+mod convert;
+pub use convert::*;
+
 mod unit;
 pub use unit::*;
 
-//mod alias;
-//pub use alias::*;
\ No newline at end of file
+mod alias;
+pub use alias::*;
+
+mod format;
+pub use format::*;
+
+mod dynamic;
+pub use dynamic::*;
+
+mod dyn_prefix;
+pub use dyn_prefix::*;
+
+mod temperature;
+pub use temperature::*;
+
+mod level;
+pub use level::*;
+
+mod angular;
+pub use angular::*;
+
+mod kind;
+pub use kind::*;
+
+mod extended;
+pub use extended::*;
+pub mod si_units;
@@ -10,7 +10,9 @@
 use core::marker::PhantomData;
 use core::ops::{Add, Sub, Mul, Div};
 
-use crate::number::{Const, TypedInt};
+use crate::number::{Const, TypedInt, Min, Cmp, Less, Equal, Greater, Scale, DefaultRounding};
+use super::ratio::{powi_f64, exact_pow};
+use super::Factor;
 
 /// Prefix struct representing a power of 10
 /// 词头结构体，表示10的幂次
@@ -23,12 +25,25 @@ impl<Exp: TypedInt> Prefix<Exp> {
     pub fn new() -> Self {
         Prefix(PhantomData)
     }
+
+    /// The prefix factor 10^Exp as f64 (compile-time constant)
+    /// 词头系数 10^Exp 的 f64 值（编译期常量）
+    pub const FACTOR_F64: f64 = powi_f64(10.0, Exp::I32);
+
+    /// The prefix factor as an exact fraction `(num, den)`; `None` beyond `i128` (compile-time constant)
+    /// 词头系数的精确分数 `(分子, 分母)`，超出 `i128` 时为 `None`（编译期常量）
+    pub const EXACT: Option<(i128, i128)> = exact_pow(10, Exp::I32);
 }
 
 /// Prefixed trait defines operations related to SI prefixes
 /// SI词头特质定义了与SI词头相关的操作
-pub trait Prefixed {}
-impl<I: TypedInt> Prefixed for Prefix<I>{}
+pub trait Prefixed {
+    /// 10的幂次 / The power of ten
+    const EXPONENT: i32;
+}
+impl<I: TypedInt> Prefixed for Prefix<I>{
+    const EXPONENT: i32 = I::I32;
+}
 
 // ========== 基本操作实现 ==========
 // ========== Basic Operations Implementation ==========
@@ -57,6 +72,93 @@ where
     }
 }
 
+/// 取较小的词头 (min(10^a, 10^b) = 10^min(a, b))
+/// Selects the smaller prefix (min(10^a, 10^b) = 10^min(a, b))
+impl<Ea, Eb> Min<Prefix<Eb>> for Prefix<Ea>
+where
+    Ea: TypedInt + Min<Eb, Output: TypedInt>,
+    Eb: TypedInt,
+{
+    type Output = Prefix<<Ea as Min<Eb>>::Output>;
+
+    fn min(self, _: Prefix<Eb>) -> Self::Output {
+        Prefix::new()
+    }
+}
+
+/// 相加减时对齐两个词头：词头相同时直接运算（无换算、无额外约束），
+/// 不同时较粗的一方换算到较小词头（整数存储时溢出：`align` panic，`checked_align` 返回 `None`）
+/// Aligns two prefixes for addition and subtraction: equal prefixes operate directly
+/// (no conversion and no extra bounds); otherwise the coarser side is rescaled to the
+/// smaller prefix (on overflow with integer storage `align` panics and `checked_align`
+/// returns `None`)
+pub trait AlignPrefix<Rhs, T> {
+    /// 结果词头 / Resulting prefix
+    type Output: Prefixed;
+
+    /// 换算到结果词头的两个数值，整数存储时溢出返回 `None`
+    /// Both values in the resulting prefix; `None` on overflow with integer storage
+    fn checked_align(lhs: T, rhs: T) -> Option<(T, T)>;
+
+    /// 换算到结果词头的两个数值（整数存储时溢出则 panic）
+    /// Both values in the resulting prefix (panics on overflow with integer storage)
+    #[inline(always)]
+    fn align(lhs: T, rhs: T) -> (T, T) {
+        Self::checked_align(lhs, rhs).expect("prefix conversion overflows the storage type")
+    }
+}
+
+impl<Ea, Eb, T> AlignPrefix<Prefix<Eb>, T> for Prefix<Ea>
+where
+    Ea: TypedInt + Cmp<Eb, Output: AlignBy<Ea, Eb, T>>,
+    Eb: TypedInt,
+{
+    type Output = <<Ea as Cmp<Eb>>::Output as AlignBy<Ea, Eb, T>>::Output;
+
+    #[inline(always)]
+    fn checked_align(lhs: T, rhs: T) -> Option<(T, T)> {
+        <<Ea as Cmp<Eb>>::Output as AlignBy<Ea, Eb, T>>::checked_align(lhs, rhs)
+    }
+}
+
+/// 按词头指数的比较结果选择对齐方式
+/// Selects the alignment by the comparison of the prefix exponents
+pub trait AlignBy<Ea, Eb, T> {
+    /// 结果词头 / Resulting prefix
+    type Output: Prefixed;
+
+    /// 换算到结果词头的两个数值，整数存储时溢出返回 `None`
+    /// Both values in the resulting prefix; `None` on overflow with integer storage
+    fn checked_align(lhs: T, rhs: T) -> Option<(T, T)>;
+}
+
+impl<E: TypedInt, T> AlignBy<E, E, T> for Equal {
+    type Output = Prefix<E>;
+
+    #[inline(always)]
+    fn checked_align(lhs: T, rhs: T) -> Option<(T, T)> {
+        Some((lhs, rhs))
+    }
+}
+
+impl<Ea: TypedInt, Eb: TypedInt, T: Scale> AlignBy<Ea, Eb, T> for Less {
+    type Output = Prefix<Ea>;
+
+    fn checked_align(lhs: T, rhs: T) -> Option<(T, T)> {
+        let rhs = Factor::power_of_ten(Eb::I32 - Ea::I32).checked_apply::<T, DefaultRounding>(rhs)?;
+        Some((lhs, rhs))
+    }
+}
+
+impl<Ea: TypedInt, Eb: TypedInt, T: Scale> AlignBy<Ea, Eb, T> for Greater {
+    type Output = Prefix<Eb>;
+
+    fn checked_align(lhs: T, rhs: T) -> Option<(T, T)> {
+        let lhs = Factor::power_of_ten(Ea::I32 - Eb::I32).checked_apply::<T, DefaultRounding>(lhs)?;
+        Some((lhs, rhs))
+    }
+}
+
 // ========== 实用类型别名 ==========
 // ========== Useful Type Aliases ==========
 
@@ -1,87 +1,466 @@
 // ratio.rs
 //! 比例因子模块，处理所有非10的幂次的比例关系，包括时间单位、角度单位等
+//!
+//! 2、3、5 与 π 的幂次直接作为类型参数；其它质因数（如英寸中的 127）放在按质数升序排列的
+//! 质因数表 `Primes` 中，因此任意精确有理数都可以表示。
+//! Powers of 2, 3, 5 and π are direct type parameters; any other prime factor (such
+//! as the 127 in the inch) goes into the ascending prime list `Primes`, so every
+//! exact rational scale can be represented.
 use crate::sealed::Sealed;
-use crate::number::{TypedInt, NonZero, Z0};
+use crate::number::{TypedInt, NonZero, Z0, P1, B0, B1, Cmp, Less, Equal, Greater, ExactValue, Min};
 
 use core::marker::PhantomData;
-use core::ops::{Add, Sub, Mul, Div};
-// use core::f64::consts::PI;
+use core::ops::{Neg, Add, Sub, Mul, Div};
+use core::f64::consts::PI;
 
 /// 比例因子结构体，使用质因数分解表示
 /// Exp5: 5的幂次
 /// Exp3: 3的幂次
 /// ExpPi: PI的幂次
 /// Exp2: 2的幂次
+/// Primes: 其它质因数的幂次表（默认为空）
 #[derive(Debug, Clone, Copy, Default)]
-pub struct Ratio<Exp5: TypedInt, ExpPi:TypedInt, Exp3: TypedInt, Exp2: TypedInt>(
-    PhantomData<(Exp5, ExpPi, Exp3, Exp2)>
+pub struct Ratio<Exp5: TypedInt, ExpPi:TypedInt, Exp3: TypedInt, Exp2: TypedInt, Primes: PrimeList = NoPrimes>(
+    PhantomData<(Exp5, ExpPi, Exp3, Exp2, Primes)>
 );
 
-/* impl<E5: TypedInt, Pi: TypedInt, E3: TypedInt, E2: TypedInt> Ratio<E5, Pi, E3, E2>{
-    /// 计算比例因子的实际值
-    pub fn value() -> f64 {
-        5f64.powi(E5::to_i32()) * PI.powi(Pi::to_i32()) * 3f64.powi(E3::to_i32()) * 2f64.powi(E2::to_i32())
+// ========== 其它质因数表 ==========
+// ========== List of Other Prime Factors ==========
+
+/// 空质因数表
+/// Empty prime list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoPrimes;
+
+/// 质因数表节点：质数 `P` 的 `E` 次幂，后接 `Next`
+/// Prime list node: the prime `P` raised to `E`, followed by `Next`
+///
+/// 规范形式要求：`P` 为大于5的质数，按质数严格升序排列，指数非零。
+/// 只用单节点表并通过 `Ratio` 的乘除组合，即可始终得到规范形式。`P > 5` 与指数非零
+/// 由特质约束保证，质数性与升序在求 [`PrimeList::EXACT`] 或 [`PrimeList::FACTOR_F64`]
+/// 时于编译期检查，因此非规范的表（如 `PrimePow<Const<9>, _>`）无法用于换算与显示。
+/// Canonical form: `P` is a prime above 5, primes are strictly ascending and
+/// exponents are non-zero. Building single-node lists and combining them through
+/// `Ratio` multiplication and division always yields the canonical form. `P > 5` and
+/// the non-zero exponent are trait bounds; primality and ascending order are checked
+/// at compile time when [`PrimeList::EXACT`] or [`PrimeList::FACTOR_F64`] is
+/// evaluated, so a non-canonical list (such as `PrimePow<Const<9>, _>`) cannot be
+/// used in conversions or display.
+///
+/// ```compile_fail
+/// use unitrix::number::Const;
+/// use unitrix::quantity::{PrimeList, PrimePow};
+///
+/// // 9 不是质数 / 9 is not a prime
+/// let _ = <PrimePow<Const<9>, Const<1>>>::EXACT;
+/// ```
+///
+/// # 示例 / Example
+/// ```rust
+/// use unitrix::number::Const;
+/// use unitrix::quantity::{Ratio, PrimePow};
+///
+/// // 一周 = 604800 s = 2^7 * 3^3 * 5^2 * 7
+/// // One week = 604800 s = 2^7 * 3^3 * 5^2 * 7
+/// type Week = Ratio<Const<2>, Const<0>, Const<3>, Const<7>, PrimePow<Const<7>, Const<1>>>;
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PrimePow<P, E, Next = NoPrimes>(PhantomData<(P, E, Next)>);
+
+/// 质因数表特质
+/// Trait for prime lists
+pub trait PrimeList: Sealed + Copy + Default + 'static {
+    /// 各质因数幂之积的 f64 值（编译期常量）
+    /// The product of all prime powers as f64 (compile-time constant)
+    const FACTOR_F64: f64;
+    /// 各质因数幂之积的精确分数，超出 `i128` 时为 `None`（编译期常量）
+    /// The product of all prime powers as an exact fraction; `None` beyond `i128` (compile-time constant)
+    const EXACT: Option<(i128, i128)>;
+    /// 首个质数，空表为 `i32::MAX`；求值时检查规范形式
+    /// The first prime, `i32::MAX` for the empty list; evaluating it checks the canonical form
+    const FIRST: i32;
+}
+
+impl Sealed for NoPrimes {}
+impl PrimeList for NoPrimes {
+    const FACTOR_F64: f64 = 1.0;
+    const EXACT: Option<(i128, i128)> = Some((1, 1));
+    const FIRST: i32 = i32::MAX;
+}
+
+impl<P, E, Next> Sealed for PrimePow<P, E, Next> {}
+impl<P, E, Next> PrimeList for PrimePow<P, E, Next>
+where
+    P: TypedInt + Default + Cmp<B1<B0<P1>>, Output = Greater>,
+    E: NonZero,
+    Next: PrimeList,
+{
+    const FACTOR_F64: f64 = powi_f64(Self::FIRST as f64, E::I32) * Next::FACTOR_F64;
+    const EXACT: Option<(i128, i128)> = exact_mul(exact_pow(Self::FIRST as i128, E::I32), Next::EXACT);
+    const FIRST: i32 = canonical_prime(P::I32, Next::FIRST);
+}
+
+/// 检查质因数表节点的规范形式：`p` 为质数且小于后继节点的质数
+/// Checks the canonical form of a prime list node: `p` is a prime below the next node's prime
+const fn canonical_prime(p: i32, next: i32) -> i32 {
+    let mut d = 2;
+    while d * d <= p {
+        assert!(p % d != 0, "PrimePow: P must be a prime");
+        d += 1;
     }
+    assert!(p < next, "PrimePow: primes must be strictly ascending");
+    p
+}
+
+/// 质因数表的乘法（同一质数的指数相加，指数为零的节点被移除）
+/// Prime list multiplication (exponents of the same prime add up; zero exponents are removed)
+pub trait PrimeMul<Rhs> {
+    type Output;
+}
+
+/// 质因数表取倒数（所有指数取负）
+/// Prime list reciprocal (all exponents negated)
+pub trait PrimeInv {
+    type Output;
+}
+
+/// 质因数表的除法
+/// Prime list division
+pub type PrimeDiv<A, B> = <A as PrimeMul<<B as PrimeInv>::Output>>::Output;
+
+impl PrimeInv for NoPrimes {
+    type Output = NoPrimes;
+}
+
+impl<P, E: Neg, Next: PrimeInv> PrimeInv for PrimePow<P, E, Next> {
+    type Output = PrimePow<P, E::Output, Next::Output>;
+}
+
+// 空表 * L = L
+// Empty * L = L
+impl<L> PrimeMul<L> for NoPrimes {
+    type Output = L;
+}
+
+// L * 空表 = L
+// L * Empty = L
+impl<P, E, Next> PrimeMul<NoPrimes> for PrimePow<P, E, Next> {
+    type Output = Self;
+}
+
+// 两个非空表按首个质数的大小归并
+// Two non-empty lists are merged by comparing their first primes
+impl<Pa, Ea, Na, Pb, Eb, Nb> PrimeMul<PrimePow<Pb, Eb, Nb>> for PrimePow<Pa, Ea, Na>
+where
+    Pa: Cmp<Pb>,
+    PrimePow<Pa, Ea, Na>: PrimeMerge<<Pa as Cmp<Pb>>::Output, PrimePow<Pb, Eb, Nb>>,
+{
+    type Output = <PrimePow<Pa, Ea, Na> as PrimeMerge<<Pa as Cmp<Pb>>::Output, PrimePow<Pb, Eb, Nb>>>::Output;
+}
+
+/// 按首个质数的比较结果 `Ord` 归并两个质因数表
+/// Merges two prime lists according to the comparison `Ord` of their first primes
+pub trait PrimeMerge<Ord, Rhs> {
+    type Output;
+}
+
+// Pa < Pb：保留左侧首节点
+// Pa < Pb: keep the left head
+impl<Pa, Ea, Na, Rhs> PrimeMerge<Less, Rhs> for PrimePow<Pa, Ea, Na>
+where
+    Na: PrimeMul<Rhs>,
+{
+    type Output = PrimePow<Pa, Ea, <Na as PrimeMul<Rhs>>::Output>;
+}
+
+// Pa > Pb：保留右侧首节点
+// Pa > Pb: keep the right head
+impl<Pa, Ea, Na, Pb, Eb, Nb> PrimeMerge<Greater, PrimePow<Pb, Eb, Nb>> for PrimePow<Pa, Ea, Na>
+where
+    PrimePow<Pa, Ea, Na>: PrimeMul<Nb>,
+{
+    type Output = PrimePow<Pb, Eb, <PrimePow<Pa, Ea, Na> as PrimeMul<Nb>>::Output>;
+}
+
+// Pa == Pb：指数相加
+// Pa == Pb: add the exponents
+impl<Pa, Ea, Na, Pb, Eb, Nb> PrimeMerge<Equal, PrimePow<Pb, Eb, Nb>> for PrimePow<Pa, Ea, Na>
+where
+    Ea: Add<Eb>,
+    Na: PrimeMul<Nb>,
+    <Ea as Add<Eb>>::Output: PrimeNode<Pa, <Na as PrimeMul<Nb>>::Output>,
+{
+    type Output = <<Ea as Add<Eb>>::Output as PrimeNode<Pa, <Na as PrimeMul<Nb>>::Output>>::Output;
+}
+
+/// 以指数 `Self` 构造节点：指数为零时省略该节点
+/// Builds a node with exponent `Self`; the node is dropped when the exponent is zero
+pub trait PrimeNode<P, Next> {
+    type Output;
+}
+
+impl<P, Next> PrimeNode<P, Next> for Z0 {
+    type Output = Next;
+}
+
+impl<P, Next, E: NonZero> PrimeNode<P, Next> for E {
+    type Output = PrimePow<P, E, Next>;
+}
+
+/// 质因数表的公约部分（同一质数的指数取较小值，缺失的质数按指数0计）
+/// Common part of two prime lists (the smaller exponent of each prime, a missing prime counting as exponent 0)
+pub trait PrimeGcd<Rhs> {
+    type Output;
+}
+
+/// 按首个质数的比较结果 `Ord` 求两个质因数表的公约部分
+/// Common part of two prime lists according to the comparison `Ord` of their first primes
+pub trait PrimeGcdMerge<Ord, Rhs> {
+    type Output;
+}
+
+// 空表与空表
+// Empty and empty
+impl PrimeGcd<NoPrimes> for NoPrimes {
+    type Output = NoPrimes;
+}
+
+// 空表与非空表：只保留负指数
+// Empty and non-empty: only negative exponents remain
+impl<P, E, Next> PrimeGcd<PrimePow<P, E, Next>> for NoPrimes
+where
+    E: Min<Z0>,
+    NoPrimes: PrimeGcd<Next>,
+    <E as Min<Z0>>::Output: PrimeNode<P, <NoPrimes as PrimeGcd<Next>>::Output>,
+{
+    type Output = <<E as Min<Z0>>::Output as PrimeNode<P, <NoPrimes as PrimeGcd<Next>>::Output>>::Output;
+}
 
-    /* /// 获取比例因子的符号表示
-    pub fn symbol() -> &'static str {
-        // 检查是否是时间单位
-        if let Some(sym) = Self::time_symbol() {
-            return sym;
-        }
-    } */ 
-} */
+// 非空表与空表：只保留负指数
+// Non-empty and empty: only negative exponents remain
+impl<P, E, Next> PrimeGcd<NoPrimes> for PrimePow<P, E, Next>
+where
+    NoPrimes: PrimeGcd<PrimePow<P, E, Next>>,
+{
+    type Output = <NoPrimes as PrimeGcd<PrimePow<P, E, Next>>>::Output;
+}
+
+// 两个非空表按首个质数的大小归并
+// Two non-empty lists are merged by comparing their first primes
+impl<Pa, Ea, Na, Pb, Eb, Nb> PrimeGcd<PrimePow<Pb, Eb, Nb>> for PrimePow<Pa, Ea, Na>
+where
+    Pa: Cmp<Pb>,
+    PrimePow<Pa, Ea, Na>: PrimeGcdMerge<<Pa as Cmp<Pb>>::Output, PrimePow<Pb, Eb, Nb>>,
+{
+    type Output = <PrimePow<Pa, Ea, Na> as PrimeGcdMerge<<Pa as Cmp<Pb>>::Output, PrimePow<Pb, Eb, Nb>>>::Output;
+}
+
+// Pa < Pb：左侧首节点的指数与0取较小值
+// Pa < Pb: the left head keeps min(Ea, 0)
+impl<Pa, Ea, Na, Rhs> PrimeGcdMerge<Less, Rhs> for PrimePow<Pa, Ea, Na>
+where
+    Ea: Min<Z0>,
+    Na: PrimeGcd<Rhs>,
+    <Ea as Min<Z0>>::Output: PrimeNode<Pa, <Na as PrimeGcd<Rhs>>::Output>,
+{
+    type Output = <<Ea as Min<Z0>>::Output as PrimeNode<Pa, <Na as PrimeGcd<Rhs>>::Output>>::Output;
+}
+
+// Pa > Pb：右侧首节点的指数与0取较小值
+// Pa > Pb: the right head keeps min(Eb, 0)
+impl<Pa, Ea, Na, Pb, Eb, Nb> PrimeGcdMerge<Greater, PrimePow<Pb, Eb, Nb>> for PrimePow<Pa, Ea, Na>
+where
+    Eb: Min<Z0>,
+    PrimePow<Pa, Ea, Na>: PrimeGcd<Nb>,
+    <Eb as Min<Z0>>::Output: PrimeNode<Pb, <PrimePow<Pa, Ea, Na> as PrimeGcd<Nb>>::Output>,
+{
+    type Output = <<Eb as Min<Z0>>::Output as PrimeNode<Pb, <PrimePow<Pa, Ea, Na> as PrimeGcd<Nb>>::Output>>::Output;
+}
+
+// Pa == Pb：指数取较小值
+// Pa == Pb: the smaller exponent
+impl<Pa, Ea, Na, Pb, Eb, Nb> PrimeGcdMerge<Equal, PrimePow<Pb, Eb, Nb>> for PrimePow<Pa, Ea, Na>
+where
+    Ea: Min<Eb>,
+    Na: PrimeGcd<Nb>,
+    <Ea as Min<Eb>>::Output: PrimeNode<Pa, <Na as PrimeGcd<Nb>>::Output>,
+{
+    type Output = <<Ea as Min<Eb>>::Output as PrimeNode<Pa, <Na as PrimeGcd<Nb>>::Output>>::Output;
+}
+
+// ========== 编译期数值 ==========
+// ========== Compile-time Values ==========
+
+/// `base^exp`（编译期可用，不依赖 `std`）
+/// `base^exp` (usable at compile time, without `std`)
+pub(crate) const fn powi_f64(base: f64, exp: i32) -> f64 {
+    let mut result = 1.0;
+    let mut i = 0;
+    while i < exp.unsigned_abs() {
+        result *= base;
+        i += 1;
+    }
+    if exp < 0 { 1.0 / result } else { result }
+}
+
+/// `base^exp` 的精确分数，溢出时为 `None`
+/// Exact fraction of `base^exp`; `None` on overflow
+pub(crate) const fn exact_pow(base: i128, exp: i32) -> Option<(i128, i128)> {
+    match base.checked_pow(exp.unsigned_abs()) {
+        Some(p) if exp < 0 => Some((1, p)),
+        Some(p) => Some((p, 1)),
+        None => None,
+    }
+}
+
+/// 两个互素分数的乘积（各质因数不同，无需约分），溢出时为 `None`
+/// Product of two coprime fractions (distinct primes, no reduction needed); `None` on overflow
+pub(crate) const fn exact_mul(a: Option<(i128, i128)>, b: Option<(i128, i128)>) -> Option<(i128, i128)> {
+    match (a, b) {
+        (Some((n1, d1)), Some((n2, d2))) => match (n1.checked_mul(n2), d1.checked_mul(d2)) {
+            (Some(n), Some(d)) => Some((n, d)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+impl<E5: TypedInt, EPi: TypedInt, E3: TypedInt, E2: TypedInt, Primes: PrimeList> Ratio<E5, EPi, E3, E2, Primes> {
+    /// 比例因子的 f64 值（编译期常量）；有精确分数时由分数直接换算，误差最小
+    /// The ratio as f64 (compile-time constant); derived from the exact fraction when
+    /// there is one, which keeps the rounding error minimal
+    pub const FACTOR_F64: f64 = match Self::EXACT {
+        Some((num, den)) => num as f64 / den as f64,
+        None => powi_f64(5.0, E5::I32)
+            * powi_f64(PI, EPi::I32)
+            * powi_f64(3.0, E3::I32)
+            * powi_f64(2.0, E2::I32)
+            * Primes::FACTOR_F64,
+    };
+
+    /// 比例因子的精确分数 `(分子, 分母)`；含π或超出 `i128` 时为 `None`（编译期常量）
+    /// The ratio as an exact fraction `(num, den)`; `None` when π is present or beyond `i128` (compile-time constant)
+    pub const EXACT: Option<(i128, i128)> = if EPi::I32 != 0 {
+        None
+    } else {
+        exact_mul(
+            exact_mul(exact_pow(5, E5::I32), exact_pow(3, E3::I32)),
+            exact_mul(exact_pow(2, E2::I32), Primes::EXACT),
+        )
+    };
+
+    /// 比例因子的 f64 值
+    /// The ratio as f64
+    pub const fn value() -> f64 {
+        Self::FACTOR_F64
+    }
+}
 
 // 实现比例因子的乘法
-impl<E5a, EPia, E3a, E2a, E5b, EPib, E3b, E2b> Mul<Ratio<E5b, EPib, E3b, E2b>> for Ratio<E5a, EPia, E3a, E2a>
+impl<E5a, EPia, E3a, E2a, Pa, E5b, EPib, E3b, E2b, Pb> Mul<Ratio<E5b, EPib, E3b, E2b, Pb>> for Ratio<E5a, EPia, E3a, E2a, Pa>
 where
     E5a: TypedInt + Add<E5b, Output: TypedInt>,
     EPia: TypedInt + Add<EPib, Output: TypedInt>,
     E3a: TypedInt + Add<E3b, Output: TypedInt>,
     E2a: TypedInt + Add<E2b, Output: TypedInt>,
+    Pa: PrimeList + PrimeMul<Pb, Output: PrimeList>,
     E5b: TypedInt,
     EPib: TypedInt,
     E3b: TypedInt,
     E2b: TypedInt,
+    Pb: PrimeList,
 {
     type Output = Ratio<
         <E5a as Add<E5b>>::Output,
         <EPia as Add<EPib>>::Output,
         <E3a as Add<E3b>>::Output,
-        <E2a as Add<E2b>>::Output
+        <E2a as Add<E2b>>::Output,
+        <Pa as PrimeMul<Pb>>::Output
     >;
     
-    fn mul(self, _: Ratio<E5b, EPib, E3b, E2b>) -> Self::Output {
+    fn mul(self, _: Ratio<E5b, EPib, E3b, E2b, Pb>) -> Self::Output {
         Ratio(PhantomData)
     }
 }
 
 // 实现比例因子的除法
-impl<E5a, EPia, E3a, E2a, E5b, EPib, E3b, E2b> Div<Ratio<E5b, EPib, E3b, E2b>> for Ratio<E5a, EPia, E3a, E2a>
+impl<E5a, EPia, E3a, E2a, Pa, E5b, EPib, E3b, E2b, Pb> Div<Ratio<E5b, EPib, E3b, E2b, Pb>> for Ratio<E5a, EPia, E3a, E2a, Pa>
 where
     E5a: TypedInt + Sub<E5b, Output: TypedInt>,
     EPia: TypedInt + Sub<EPib, Output: TypedInt>,
     E3a: TypedInt + Sub<E3b, Output: TypedInt>,
     E2a: TypedInt + Sub<E2b, Output: TypedInt>,
+    Pa: PrimeList + PrimeMul<<Pb as PrimeInv>::Output, Output: PrimeList>,
     E5b: TypedInt,
     EPib: TypedInt,
     E3b: TypedInt,
     E2b: TypedInt,
+    Pb: PrimeList + PrimeInv,
 {
     type Output = Ratio<
         <E5a as Sub<E5b>>::Output,
         <EPia as Sub<EPib>>::Output,
         <E3a as Sub<E3b>>::Output,
-        <E2a as Sub<E2b>>::Output
+        <E2a as Sub<E2b>>::Output,
+        PrimeDiv<Pa, Pb>
     >;
     
-    fn div(self, _: Ratio<E5b, EPib, E3b, E2b>) -> Self::Output {
+    fn div(self, _: Ratio<E5b, EPib, E3b, E2b, Pb>) -> Self::Output {
         Ratio(PhantomData)
     }
 }
 
+/// 比例因子的公约数：两个比例都能被它整除的最大比例
+/// 2、3、5 的指数和质因数表逐项取较小值；π 的指数必须相同
+/// Common divisor of two ratios: the largest ratio that divides both of them
+/// The exponents of 2, 3, 5 and of the prime list take the smaller value; the
+/// exponent of π must be the same on both sides
+pub trait RatioGcd<Rhs> {
+    type Output;
+}
+
+impl<E5a, E3a, E2a, Pa, E5b, E3b, E2b, Pb, EPi> RatioGcd<Ratio<E5b, EPi, E3b, E2b, Pb>> for Ratio<E5a, EPi, E3a, E2a, Pa>
+where
+    E5a: TypedInt + Min<E5b, Output: TypedInt>,
+    EPi: TypedInt,
+    E3a: TypedInt + Min<E3b, Output: TypedInt>,
+    E2a: TypedInt + Min<E2b, Output: TypedInt>,
+    Pa: PrimeList + PrimeGcd<Pb, Output: PrimeList>,
+    E5b: TypedInt,
+    E3b: TypedInt,
+    E2b: TypedInt,
+    Pb: PrimeList,
+{
+    type Output = Ratio<
+        <E5a as Min<E5b>>::Output,
+        EPi,
+        <E3a as Min<E3b>>::Output,
+        <E2a as Min<E2b>>::Output,
+        <Pa as PrimeGcd<Pb>>::Output
+    >;
+}
+
+// ========== 精确值 ==========
+// ========== Exact Value ==========
+
+// 只有不含π的比例才有精确值
+// Only ratios without π have an exact value
+impl<E5: TypedInt, E3: TypedInt, E2: TypedInt, Primes: PrimeList> ExactValue for Ratio<E5, Z0, E3, E2, Primes> {
+    const EXACT: (i128, i128) = match Ratio::<E5, Z0, E3, E2, Primes>::EXACT {
+        Some(exact) => exact,
+        None => panic!("ratio factor overflows i128"),
+    };
+}
+
 pub trait Scaled: Sealed{}// 不含NoRatio
-impl<Exp5: TypedInt, ExpPi:TypedInt, Exp3: TypedInt, Exp2: TypedInt> Sealed for Ratio<Exp5, ExpPi, Exp3, Exp2>{}
+impl<Exp5: TypedInt, ExpPi:TypedInt, Exp3: TypedInt, Exp2: TypedInt, Primes: PrimeList> Sealed for Ratio<Exp5, ExpPi, Exp3, Exp2, Primes>{}
+
+// 质因数表非空时必定不是1
+// A non-empty prime list is never 1
+impl<Exp5: TypedInt, ExpPi: TypedInt, Exp3: TypedInt, Exp2: TypedInt, P, E, Next> Scaled for Ratio<Exp5, ExpPi, Exp3, Exp2, PrimePow<P, E, Next>>
+where
+    PrimePow<P, E, Next>: PrimeList,
+{}
 
 impl<Exp5: TypedInt, ExpPi: TypedInt, Exp3: TypedInt, Exp2: NonZero> Scaled for Ratio<Exp5, ExpPi, Exp3, Exp2>{}
 impl<Exp5: TypedInt, ExpPi: TypedInt, Exp3: NonZero> Scaled for Ratio<Exp5, ExpPi, Exp3, Z0>{}
@@ -91,4 +470,85 @@ impl<Exp5: NonZero> Scaled for Ratio<Exp5, Z0, Z0, Z0>{}
 // ========== 常用比例定义 ==========
 
 /// 单位1 (无比例)
-pub type NoRatio = Ratio<Z0, Z0, Z0, Z0>;
\ No newline at end of file
+pub type NoRatio = Ratio<Z0, Z0, Z0, Z0>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{N1, Const};
+
+    type P7 = PrimePow<Const<7>, P1>;
+    // 127 超出 `Const` 范围，直接写出二进制形式
+    // 127 is beyond the range of `Const`, so it is spelled out in binary
+    type P127 = PrimePow<B1<B1<B1<B1<B1<B1<P1>>>>>>, P1>;
+
+    #[test]
+    fn test_prime_list_merge() {
+        // 7 * 127 -> [7, 127]，顺序无关
+        // 7 * 127 -> [7, 127], regardless of order
+        let _: PrimePow<Const<7>, P1, P127> = <P7 as PrimeMul<P127>>::Output::default();
+        let _: PrimePow<Const<7>, P1, P127> = <P127 as PrimeMul<P7>>::Output::default();
+        // 7 * 7 -> 7^2
+        let _: PrimePow<Const<7>, Const<2>> = <P7 as PrimeMul<P7>>::Output::default();
+        // 7 / 7 -> 空表 / empty list
+        let _: NoPrimes = PrimeDiv::<P7, P7>::default();
+    }
+
+    #[test]
+    fn test_extended_ratio() {
+        // 英寸 = 0.0254 m = 127 * 2^-3 * 5^-4
+        // Inch = 0.0254 m = 127 * 2^-3 * 5^-4
+        type Inch = Ratio<Const<-4>, Z0, Z0, Const<-3>, P127>;
+        // 英尺 = 12 英寸 / Foot = 12 inches
+        type Foot = <Inch as Mul<Ratio<Z0, Z0, P1, Const<2>>>>::Output;
+        let _: Ratio<Const<-4>, Z0, P1, N1, P127> = Foot::default();
+
+        // 英尺 / 英寸 = 12，质因数127被消去
+        // Foot / inch = 12, the prime 127 cancels out
+        let _: Ratio<Z0, Z0, P1, Const<2>> = <Foot as Div<Inch>>::Output::default();
+
+        // 一周 = 7 天 / One week = 7 days
+        type Day = Ratio<Const<2>, Z0, Const<3>, Const<7>>;
+        let _: Ratio<Const<2>, Z0, Const<3>, Const<7>, P7> = <Day as Mul<Ratio<Z0, Z0, Z0, Z0, P7>>>::Output::default();
+    }
+
+    #[test]
+    fn test_ratio_gcd() {
+        // 小时与分钟的公约数为分钟 / The common divisor of an hour and a minute is the minute
+        type Minute = Ratio<P1, Z0, P1, Const<2>>;
+        type Hour = Ratio<Const<2>, Z0, Const<2>, Const<4>>;
+        let _: Minute = <Hour as RatioGcd<Minute>>::Output::default();
+        // 英寸与米：质因数127被舍去 / Inch and metre: the prime 127 is dropped
+        type Inch = Ratio<Const<-4>, Z0, Z0, Const<-3>, P127>;
+        let _: Ratio<Const<-4>, Z0, Z0, Const<-3>> = <Inch as RatioGcd<NoRatio>>::Output::default();
+        // 负指数的质因数保留 / Primes with negative exponents are kept
+        let _: Ratio<Z0, Z0, Z0, Z0, <P7 as PrimeInv>::Output> = <NoRatio as RatioGcd<Ratio<Z0, Z0, Z0, Z0, <P7 as PrimeInv>::Output>>>::Output::default();
+        let _: P7 = <P7 as PrimeGcd<PrimePow<Const<7>, Const<2>, P127>>>::Output::default();
+    }
+
+    #[test]
+    fn test_consts() {
+        const MINUTE: Option<(i128, i128)> = <Ratio<P1, Z0, P1, Const<2>>>::EXACT;
+        assert_eq!(MINUTE, Some((60, 1)));
+        assert_eq!(<Ratio<N1, P1, Const<-2>, Const<-2>>>::EXACT, None);
+        assert!((<Ratio<N1, P1, Const<-2>, Const<-2>>>::FACTOR_F64 - core::f64::consts::PI / 180.0).abs() < 1e-15);
+        assert_eq!(<Ratio<Const<-4>, Z0, Z0, Const<-3>, P127>>::FACTOR_F64, 0.0254);
+        assert_eq!(<Ratio<Const<60>, Z0, Z0, Z0>>::EXACT, None);
+        assert_eq!(<PrimePow<Const<7>, P1, P127>>::FIRST, 7);
+    }
+
+    #[test]
+    fn test_exact_value() {
+        assert_eq!(<Ratio<P1, Z0, P1, Const<2>> as ExactValue>::EXACT, (60, 1));
+        assert_eq!(<Ratio<Const<-4>, Z0, Z0, Const<-3>, P127> as ExactValue>::EXACT, (127, 5000));
+        assert_eq!(<NoRatio as ExactValue>::EXACT, (1, 1));
+    }
+
+    fn assert_scaled<R: Scaled>() {}
+
+    #[test]
+    fn test_scaled() {
+        assert_scaled::<Ratio<Z0, Z0, Z0, Z0, P7>>();
+        assert_scaled::<Ratio<Z0, Z0, Z0, P1>>();
+    }
+}
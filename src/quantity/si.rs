@@ -2,21 +2,49 @@
 //! 
 //! 支持单位自动推导和SI前缀转换
 //! 
+use core::cmp::Ordering;
 use core::marker::PhantomData;
 use core::ops::{Neg, Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAssign};
 
 use crate::sealed::Sealed;
 use super::Dimensional;
-use super::prefix::Prefixed;
-use crate::number::{Positive, Primitive, Var};
+use super::prefix::{Prefixed, AlignPrefix};
+use crate::number::{Positive, Primitive, Var, Scale, Min, CheckedArith};
 use super::Unitary;
 use super::Unit;
+use super::unit::{Normalize, UnitOrSi, CheckedAddSub};
 use super::ratio::{NoRatio, Scaled};
+use super::convert::Conversion;
 /// SI基础结构
 /// 
 /// # 类型参数
 /// - `Pr`: SI前缀类型
 /// - `D`: 量纲类型
+///
+/// # 不同词头相加减 / Adding across prefixes
+///
+/// 右操作数的词头是泛型参数，因此两侧都需能推断出词头：`Meter::new(..)` 不写类型时
+/// 词头无法推断（E0283），需写成 `<Meter>::new(..)` 或标注类型。
+/// The prefix of the right operand is generic, so both sides need an inferable prefix:
+/// a bare `Meter::new(..)` leaves it ambiguous (E0283); write `<Meter>::new(..)` or
+/// annotate the type.
+///
+/// ```
+/// use unitrix::quantity::si_units::Meter;
+///
+/// let sum = <Meter>::new(1.0) + <Meter>::new(2.0);
+/// assert_eq!(sum.0 .0, 3.0);
+/// ```
+///
+/// ```compile_fail,E0283
+/// use unitrix::quantity::si_units::Meter;
+///
+/// let sum = <Meter>::new(1.0) + Meter::new(2.0);
+/// ```
+///
+/// 整数存储时换算或相加可能溢出：运算符会 panic，[`CheckedAddSub`] 返回 `None`。
+/// With integer storage the rescale or the sum may overflow: the operators panic,
+/// while [`CheckedAddSub`] returns `None`.
 #[derive(Debug, Clone, Copy)]
 pub struct Si<
     Value,
@@ -66,19 +94,79 @@ where
     }
 }
 
+// ----- 比较运算符 -----
+// 词头不同时换算到较小词头后比较；较粗的一方放大溢出时，其绝对值必然更大
+// With differing prefixes both sides are rescaled to the finer prefix; if the coarser
+// side overflows while rescaling, its magnitude is necessarily the larger one
+
+impl<T, D, Pr1, Pr2> PartialEq<Si<Var<T>, D, Pr2>> for Si<Var<T>, D, Pr1>
+where
+    T: Primitive + Scale + PartialEq,
+    D: Dimensional,
+    Pr1: Prefixed + Min<Pr2, Output: Prefixed> + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    Pr2: Prefixed + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+{
+    fn eq(&self, other: &Si<Var<T>, D, Pr2>) -> bool {
+        let a = Si::<Var<T>, D, Pr1>::new(self.0 .0).checked_convert_to::<<Pr1 as Min<Pr2>>::Output>();
+        let b = Si::<Var<T>, D, Pr2>::new(other.0 .0).checked_convert_to::<<Pr1 as Min<Pr2>>::Output>();
+        match (a, b) {
+            (Some(a), Some(b)) => a.0 .0 == b.0 .0,
+            _ => false,
+        }
+    }
+}
+
+impl<T, D, Pr1, Pr2> PartialOrd<Si<Var<T>, D, Pr2>> for Si<Var<T>, D, Pr1>
+where
+    T: Primitive + Scale + PartialOrd,
+    D: Dimensional,
+    Pr1: Prefixed + Min<Pr2, Output: Prefixed> + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    Pr2: Prefixed + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+{
+    fn partial_cmp(&self, other: &Si<Var<T>, D, Pr2>) -> Option<Ordering> {
+        let a = Si::<Var<T>, D, Pr1>::new(self.0 .0).checked_convert_to::<<Pr1 as Min<Pr2>>::Output>();
+        let b = Si::<Var<T>, D, Pr2>::new(other.0 .0).checked_convert_to::<<Pr1 as Min<Pr2>>::Output>();
+        match (a, b) {
+            (Some(a), Some(b)) => a.0 .0.partial_cmp(&b.0 .0),
+            (None, _) => self.0 .0.partial_cmp(&T::default()),
+            (_, None) => T::default().partial_cmp(&other.0 .0),
+        }
+    }
+}
+
 // ----- 加法运算符及加法赋值 -----
-// Si + Si
-impl<T, D, Pr> Add for Si<Var<T>, D, Pr>
+// Si + Si（词头可不同，结果取较小词头，较粗的一方按10的整数次幂精确放大；
+// 词头相同时直接相加，见 `AlignPrefix`）
+impl<T, D, Pr1, Pr2> Add<Si<Var<T>, D, Pr2>> for Si<Var<T>, D, Pr1>
 where
-    Var<T>: Add<Var<T>, Output = Var<T>>,
+    T: Primitive,
     D: Dimensional,
-    Pr: Prefixed,
+    Pr1: Prefixed + AlignPrefix<Pr2, T>,
+    Pr2: Prefixed,
 {
-    type Output = Self;
+    type Output = Si<Var<T>, D, <Pr1 as AlignPrefix<Pr2, T>>::Output>;
+    
+    /// 加法（量纲相同；词头不同且整数存储时换算溢出则 panic）
+    fn add(self, rhs: Si<Var<T>, D, Pr2>) -> Self::Output {
+        let (a, b) = Pr1::align(self.0 .0, rhs.0 .0);
+        Si(Var(a) + Var(b), PhantomData)
+    }
+}
+
+// Si + U（Si 视为无比例的 Unit）
+impl<T, D, Pr1, Pr2, R> Add<Unit<Si<Var<T>, D, Pr2>, R>> for Si<Var<T>, D, Pr1>
+where
+    T: Primitive,
+    D: Dimensional,
+    Pr1: Prefixed,
+    Pr2: Prefixed,
+    Unit<Self, NoRatio>: Add<Unit<Si<Var<T>, D, Pr2>, R>>,
+{
+    type Output = <Unit<Self, NoRatio> as Add<Unit<Si<Var<T>, D, Pr2>, R>>>::Output;
     
-    /// 加法（要求相同前缀和量纲）
-    fn add(self, rhs: Self) -> Self::Output {
-        Si(self.0 + rhs.0, PhantomData)
+    /// 物理量加法
+    fn add(self, rhs: Unit<Si<Var<T>, D, Pr2>, R>) -> Self::Output {
+        Unit(self, PhantomData) + rhs
     }
 }
 
@@ -122,18 +210,37 @@ where
 
 // ----- 减法运算符及减法赋值 -----
 
-// Si - Si
-impl<T: Primitive, D, Pr> Sub for Si<Var<T>, D, Pr>
+// Si - Si（词头可不同，结果取较小词头；词头相同时直接相减）
+impl<T, D, Pr1, Pr2> Sub<Si<Var<T>, D, Pr2>> for Si<Var<T>, D, Pr1>
 where
-    Var<T>: Sub<Var<T>, Output = Var<T>>,
+    T: Primitive,
     D: Dimensional,
-    Pr: Prefixed,
+    Pr1: Prefixed + AlignPrefix<Pr2, T>,
+    Pr2: Prefixed,
 {
-    type Output = Self;
+    type Output = Si<Var<T>, D, <Pr1 as AlignPrefix<Pr2, T>>::Output>;
+    
+    /// 减法（量纲相同；词头不同且整数存储时换算溢出则 panic）
+    fn sub(self, rhs: Si<Var<T>, D, Pr2>) -> Self::Output {
+        let (a, b) = Pr1::align(self.0 .0, rhs.0 .0);
+        Si(Var(a) - Var(b), PhantomData)
+    }
+}
+
+// Si - U（Si 视为无比例的 Unit）
+impl<T, D, Pr1, Pr2, R> Sub<Unit<Si<Var<T>, D, Pr2>, R>> for Si<Var<T>, D, Pr1>
+where
+    T: Primitive,
+    D: Dimensional,
+    Pr1: Prefixed,
+    Pr2: Prefixed,
+    Unit<Self, NoRatio>: Sub<Unit<Si<Var<T>, D, Pr2>, R>>,
+{
+    type Output = <Unit<Self, NoRatio> as Sub<Unit<Si<Var<T>, D, Pr2>, R>>>::Output;
     
-    /// 减法（要求相同前缀和量纲）
-    fn sub(self, rhs: Self) -> Self::Output {
-        Si(self.0 - rhs.0, PhantomData)
+    /// 物理量减法
+    fn sub(self, rhs: Unit<Si<Var<T>, D, Pr2>, R>) -> Self::Output {
+        Unit(self, PhantomData) - rhs
     }
 }
 
@@ -175,6 +282,48 @@ where
     }
 }
 
+// ----- 检查溢出的加减法 -----
+// Si ± Si
+impl<T, D, Pr1, Pr2> CheckedAddSub<Si<Var<T>, D, Pr2>> for Si<Var<T>, D, Pr1>
+where
+    T: Primitive + CheckedArith,
+    D: Dimensional,
+    Pr1: Prefixed + AlignPrefix<Pr2, T>,
+    Pr2: Prefixed,
+{
+    type Output = Si<Var<T>, D, <Pr1 as AlignPrefix<Pr2, T>>::Output>;
+
+    fn checked_add(self, rhs: Si<Var<T>, D, Pr2>) -> Option<Self::Output> {
+        let (a, b) = Pr1::checked_align(self.0 .0, rhs.0 .0)?;
+        Some(Si::new(a.checked_add(b)?))
+    }
+
+    fn checked_sub(self, rhs: Si<Var<T>, D, Pr2>) -> Option<Self::Output> {
+        let (a, b) = Pr1::checked_align(self.0 .0, rhs.0 .0)?;
+        Some(Si::new(a.checked_sub(b)?))
+    }
+}
+
+// Si ± U（Si 视为无比例的 Unit）
+impl<T, D, Pr1, Pr2, R> CheckedAddSub<Unit<Si<Var<T>, D, Pr2>, R>> for Si<Var<T>, D, Pr1>
+where
+    T: Primitive,
+    D: Dimensional,
+    Pr1: Prefixed,
+    Pr2: Prefixed,
+    Unit<Self, NoRatio>: CheckedAddSub<Unit<Si<Var<T>, D, Pr2>, R>>,
+{
+    type Output = <Unit<Self, NoRatio> as CheckedAddSub<Unit<Si<Var<T>, D, Pr2>, R>>>::Output;
+
+    fn checked_add(self, rhs: Unit<Si<Var<T>, D, Pr2>, R>) -> Option<Self::Output> {
+        Unit(self, PhantomData).checked_add(rhs)
+    }
+
+    fn checked_sub(self, rhs: Unit<Si<Var<T>, D, Pr2>, R>) -> Option<Self::Output> {
+        Unit(self, PhantomData).checked_sub(rhs)
+    }
+}
+
 // ----- 乘法运算符及乘法赋值 -----
 // Si * Si
 impl<T, D1, D2, Pr1, Pr2> Mul<Si<Var<T>, D2, Pr2>> for Si<Var<T>, D1, Pr1>
@@ -252,7 +401,7 @@ where
     }
 }
 
-// Si * Unit
+// Si * Unit（结果中的10的幂次移入词头）
 //因为编译器要求，需要拆解Si
 impl<S, R, T, D, Pr> Mul<Unit<S, R>> for Si<Var<T>, D, Pr>
 where
@@ -262,15 +411,21 @@ where
     S: Sied + Mul<Si<Var<T>, D, Pr>, Output: Sied>,
     Si<Var<T>, D, Pr>: Sied,
     R: Scaled,
-{
-    type Output = Unit<
-        <S as Mul<Si<Var<T>, D, Pr>>>::Output,  // 单位相乘
+    Unit<
+        <S as Mul<Si<Var<T>, D, Pr>>>::Output,
         R
-    >;
+    >: Normalize<Output: UnitOrSi>,
+{
+    type Output = <
+        <Unit<
+            <S as Mul<Si<Var<T>, D, Pr>>>::Output,  // 单位相乘
+            R
+        > as Normalize>::Output as UnitOrSi
+    >::Output;
     
     /// 物理量乘法
     fn mul(self, rhs: Unit<S, R>) -> Self::Output {
-        Unit(rhs.0 * self, PhantomData)
+        Unit(rhs.0 * self, PhantomData).normalize().unit()
     }
 }
 
@@ -319,7 +474,7 @@ where
     }
 }
 
-// Si / U
+// Si / U（结果中的10的幂次移入词头）
 //因为编译器对Si要求，必须拆解后打包
 impl<T, D1, Pr1, D2, Pr2, R> Div<Unit<Si<Var<T>, D2, Pr2>, R>> for Si<Var<T>, D1, Pr1>
 where
@@ -331,14 +486,100 @@ where
     R: Scaled,
     NoRatio: Div<R>,
     Si<Var<T>, D1, Pr1>: Div<Si<Var<T>, D2, Pr2>,Output: Sied>,
-{
-    type Output = Unit<
+    Unit<
         < Si<Var<T>, D1, Pr1> as Div<Si<Var<T>, D2, Pr2>> >::Output,
         <NoRatio as Div<R>>::Output,
-    >;
+    >: Normalize<Output: UnitOrSi>,
+{
+    type Output = <
+        <Unit<
+            < Si<Var<T>, D1, Pr1> as Div<Si<Var<T>, D2, Pr2>> >::Output,
+            <NoRatio as Div<R>>::Output,
+        > as Normalize>::Output as UnitOrSi
+    >::Output;
     
     /// 物理量除法
     fn div(self, rhs: Unit<Si<Var<T>, D2, Pr2>, R>) -> Self::Output {
-        Unit(self / rhs.0, PhantomData)
+        Unit(self / rhs.0, PhantomData).normalize().unit()
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantity::{Kilo, Milli, NoPrefix};
+    use crate::quantity::si_units::Meter;
+
+    #[test]
+    fn test_mixed_prefix_add_sub() {
+        let km: Meter<i64, Kilo> = Si::new(1);
+        let m: Meter<i64> = Si::new(250);
+        let sum: Meter<i64, NoPrefix> = km + m;
+        assert_eq!(sum.0 .0, 1250);
+        let diff: Meter<i64, NoPrefix> = m - km;
+        assert_eq!(diff.0 .0, -750);
+
+        let mm: Meter<f64, Milli> = Si::new(5.0);
+        let total: Meter<f64, Milli> = <Meter>::new(1.0) + mm;
+        assert_eq!(total.0 .0, 1005.0);
     }
-}
\ No newline at end of file
+
+    // 同词头相加减不经换算，也不要求 `T: Scale`
+    // Same-prefix addition and subtraction skip conversion and need no `T: Scale`
+    fn same_prefix_sum<T: Primitive>(a: Meter<T, Kilo>, b: Meter<T, Kilo>) -> Meter<T, Kilo> {
+        a + b - b + b
+    }
+
+    #[test]
+    fn test_same_prefix_add_sub() {
+        let near_max: Meter<i64, Kilo> = Si::new(i64::MAX - 1);
+        assert_eq!(same_prefix_sum(near_max, Si::new(1)).0 .0, i64::MAX);
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        let km: Meter<i32, Kilo> = Si::new(3_000_000);
+        assert!(km.checked_add(Meter::<i32>::new(1)).is_none());
+        assert!(km.checked_sub(Meter::<i32>::new(1)).is_none());
+
+        let near_max: Meter<i32> = Si::new(i32::MAX);
+        assert!(near_max.checked_add(Meter::<i32>::new(1)).is_none());
+        assert!(Meter::<i32>::new(i32::MIN).checked_sub(Meter::<i32>::new(1)).is_none());
+
+        let km: Meter<i32, Kilo> = Si::new(2);
+        let sum: Meter<i32, NoPrefix> = km.checked_add(Meter::<i32>::new(5)).unwrap();
+        assert_eq!(sum.0 .0, 2005);
+        let diff: Meter<i32, NoPrefix> = Meter::<i32>::new(5).checked_sub(km).unwrap();
+        assert_eq!(diff.0 .0, -1995);
+    }
+
+    // 右操作数需能推断出词头（见 `Si` 的文档）
+    // The right operand needs an inferable prefix (see the docs of `Si`)
+    #[test]
+    fn test_default_prefix_inference() {
+        let sum = <Meter>::new(1.0) + <Meter>::new(2.0);
+        assert_eq!(sum.0 .0, 3.0);
+        let diff = <Meter>::new(1.0) - Meter::<f64>::new(2.0);
+        assert_eq!(diff.0 .0, -1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_mixed_prefix_overflow() {
+        let km: Meter<i64, Kilo> = Si::new(i64::MAX / 10);
+        let m: Meter<i64> = Si::new(1);
+        let _ = km + m;
+    }
+
+    #[test]
+    fn test_mixed_prefix_cmp() {
+        let km: Meter<i64, Kilo> = Si::new(1);
+        assert!(km == Meter::<i64>::new(1000));
+        assert!(km > Meter::<i64>::new(999));
+        assert!(km < Meter::<i64, Milli>::new(1_000_001));
+
+        let huge: Meter<i64, Kilo> = Si::new(i64::MAX);
+        assert!(huge > Meter::<i64>::new(i64::MAX));
+        assert!(-huge < Meter::<i64>::new(i64::MIN));
+        assert!(huge != Meter::<i64>::new(i64::MAX));
+    }
+}
@@ -0,0 +1,253 @@
+// si_units.rs
+//! SI units as type aliases
+//! 国际单位制单位的类型别名
+//!
+//! 包括7个基本单位、22个具有专门名称的一贯导出单位以及常用的组合量。
+//! 每个别名都对值类型 `V` 与词头 `Pr` 泛型，默认为 `f64` 与无词头。
+//! Covers the 7 base units, the 22 coherent derived units with special names and
+//! common compound quantities. Every alias is generic over the value type `V` and
+//! the prefix `Pr`, defaulting to `f64` and no prefix.
+//!
+//! 质量的量纲基准是千克，因此 `Kilogram<V, Milli>` 表示克。
+//! The mass dimension is based on the kilogram, so `Kilogram<V, Milli>` is the gram.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::si_units::{Kilogram, Meter, Second, Newton};
+//!
+//! let f: Newton = <Kilogram>::new(2.0) * <Meter>::new(3.0) / (<Second>::new(1.0) * <Second>::new(1.0));
+//! assert_eq!(f.0 .0, 6.0);
+//! ```
+
+use crate::number::{Const, Var, Z0, P1, N1};
+use super::{Dimension, Si, NoPrefix, Kinded, TorqueKind, ActivityKind, DoseEquivalentKind};
+
+// ========== 量纲 ==========
+// ========== Dimensions ==========
+// 参数顺序：m, kg, s, A, K, mol, cd
+// Parameter order: m, kg, s, A, K, mol, cd
+
+/// 无量纲 / Dimensionless
+pub type Dimensionless = Dimension<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// 长度 / Length
+pub type LengthDimension = Dimension<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// 质量 / Mass
+pub type MassDimension = Dimension<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+/// 时间 / Time
+pub type TimeDimension = Dimension<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+/// 电流 / Electric current
+pub type CurrentDimension = Dimension<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+/// 热力学温度 / Thermodynamic temperature
+pub type TemperatureDimension = Dimension<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+/// 物质的量 / Amount of substance
+pub type AmountDimension = Dimension<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+/// 发光强度 / Luminous intensity
+pub type LuminousIntensityDimension = Dimension<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+
+/// 频率 s⁻¹ / Frequency s⁻¹
+pub type FrequencyDimension = Dimension<Z0, Z0, N1, Z0, Z0, Z0, Z0>;
+/// 力 m·kg·s⁻² / Force m·kg·s⁻²
+pub type ForceDimension = Dimension<P1, P1, Const<-2>, Z0, Z0, Z0, Z0>;
+/// 压强 m⁻¹·kg·s⁻² / Pressure m⁻¹·kg·s⁻²
+pub type PressureDimension = Dimension<N1, P1, Const<-2>, Z0, Z0, Z0, Z0>;
+/// 能量 m²·kg·s⁻² / Energy m²·kg·s⁻²
+pub type EnergyDimension = Dimension<Const<2>, P1, Const<-2>, Z0, Z0, Z0, Z0>;
+/// 功率 m²·kg·s⁻³ / Power m²·kg·s⁻³
+pub type PowerDimension = Dimension<Const<2>, P1, Const<-3>, Z0, Z0, Z0, Z0>;
+/// 电荷 s·A / Electric charge s·A
+pub type ChargeDimension = Dimension<Z0, Z0, P1, P1, Z0, Z0, Z0>;
+/// 电压 m²·kg·s⁻³·A⁻¹ / Voltage m²·kg·s⁻³·A⁻¹
+pub type VoltageDimension = Dimension<Const<2>, P1, Const<-3>, N1, Z0, Z0, Z0>;
+/// 电容 m⁻²·kg⁻¹·s⁴·A² / Capacitance m⁻²·kg⁻¹·s⁴·A²
+pub type CapacitanceDimension = Dimension<Const<-2>, N1, Const<4>, Const<2>, Z0, Z0, Z0>;
+/// 电阻 m²·kg·s⁻³·A⁻² / Resistance m²·kg·s⁻³·A⁻²
+pub type ResistanceDimension = Dimension<Const<2>, P1, Const<-3>, Const<-2>, Z0, Z0, Z0>;
+/// 电导 m⁻²·kg⁻¹·s³·A² / Conductance m⁻²·kg⁻¹·s³·A²
+pub type ConductanceDimension = Dimension<Const<-2>, N1, Const<3>, Const<2>, Z0, Z0, Z0>;
+/// 磁通量 m²·kg·s⁻²·A⁻¹ / Magnetic flux m²·kg·s⁻²·A⁻¹
+pub type MagneticFluxDimension = Dimension<Const<2>, P1, Const<-2>, N1, Z0, Z0, Z0>;
+/// 磁通量密度 kg·s⁻²·A⁻¹ / Magnetic flux density kg·s⁻²·A⁻¹
+pub type MagneticFluxDensityDimension = Dimension<Z0, P1, Const<-2>, N1, Z0, Z0, Z0>;
+/// 电感 m²·kg·s⁻²·A⁻² / Inductance m²·kg·s⁻²·A⁻²
+pub type InductanceDimension = Dimension<Const<2>, P1, Const<-2>, Const<-2>, Z0, Z0, Z0>;
+/// 照度 m⁻²·cd / Illuminance m⁻²·cd
+pub type IlluminanceDimension = Dimension<Const<-2>, Z0, Z0, Z0, Z0, Z0, P1>;
+/// 吸收剂量与剂量当量 m²·s⁻² / Absorbed and equivalent dose m²·s⁻²
+pub type DoseDimension = Dimension<Const<2>, Z0, Const<-2>, Z0, Z0, Z0, Z0>;
+/// 催化活性 s⁻¹·mol / Catalytic activity s⁻¹·mol
+pub type CatalyticActivityDimension = Dimension<Z0, Z0, N1, Z0, Z0, P1, Z0>;
+
+/// 面积 m² / Area m²
+pub type AreaDimension = Dimension<Const<2>, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// 体积 m³ / Volume m³
+pub type VolumeDimension = Dimension<Const<3>, Z0, Z0, Z0, Z0, Z0, Z0>;
+/// 速度 m·s⁻¹ / Velocity m·s⁻¹
+pub type VelocityDimension = Dimension<P1, Z0, N1, Z0, Z0, Z0, Z0>;
+/// 加速度 m·s⁻² / Acceleration m·s⁻²
+pub type AccelerationDimension = Dimension<P1, Z0, Const<-2>, Z0, Z0, Z0, Z0>;
+/// 动量 m·kg·s⁻¹ / Momentum m·kg·s⁻¹
+pub type MomentumDimension = Dimension<P1, P1, N1, Z0, Z0, Z0, Z0>;
+/// 密度 m⁻³·kg / Density m⁻³·kg
+pub type DensityDimension = Dimension<Const<-3>, P1, Z0, Z0, Z0, Z0, Z0>;
+/// 电场强度 m·kg·s⁻³·A⁻¹ / Electric field strength m·kg·s⁻³·A⁻¹
+pub type ElectricFieldDimension = Dimension<P1, P1, Const<-3>, N1, Z0, Z0, Z0>;
+/// 物质的量浓度 m⁻³·mol / Amount concentration m⁻³·mol
+pub type ConcentrationDimension = Dimension<Const<-3>, Z0, Z0, Z0, Z0, P1, Z0>;
+
+// ========== 基本单位 ==========
+// ========== Base Units ==========
+
+/// 米 m / Meter m
+pub type Meter<V = f64, Pr = NoPrefix> = Si<Var<V>, LengthDimension, Pr>;
+/// 千克 kg / Kilogram kg
+pub type Kilogram<V = f64, Pr = NoPrefix> = Si<Var<V>, MassDimension, Pr>;
+/// 秒 s / Second s
+pub type Second<V = f64, Pr = NoPrefix> = Si<Var<V>, TimeDimension, Pr>;
+/// 安培 A / Ampere A
+pub type Ampere<V = f64, Pr = NoPrefix> = Si<Var<V>, CurrentDimension, Pr>;
+/// 开尔文 K / Kelvin K
+pub type Kelvin<V = f64, Pr = NoPrefix> = Si<Var<V>, TemperatureDimension, Pr>;
+/// 摩尔 mol / Mole mol
+pub type Mole<V = f64, Pr = NoPrefix> = Si<Var<V>, AmountDimension, Pr>;
+/// 坎德拉 cd / Candela cd
+pub type Candela<V = f64, Pr = NoPrefix> = Si<Var<V>, LuminousIntensityDimension, Pr>;
+
+// ========== 具有专门名称的导出单位 ==========
+// ========== Derived Units with Special Names ==========
+
+/// 弧度 rad = m/m / Radian rad = m/m
+///
+/// 按SI是无量纲的：与 [`Steradian`] 及纯比值同一类型，可以相加，显示时不带单位，
+/// 解析时接受 `rad`、`sr` 并去掉角度。需要与纯比值区分时用
+/// [`PlaneAngle`](crate::quantity::PlaneAngle)。
+/// Dimensionless as in the SI: the same type as [`Steradian`] and plain ratios, so
+/// they add together and display without a unit, and parsing accepts `rad` and `sr`
+/// and drops the angle. Use [`PlaneAngle`](crate::quantity::PlaneAngle) to keep angles
+/// apart from plain ratios.
+pub type Radian<V = f64, Pr = NoPrefix> = Si<Var<V>, Dimensionless, Pr>;
+/// 球面度 sr = m²/m² / Steradian sr = m²/m²
+///
+/// 与 [`Radian`] 一样是无量纲的；需要区分时用 [`SolidAngle`](crate::quantity::SolidAngle)。
+/// Dimensionless like [`Radian`]; use [`SolidAngle`](crate::quantity::SolidAngle) to
+/// keep solid angles apart.
+pub type Steradian<V = f64, Pr = NoPrefix> = Si<Var<V>, Dimensionless, Pr>;
+/// 赫兹 Hz = s⁻¹ / Hertz Hz = s⁻¹
+pub type Hertz<V = f64, Pr = NoPrefix> = Si<Var<V>, FrequencyDimension, Pr>;
+/// 牛顿 N = kg·m·s⁻² / Newton N = kg·m·s⁻²
+pub type Newton<V = f64, Pr = NoPrefix> = Si<Var<V>, ForceDimension, Pr>;
+/// 帕斯卡 Pa = N/m² / Pascal Pa = N/m²
+pub type Pascal<V = f64, Pr = NoPrefix> = Si<Var<V>, PressureDimension, Pr>;
+/// 焦耳 J = N·m / Joule J = N·m
+pub type Joule<V = f64, Pr = NoPrefix> = Si<Var<V>, EnergyDimension, Pr>;
+/// 瓦特 W = J/s / Watt W = J/s
+pub type Watt<V = f64, Pr = NoPrefix> = Si<Var<V>, PowerDimension, Pr>;
+/// 库仑 C = A·s / Coulomb C = A·s
+pub type Coulomb<V = f64, Pr = NoPrefix> = Si<Var<V>, ChargeDimension, Pr>;
+/// 伏特 V = W/A / Volt V = W/A
+pub type Volt<V = f64, Pr = NoPrefix> = Si<Var<V>, VoltageDimension, Pr>;
+/// 法拉 F = C/V / Farad F = C/V
+pub type Farad<V = f64, Pr = NoPrefix> = Si<Var<V>, CapacitanceDimension, Pr>;
+/// 欧姆 Ω = V/A / Ohm Ω = V/A
+pub type Ohm<V = f64, Pr = NoPrefix> = Si<Var<V>, ResistanceDimension, Pr>;
+/// 西门子 S = A/V / Siemens S = A/V
+pub type Siemens<V = f64, Pr = NoPrefix> = Si<Var<V>, ConductanceDimension, Pr>;
+/// 韦伯 Wb = V·s / Weber Wb = V·s
+pub type Weber<V = f64, Pr = NoPrefix> = Si<Var<V>, MagneticFluxDimension, Pr>;
+/// 特斯拉 T = Wb/m² / Tesla T = Wb/m²
+pub type Tesla<V = f64, Pr = NoPrefix> = Si<Var<V>, MagneticFluxDensityDimension, Pr>;
+/// 亨利 H = Wb/A / Henry H = Wb/A
+pub type Henry<V = f64, Pr = NoPrefix> = Si<Var<V>, InductanceDimension, Pr>;
+/// 摄氏度（温差）°C = K / Degree Celsius (as a temperature difference) °C = K
+///
+/// 只表示温差；摄氏温度值与开尔文之间有偏移，请使用 [`Celsius`](super::Celsius)。
+/// Only a temperature difference; a Celsius temperature reading is offset from the
+/// kelvin scale, use [`Celsius`](super::Celsius) for it.
+pub type DegreeCelsiusDelta<V = f64, Pr = NoPrefix> = Si<Var<V>, TemperatureDimension, Pr>;
+/// 流明 lm = cd·sr / Lumen lm = cd·sr
+pub type Lumen<V = f64, Pr = NoPrefix> = Si<Var<V>, LuminousIntensityDimension, Pr>;
+/// 勒克斯 lx = lm/m² / Lux lx = lm/m²
+pub type Lux<V = f64, Pr = NoPrefix> = Si<Var<V>, IlluminanceDimension, Pr>;
+/// 贝可勒尔 Bq = s⁻¹（种类为放射性活度，不能与 Hz 相加）/ Becquerel Bq = s⁻¹ (of the activity kind, not addable to Hz)
+pub type Becquerel<V = f64, Pr = NoPrefix> = Si<Var<V>, Kinded<FrequencyDimension, ActivityKind>, Pr>;
+/// 戈瑞 Gy = J/kg / Gray Gy = J/kg
+pub type Gray<V = f64, Pr = NoPrefix> = Si<Var<V>, DoseDimension, Pr>;
+/// 希沃特 Sv = J/kg（种类为剂量当量，不能与 Gy 相加）/ Sievert Sv = J/kg (of the dose-equivalent kind, not addable to Gy)
+pub type Sievert<V = f64, Pr = NoPrefix> = Si<Var<V>, Kinded<DoseDimension, DoseEquivalentKind>, Pr>;
+/// 开特 kat = mol/s / Katal kat = mol/s
+pub type Katal<V = f64, Pr = NoPrefix> = Si<Var<V>, CatalyticActivityDimension, Pr>;
+
+// ========== 常用组合量 ==========
+// ========== Common Compound Quantities ==========
+
+/// 面积 m² / Area m²
+pub type Area<V = f64, Pr = NoPrefix> = Si<Var<V>, AreaDimension, Pr>;
+/// 体积 m³ / Volume m³
+pub type Volume<V = f64, Pr = NoPrefix> = Si<Var<V>, VolumeDimension, Pr>;
+/// 速度 m/s / Velocity m/s
+pub type Velocity<V = f64, Pr = NoPrefix> = Si<Var<V>, VelocityDimension, Pr>;
+/// 加速度 m/s² / Acceleration m/s²
+pub type Acceleration<V = f64, Pr = NoPrefix> = Si<Var<V>, AccelerationDimension, Pr>;
+/// 动量 kg·m/s / Momentum kg·m/s
+pub type Momentum<V = f64, Pr = NoPrefix> = Si<Var<V>, MomentumDimension, Pr>;
+/// 密度 kg/m³ / Density kg/m³
+pub type Density<V = f64, Pr = NoPrefix> = Si<Var<V>, DensityDimension, Pr>;
+/// 力矩 N·m（与焦耳同量纲，但种类不同，不能相加）/ Torque N·m (same dimension as the joule but a different kind, not addable)
+pub type Torque<V = f64, Pr = NoPrefix> = Si<Var<V>, Kinded<EnergyDimension, TorqueKind>, Pr>;
+/// 角速度 rad/s / Angular velocity rad/s
+pub type AngularVelocity<V = f64, Pr = NoPrefix> = Si<Var<V>, FrequencyDimension, Pr>;
+/// 电场强度 V/m / Electric field strength V/m
+pub type ElectricField<V = f64, Pr = NoPrefix> = Si<Var<V>, ElectricFieldDimension, Pr>;
+/// 物质的量浓度 mol/m³ / Amount concentration mol/m³
+pub type Concentration<V = f64, Pr = NoPrefix> = Si<Var<V>, ConcentrationDimension, Pr>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 以下等式均在编译期由类型检查验证
+    // The identities below are verified at compile time by type checking
+
+    fn m() -> Meter { Meter::new(1.0) }
+    fn kg() -> Kilogram { Kilogram::new(1.0) }
+    fn s() -> Second { Second::new(1.0) }
+    fn a() -> Ampere { Ampere::new(1.0) }
+
+    #[test]
+    fn test_mechanical_identities() {
+        let n: Newton = kg() * m() / (s() * s());
+        let _: Pascal = n / (m() * m());
+        let j: Joule = n * m();
+        let _: Watt = j / s();
+        let _: Hertz = <Radian>::new(1.0) / s();
+        let _: Velocity = m() / s();
+        let _: Acceleration = m() / (s() * s());
+        let _: Momentum = kg() * m() / s();
+        let _: Density = kg() / (m() * m() * m());
+        let _: Torque = (n * m()).reinterpret_kind::<TorqueKind>();
+        let _: Gray = j / kg();
+        let _: Sievert = (j / kg()).reinterpret_kind::<DoseEquivalentKind>();
+    }
+
+    #[test]
+    fn test_electromagnetic_identities() {
+        let c: Coulomb = a() * s();
+        let w: Watt = <Joule>::new(1.0) / s();
+        let v: Volt = w / a();
+        let _: Farad = c / v;
+        let _: Ohm = v / a();
+        let _: Siemens = a() / v;
+        let wb: Weber = v * s();
+        let _: Tesla = wb / (m() * m());
+        let _: Henry = wb / a();
+        let _: ElectricField = v / m();
+    }
+
+    #[test]
+    fn test_other_identities() {
+        let lm: Lumen = <Candela>::new(1.0) * <Steradian>::new(1.0);
+        let _: Lux = lm / (m() * m());
+        let _: Katal = <Mole>::new(1.0) / s();
+        let _: Concentration = <Mole>::new(1.0) / (m() * m() * m());
+        let _: DegreeCelsiusDelta = <Kelvin>::new(1.0);
+    }
+}
@@ -0,0 +1,491 @@
+//! 温标：温度值与温差
+//! Temperature scales: readings and differences
+//!
+//! 量纲中的开尔文指数只能表示温差，比例 `Ratio` 也无法表示零点偏移，因此摄氏度、
+//! 华氏度等温标单独建模：[`Temperature<T, S>`] 是温标 `S` 上的温度值（仿射的“点”），
+//! [`TemperatureDelta<T, S>`] 是同一温标上的温差（“向量”）。
+//! 温度值相减得温差，温度值加减温差得温度值，两个温度值不能相加。
+//! The kelvin exponent of a dimension only expresses temperature differences and a
+//! `Ratio` cannot carry a zero offset, so scales such as Celsius and Fahrenheit are
+//! modelled separately: [`Temperature<T, S>`] is a reading on the scale `S` (an affine
+//! "point") and [`TemperatureDelta<T, S>`] a difference on that scale (a "vector").
+//! Subtracting two readings gives a difference, a reading plus or minus a difference
+//! gives a reading, and two readings cannot be added.
+//!
+//! 温标之间的换算系数均为精确分数（如 °C→°F 为 `(9t + 160) / 5`），整数存储只在
+//! 最后取整一次。
+//! Conversion coefficients between scales are exact fractions (°C→°F is
+//! `(9t + 160) / 5`), so integer storage is rounded only once at the end.
+//!
+//! # 示例 / Example
+//! ```rust
+//! use unitrix::quantity::{Celsius, CelsiusDelta, Fahrenheit, KelvinTemperature};
+//!
+//! let room: Celsius<i32> = Celsius::new(20);
+//! let f: Fahrenheit<i32> = room.convert_to();
+//! assert_eq!(f.value, 68);
+//!
+//! let warmer = room + CelsiusDelta::new(5);
+//! assert_eq!(warmer - room, CelsiusDelta::new(5));
+//!
+//! let k: KelvinTemperature = Celsius::new(100.0).convert_to();
+//! assert_eq!(k.value, 373.15);
+//! assert_eq!(format!("{}", Celsius::new(36.6)), "36.6 °C");
+//! ```
+//!
+//! 两个温度值相加无法编译：
+//! Adding two readings does not compile:
+//! ```compile_fail
+//! use unitrix::quantity::Celsius;
+//!
+//! let _ = Celsius::new(20.0) + Celsius::new(30.0);
+//! ```
+
+use core::marker::PhantomData;
+use core::ops::{Add, Sub, Neg, Mul, Div, AddAssign, SubAssign};
+
+use crate::sealed::Sealed;
+use crate::number::{Primitive, Scale, DefaultRounding, Var, reduce};
+use super::Si;
+use super::si_units::Kelvin;
+use super::format::{UnitNames, BASE_UNITS, DEGREE_CELSIUS, DEGREE_FAHRENHEIT, DEGREE_RANKINE};
+
+// ========== 温标 ==========
+// ========== Temperature Scales ==========
+
+/// 温标：度的大小与零点
+/// A temperature scale: the size of its degree and its zero point
+///
+/// 读数 `t` 对应的热力学温度为 `(t - ABSOLUTE_ZERO) * KELVIN_PER_DEGREE` 开尔文。
+/// A reading `t` is the thermodynamic temperature `(t - ABSOLUTE_ZERO) * KELVIN_PER_DEGREE` kelvins.
+pub trait TemperatureScale: Sealed + Copy + Default + 'static {
+    /// 每度的开尔文数 (分子, 分母) / Kelvins per degree as (numerator, denominator)
+    const KELVIN_PER_DEGREE: (i128, i128);
+    /// 绝对零度在该温标上的读数 (分子, 分母) / The reading of absolute zero as (numerator, denominator)
+    const ABSOLUTE_ZERO: (i128, i128);
+    /// 单位写法 / Unit spellings
+    const NAMES: UnitNames;
+}
+
+/// 开尔文温标 / The kelvin scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct KelvinScale;
+
+/// 摄氏温标：t/°C = T/K − 273.15 / The Celsius scale: t/°C = T/K − 273.15
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CelsiusScale;
+
+/// 华氏温标：t/°F = 9/5 · T/K − 459.67 / The Fahrenheit scale: t/°F = 9/5 · T/K − 459.67
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FahrenheitScale;
+
+/// 兰氏温标：T/°R = 9/5 · T/K / The Rankine scale: T/°R = 9/5 · T/K
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RankineScale;
+
+impl Sealed for KelvinScale {}
+impl Sealed for CelsiusScale {}
+impl Sealed for FahrenheitScale {}
+impl Sealed for RankineScale {}
+
+impl TemperatureScale for KelvinScale {
+    const KELVIN_PER_DEGREE: (i128, i128) = (1, 1);
+    const ABSOLUTE_ZERO: (i128, i128) = (0, 1);
+    const NAMES: UnitNames = BASE_UNITS[4];
+}
+
+impl TemperatureScale for CelsiusScale {
+    const KELVIN_PER_DEGREE: (i128, i128) = (1, 1);
+    const ABSOLUTE_ZERO: (i128, i128) = (-27315, 100);
+    const NAMES: UnitNames = DEGREE_CELSIUS;
+}
+
+impl TemperatureScale for FahrenheitScale {
+    const KELVIN_PER_DEGREE: (i128, i128) = (5, 9);
+    const ABSOLUTE_ZERO: (i128, i128) = (-45967, 100);
+    const NAMES: UnitNames = DEGREE_FAHRENHEIT;
+}
+
+impl TemperatureScale for RankineScale {
+    const KELVIN_PER_DEGREE: (i128, i128) = (5, 9);
+    const ABSOLUTE_ZERO: (i128, i128) = (0, 1);
+    const NAMES: UnitNames = DEGREE_RANKINE;
+}
+
+/// 温差换算系数 `S1 → S2`：(分子, 分母)
+/// Difference conversion factor `S1 → S2` as (numerator, denominator)
+const fn delta_factor<S1: TemperatureScale, S2: TemperatureScale>() -> (i128, i128) {
+    let (n1, d1) = S1::KELVIN_PER_DEGREE;
+    let (n2, d2) = S2::KELVIN_PER_DEGREE;
+    reduce(n1 * d2, d1 * n2)
+}
+
+/// 最大公约数 / Greatest common divisor
+const fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// 温度值换算 `S1 → S2` 的系数 (num, den, offset)：`t2 = (t1 * num + offset) / den`
+/// Reading conversion `S1 → S2` as (num, den, offset): `t2 = (t1 * num + offset) / den`
+const fn point_coefficients<S1: TemperatureScale, S2: TemperatureScale>() -> (i128, i128, i128) {
+    // t2 = (t1 - z1) * a/b + z2，z = p/q
+    // t2 = (t1 - z1) * a/b + z2 with z = p/q
+    let (a, b) = delta_factor::<S1, S2>();
+    let (p1, q1) = S1::ABSOLUTE_ZERO;
+    let (p2, q2) = S2::ABSOLUTE_ZERO;
+    let num = a * q1 * q2;
+    let den = b * q1 * q2;
+    let offset = p2 * q1 * b - p1 * a * q2;
+    let g = gcd(gcd(num, den), offset);
+    (num / g, den / g, offset / g)
+}
+
+// ========== 温度值与温差 ==========
+// ========== Readings and Differences ==========
+
+/// 温标 `S` 上的温度值
+/// A temperature reading on the scale `S`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Temperature<T, S: TemperatureScale> {
+    /// 读数 / Reading
+    pub value: T,
+    _scale: PhantomData<S>,
+}
+
+/// 温标 `S` 上的温差
+/// A temperature difference on the scale `S`
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct TemperatureDelta<T, S: TemperatureScale> {
+    /// 温差 / Difference
+    pub value: T,
+    _scale: PhantomData<S>,
+}
+
+/// 热力学温度（开尔文温标上的温度值）/ Thermodynamic temperature (a reading on the kelvin scale)
+pub type KelvinTemperature<V = f64> = Temperature<V, KelvinScale>;
+/// 摄氏温度 °C / Celsius temperature °C
+pub type Celsius<V = f64> = Temperature<V, CelsiusScale>;
+/// 华氏温度 °F / Fahrenheit temperature °F
+pub type Fahrenheit<V = f64> = Temperature<V, FahrenheitScale>;
+/// 兰氏温度 °R / Rankine temperature °R
+pub type Rankine<V = f64> = Temperature<V, RankineScale>;
+
+/// 开尔文温差（与 [`Kelvin`] 可互相转换）/ Kelvin difference (interconverts with [`Kelvin`])
+pub type KelvinDelta<V = f64> = TemperatureDelta<V, KelvinScale>;
+/// 摄氏温差 / Celsius difference
+pub type CelsiusDelta<V = f64> = TemperatureDelta<V, CelsiusScale>;
+/// 华氏温差 / Fahrenheit difference
+pub type FahrenheitDelta<V = f64> = TemperatureDelta<V, FahrenheitScale>;
+/// 兰氏温差 / Rankine difference
+pub type RankineDelta<V = f64> = TemperatureDelta<V, RankineScale>;
+
+impl<T, S: TemperatureScale> Temperature<T, S> {
+    /// 以读数创建 / Creates a reading
+    pub const fn new(value: T) -> Self {
+        Temperature { value, _scale: PhantomData }
+    }
+}
+
+impl<T, S: TemperatureScale> TemperatureDelta<T, S> {
+    /// 以温差创建 / Creates a difference
+    pub const fn new(value: T) -> Self {
+        TemperatureDelta { value, _scale: PhantomData }
+    }
+}
+
+impl<T: Scale, S: TemperatureScale> Temperature<T, S> {
+    /// 换算到温标 `S2`，溢出时返回 `None`
+    /// Converts to the scale `S2`; `None` on overflow
+    pub fn checked_convert_to<S2: TemperatureScale>(self) -> Option<Temperature<T, S2>> {
+        let (num, den, offset) = point_coefficients::<S, S2>();
+        let value = self.value.checked_scale_offset::<DefaultRounding>(num, den, offset)?;
+        Some(Temperature::new(value))
+    }
+
+    /// 换算到温标 `S2`（整数四舍五入，溢出时 panic）
+    /// Converts to the scale `S2` (integers rounded half away from zero; panics on overflow)
+    pub fn convert_to<S2: TemperatureScale>(self) -> Temperature<T, S2> {
+        self.checked_convert_to().expect("temperature conversion overflows the storage type")
+    }
+
+    /// 以开尔文表示的热力学温度，溢出时返回 `None`
+    /// The thermodynamic temperature in kelvins; `None` on overflow
+    pub fn checked_to_si(self) -> Option<Kelvin<T>> {
+        let kelvin = self.checked_convert_to::<KelvinScale>()?;
+        Some(Si(Var(kelvin.value), PhantomData))
+    }
+
+    /// 以开尔文表示的热力学温度（溢出时 panic）
+    /// The thermodynamic temperature in kelvins (panics on overflow)
+    pub fn to_si(self) -> Kelvin<T> {
+        self.checked_to_si().expect("temperature conversion overflows the storage type")
+    }
+
+    /// 由以开尔文表示的热力学温度创建，溢出时返回 `None`
+    /// Creates the reading from a thermodynamic temperature in kelvins; `None` on overflow
+    pub fn checked_from_si(kelvin: Kelvin<T>) -> Option<Self> {
+        KelvinTemperature::new(kelvin.0 .0).checked_convert_to()
+    }
+
+    /// 由以开尔文表示的热力学温度创建（溢出时 panic）
+    /// Creates the reading from a thermodynamic temperature in kelvins (panics on overflow)
+    pub fn from_si(kelvin: Kelvin<T>) -> Self {
+        Self::checked_from_si(kelvin).expect("temperature conversion overflows the storage type")
+    }
+}
+
+impl<T: Scale, S: TemperatureScale> TemperatureDelta<T, S> {
+    /// 换算到温标 `S2` 的温差，溢出时返回 `None`
+    /// Converts to a difference on the scale `S2`; `None` on overflow
+    pub fn checked_convert_to<S2: TemperatureScale>(self) -> Option<TemperatureDelta<T, S2>> {
+        let (num, den) = delta_factor::<S, S2>();
+        Some(TemperatureDelta::new(self.value.checked_scale::<DefaultRounding>(num, den)?))
+    }
+
+    /// 换算到温标 `S2` 的温差（整数四舍五入，溢出时 panic）
+    /// Converts to a difference on the scale `S2` (integers rounded half away from zero; panics on overflow)
+    pub fn convert_to<S2: TemperatureScale>(self) -> TemperatureDelta<T, S2> {
+        self.checked_convert_to().expect("temperature conversion overflows the storage type")
+    }
+
+    /// 以开尔文表示的温差，溢出时返回 `None`
+    /// The difference in kelvins; `None` on overflow
+    pub fn checked_to_si(self) -> Option<Kelvin<T>> {
+        let kelvin = self.checked_convert_to::<KelvinScale>()?;
+        Some(Si(Var(kelvin.value), PhantomData))
+    }
+
+    /// 以开尔文表示的温差（溢出时 panic）
+    /// The difference in kelvins (panics on overflow)
+    pub fn to_si(self) -> Kelvin<T> {
+        self.checked_to_si().expect("temperature conversion overflows the storage type")
+    }
+}
+
+// ========== 转换 ==========
+// ========== Conversions ==========
+
+macro_rules! impl_scale_from {
+    ($($from:ty => $to:ty),* $(,)?) => {
+        $(
+            impl<T: Scale> From<Temperature<T, $from>> for Temperature<T, $to> {
+                fn from(t: Temperature<T, $from>) -> Self {
+                    t.convert_to()
+                }
+            }
+
+            impl<T: Scale> From<TemperatureDelta<T, $from>> for TemperatureDelta<T, $to> {
+                fn from(d: TemperatureDelta<T, $from>) -> Self {
+                    d.convert_to()
+                }
+            }
+        )*
+    };
+}
+
+impl_scale_from!(
+    KelvinScale => CelsiusScale, KelvinScale => FahrenheitScale, KelvinScale => RankineScale,
+    CelsiusScale => KelvinScale, CelsiusScale => FahrenheitScale, CelsiusScale => RankineScale,
+    FahrenheitScale => KelvinScale, FahrenheitScale => CelsiusScale, FahrenheitScale => RankineScale,
+    RankineScale => KelvinScale, RankineScale => CelsiusScale, RankineScale => FahrenheitScale,
+);
+
+// 开尔文温差与量纲为温度的 `Si` 是同一个量
+// A kelvin difference and an `Si` of the temperature dimension are the same quantity
+impl<T: Primitive> From<Kelvin<T>> for KelvinDelta<T> {
+    fn from(k: Kelvin<T>) -> Self {
+        TemperatureDelta::new(k.0 .0)
+    }
+}
+
+impl<T: Primitive> From<KelvinDelta<T>> for Kelvin<T> {
+    fn from(d: KelvinDelta<T>) -> Self {
+        Si(Var(d.value), PhantomData)
+    }
+}
+
+// ========== 运算 ==========
+// ========== Arithmetic ==========
+
+/// 温度值 - 温度值 = 温差 / Reading − reading = difference
+impl<T: Primitive, S: TemperatureScale> Sub for Temperature<T, S> {
+    type Output = TemperatureDelta<T, S>;
+
+    fn sub(self, rhs: Self) -> TemperatureDelta<T, S> {
+        TemperatureDelta::new(self.value - rhs.value)
+    }
+}
+
+/// 温度值 + 温差 = 温度值 / Reading + difference = reading
+impl<T: Primitive, S: TemperatureScale> Add<TemperatureDelta<T, S>> for Temperature<T, S> {
+    type Output = Self;
+
+    fn add(self, rhs: TemperatureDelta<T, S>) -> Self {
+        Temperature::new(self.value + rhs.value)
+    }
+}
+
+/// 温差 + 温度值 = 温度值 / Difference + reading = reading
+impl<T: Primitive, S: TemperatureScale> Add<Temperature<T, S>> for TemperatureDelta<T, S> {
+    type Output = Temperature<T, S>;
+
+    fn add(self, rhs: Temperature<T, S>) -> Temperature<T, S> {
+        Temperature::new(self.value + rhs.value)
+    }
+}
+
+/// 温度值 - 温差 = 温度值 / Reading − difference = reading
+impl<T: Primitive, S: TemperatureScale> Sub<TemperatureDelta<T, S>> for Temperature<T, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: TemperatureDelta<T, S>) -> Self {
+        Temperature::new(self.value - rhs.value)
+    }
+}
+
+impl<T: Primitive, S: TemperatureScale> AddAssign<TemperatureDelta<T, S>> for Temperature<T, S> {
+    fn add_assign(&mut self, rhs: TemperatureDelta<T, S>) {
+        self.value += rhs.value;
+    }
+}
+
+impl<T: Primitive, S: TemperatureScale> SubAssign<TemperatureDelta<T, S>> for Temperature<T, S> {
+    fn sub_assign(&mut self, rhs: TemperatureDelta<T, S>) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<T: Primitive, S: TemperatureScale> Add for TemperatureDelta<T, S> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        TemperatureDelta::new(self.value + rhs.value)
+    }
+}
+
+impl<T: Primitive, S: TemperatureScale> Sub for TemperatureDelta<T, S> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        TemperatureDelta::new(self.value - rhs.value)
+    }
+}
+
+impl<T: Primitive, S: TemperatureScale> AddAssign for TemperatureDelta<T, S> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value;
+    }
+}
+
+impl<T: Primitive, S: TemperatureScale> SubAssign for TemperatureDelta<T, S> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value;
+    }
+}
+
+impl<T: Primitive, S: TemperatureScale> Neg for TemperatureDelta<T, S> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        TemperatureDelta::new(-self.value)
+    }
+}
+
+impl<T: Primitive, S: TemperatureScale> Mul<T> for TemperatureDelta<T, S> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        TemperatureDelta::new(self.value * rhs)
+    }
+}
+
+impl<T: Primitive, S: TemperatureScale> Div<T> for TemperatureDelta<T, S> {
+    type Output = Self;
+
+    fn div(self, rhs: T) -> Self {
+        TemperatureDelta::new(self.value / rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use std::format;
+
+    use super::*;
+    use crate::quantity::{Locale, Style};
+
+    #[test]
+    fn test_coefficients() {
+        assert_eq!(point_coefficients::<CelsiusScale, FahrenheitScale>(), (9, 5, 160));
+        assert_eq!(point_coefficients::<FahrenheitScale, CelsiusScale>(), (5, 9, -160));
+        assert_eq!(point_coefficients::<CelsiusScale, KelvinScale>(), (20, 20, 5463));
+        assert_eq!(point_coefficients::<KelvinScale, RankineScale>(), (9, 5, 0));
+        assert_eq!(delta_factor::<FahrenheitScale, CelsiusScale>(), (5, 9));
+    }
+
+    #[test]
+    fn test_exact_conversions() {
+        assert_eq!(Celsius::new(20).convert_to::<FahrenheitScale>().value, 68);
+        assert_eq!(Fahrenheit::new(-40).convert_to::<CelsiusScale>().value, -40);
+        assert_eq!(Fahrenheit::new(212_i64).convert_to::<CelsiusScale>().value, 100);
+        assert_eq!(Celsius::new(0.0).convert_to::<KelvinScale>().value, 273.15);
+        assert_eq!(Rankine::new(672).convert_to::<FahrenheitScale>().value, 212);
+        assert_eq!(KelvinTemperature::new(300).convert_to::<RankineScale>().value, 540);
+        // 整数只取整一次：26.85 °C → 80.33 °F
+        // Integers are rounded once: 26.85 °C → 80.33 °F
+        assert_eq!(Celsius::new(27).convert_to::<KelvinScale>().value, 300);
+        assert_eq!(KelvinTemperature::new(300).convert_to::<FahrenheitScale>().value, 80);
+
+        let f: Fahrenheit<i32> = Celsius::new(100).into();
+        assert_eq!(f.value, 212);
+        assert!(Celsius::<i8>::new(100).checked_convert_to::<FahrenheitScale>().is_none());
+
+        let d: FahrenheitDelta<i32> = CelsiusDelta::new(10).into();
+        assert_eq!(d.value, 18);
+        assert_eq!(d.to_si().0 .0, 10);
+        assert_eq!(Celsius::new(25.0).to_si().0 .0, 298.15);
+        assert!(Fahrenheit::from_si(<Kelvin>::new(255.3722222222222)).value.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_affine_arithmetic() {
+        let morning = Celsius::new(12);
+        let noon = Celsius::new(21);
+        let rise = noon - morning;
+        assert_eq!(rise, CelsiusDelta::new(9));
+        assert_eq!(morning + rise, noon);
+        assert_eq!(rise + morning, noon);
+        assert_eq!(noon - rise, morning);
+        assert_eq!(rise * 2 - rise, rise);
+        assert_eq!(-rise / 3, CelsiusDelta::new(-3));
+
+        let mut t = morning;
+        t += rise;
+        t -= CelsiusDelta::new(1);
+        assert_eq!(t.value, 20);
+
+        let k: KelvinDelta = Kelvin::new(1.5).into();
+        let back: Kelvin = k.into();
+        assert_eq!(back.0 .0, 1.5);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Celsius::new(20)), "20 °C");
+        assert_eq!(format!("{:.1}", Fahrenheit::new(98.61)), "98.6 °F");
+        assert_eq!(format!("{}", KelvinTemperature::new(300)), "300 K");
+        assert_eq!(format!("{}", RankineDelta::new(9)), "9 °R");
+        assert_eq!(format!("{}", Celsius::new(20).format().style(Style::Ascii)), "20 degC");
+        assert_eq!(format!("{}", Celsius::new(20).format().style(Style::Long)), "20 degrees Celsius");
+        assert_eq!(format!("{}", Celsius::new(1).format().style(Style::Long)), "1 degree Celsius");
+        assert_eq!(format!("{}", Celsius::new(20).format().style(Style::Latex)), "\\SI{20}{\\degreeCelsius}");
+        assert_eq!(format!("{}", Fahrenheit::new(68).format().style(Style::Latex)), "\\SI{68}{\\fahrenheit}");
+        assert_eq!(format!("{}", RankineDelta::new(9).format().style(Style::Latex)), "\\SI{9}{\\rankine}");
+        assert_eq!(format!("{}", Celsius::new(20).format().locale(Locale::Chinese)), "20 摄氏度");
+    }
+}
@@ -7,10 +7,13 @@ use core::ops::{Neg, Add, Sub, Mul, Div, AddAssign, SubAssign, MulAssign, DivAss
 
 use crate::sealed::Sealed;
 use super::{Si, Sied};
-use super::ratio::{NoRatio, Scaled};
+use super::ratio::{Ratio, NoRatio, PrimeList, Scaled};
+use super::measured::Approx;
+use super::labeled::Labeled;
+use super::convert::{Conversion, CommonScale};
 use super::Dimensional;
-use super::prefix::Prefixed;
-use crate::number::{Primitive, Var};
+use super::prefix::{Prefix, Prefixed};
+use crate::number::{TypedInt, Primitive, Var, Scale, Min, DefaultRounding, CheckedArith};
 use super::Unitary;
 
 // ========== 辅助trait和实现 ==========
@@ -35,6 +38,87 @@ impl<S: Sied> UnitOrSi for Unit<S, NoRatio> {
     }
 }
 
+// 辅助trait，把比例中的10的幂次移入词头：取 k = min(exp2, exp5)，
+// 比例的2、5指数各减 k，词头指数加 k，数值不变
+// Helper trait moving the power of ten out of the ratio into the prefix: with
+// k = min(exp2, exp5), the exponents of 2 and 5 drop by k and the prefix exponent
+// rises by k; the stored value is unchanged
+pub trait Normalize {
+    type Output;
+    fn normalize(self) -> Self::Output;
+}
+
+impl<T, D, Ep, E5, EPi, E3, E2, P> Normalize for Unit<Si<Var<T>, D, Prefix<Ep>>, Ratio<E5, EPi, E3, E2, P>>
+where
+    T: Primitive,
+    D: Dimensional,
+    Ep: TypedInt + Add<<E2 as Min<E5>>::Output, Output: TypedInt>,
+    E5: TypedInt + Sub<<E2 as Min<E5>>::Output, Output: TypedInt>,
+    EPi: TypedInt,
+    E3: TypedInt,
+    E2: TypedInt + Min<E5, Output: TypedInt> + Sub<<E2 as Min<E5>>::Output, Output: TypedInt>,
+    P: PrimeList,
+{
+    type Output = Unit<
+        Si<Var<T>, D, Prefix<<Ep as Add<<E2 as Min<E5>>::Output>>::Output>>,
+        Ratio<
+            <E5 as Sub<<E2 as Min<E5>>::Output>>::Output,
+            EPi,
+            E3,
+            <E2 as Sub<<E2 as Min<E5>>::Output>>::Output,
+            P
+        >
+    >;
+    fn normalize(self) -> Self::Output {
+        Unit(Si((self.0).0, PhantomData), PhantomData)
+    }
+}
+
+// 近似比例保持不变
+impl<S: Sied, M, R> Normalize for Unit<S, Approx<M, R>> {
+    type Output = Self;
+    fn normalize(self) -> Self::Output {
+        self
+    }
+}
+
+// 带名称的比例保持不变，以免丢失名称
+impl<S: Sied, L, R> Normalize for Unit<S, Labeled<L, R>> {
+    type Output = Self;
+    fn normalize(self) -> Self::Output {
+        self
+    }
+}
+
+/// 把两个值换算到公共比例与较小词头下，整数存储时溢出返回 `None`
+fn checked_common_values<T, Pr1, Pr2, R1, R2>(a: T, b: T) -> Option<(T, T)>
+where
+    T: Scale,
+    Pr1: Min<Pr2> + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    Pr2: Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    R1: CommonScale<R2>,
+{
+    let (fa, fb) = R1::factors();
+    let fa = fa * <Pr1 as Div<<Pr1 as Min<Pr2>>::Output>>::Output::conversion_factor();
+    let fb = fb * <Pr2 as Div<<Pr1 as Min<Pr2>>::Output>>::Output::conversion_factor();
+    Some((
+        fa.checked_apply::<T, DefaultRounding>(a)?,
+        fb.checked_apply::<T, DefaultRounding>(b)?,
+    ))
+}
+
+/// 把两个值换算到公共比例与较小词头下（整数存储时溢出则 panic）
+fn common_values<T, Pr1, Pr2, R1, R2>(a: T, b: T) -> (T, T)
+where
+    T: Scale,
+    Pr1: Min<Pr2> + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    Pr2: Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    R1: CommonScale<R2>,
+{
+    checked_common_values::<T, Pr1, Pr2, R1, R2>(a, b)
+        .expect("unit conversion overflows the storage type")
+}
+
 /// Unit基础结构
 /// 
 /// # 类型参数
@@ -55,6 +139,67 @@ where
     }
 }
 
+/// 检查溢出的加减法：词头、比例换算或结果溢出时返回 `None`（运算符则 panic）
+/// Overflow-checked addition and subtraction: `None` if the prefix or ratio rescale or
+/// the result overflows (where the operators panic)
+pub trait CheckedAddSub<Rhs> {
+    /// 结果类型，同对应的 `Add` / `Sub` / Result type, as for the matching `Add` / `Sub`
+    type Output;
+
+    /// 检查溢出的加法 / Overflow-checked addition
+    fn checked_add(self, rhs: Rhs) -> Option<Self::Output>;
+
+    /// 检查溢出的减法 / Overflow-checked subtraction
+    fn checked_sub(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// 公共比例与较小词头下的结果类型
+type CommonUnit<T, D, Pr1, Pr2, R1, R2> =
+    Unit<Si<Var<T>, D, <Pr1 as Min<Pr2>>::Output>, <R1 as CommonScale<R2>>::Output>;
+
+// U ± U
+impl<T, D, Pr1, Pr2, R1, R2> CheckedAddSub<Unit<Si<Var<T>, D, Pr2>, R2>> for Unit<Si<Var<T>, D, Pr1>, R1>
+where
+    T: Primitive + Scale + CheckedArith,
+    D: Dimensional,
+    Pr1: Prefixed + Min<Pr2, Output: Prefixed> + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    Pr2: Prefixed + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    R1: CommonScale<R2>,
+    CommonUnit<T, D, Pr1, Pr2, R1, R2>: UnitOrSi,
+{
+    type Output = <CommonUnit<T, D, Pr1, Pr2, R1, R2> as UnitOrSi>::Output;
+
+    fn checked_add(self, rhs: Unit<Si<Var<T>, D, Pr2>, R2>) -> Option<Self::Output> {
+        let (a, b) = checked_common_values::<T, Pr1, Pr2, R1, R2>((self.0).0 .0, (rhs.0).0 .0)?;
+        Some(Unit(Si(Var(a.checked_add(b)?), PhantomData), PhantomData).unit())
+    }
+
+    fn checked_sub(self, rhs: Unit<Si<Var<T>, D, Pr2>, R2>) -> Option<Self::Output> {
+        let (a, b) = checked_common_values::<T, Pr1, Pr2, R1, R2>((self.0).0 .0, (rhs.0).0 .0)?;
+        Some(Unit(Si(Var(a.checked_sub(b)?), PhantomData), PhantomData).unit())
+    }
+}
+
+// U ± Si（Si 视为无比例的 Unit）
+impl<T, D, Pr1, Pr2, R> CheckedAddSub<Si<Var<T>, D, Pr2>> for Unit<Si<Var<T>, D, Pr1>, R>
+where
+    T: Primitive,
+    D: Dimensional,
+    Pr1: Prefixed,
+    Pr2: Prefixed,
+    Self: CheckedAddSub<Unit<Si<Var<T>, D, Pr2>, NoRatio>>,
+{
+    type Output = <Self as CheckedAddSub<Unit<Si<Var<T>, D, Pr2>, NoRatio>>>::Output;
+
+    fn checked_add(self, rhs: Si<Var<T>, D, Pr2>) -> Option<Self::Output> {
+        self.checked_add(Unit(rhs, PhantomData))
+    }
+
+    fn checked_sub(self, rhs: Si<Var<T>, D, Pr2>) -> Option<Self::Output> {
+        self.checked_sub(Unit(rhs, PhantomData))
+    }
+}
+
 impl< S: Sied, R: Scaled> Sealed for Unit<S, R>{}
 
 impl<S: Sied, R: Scaled> Unitary for Unit<S, R>{}
@@ -75,17 +220,41 @@ where
 }
 
 // ----- 加法运算符及其赋值 -----
-// U + U
-impl<S: Sied, R: Scaled> Add for Unit<S, R>
+// U + U（比例、词头可不同，结果取公共细比例与较小词头，双方精确换算后相加）
+impl<T, D, Pr1, Pr2, R1, R2> Add<Unit<Si<Var<T>, D, Pr2>, R2>> for Unit<Si<Var<T>, D, Pr1>, R1>
 where
-    S:  Sied + Add<S, Output = S>,
-    R: Scaled,
+    T: Primitive + Scale,
+    D: Dimensional,
+    Pr1: Prefixed + Min<Pr2, Output: Prefixed> + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    Pr2: Prefixed + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    R1: CommonScale<R2>,
+    Unit<Si<Var<T>, D, <Pr1 as Min<Pr2>>::Output>, <R1 as CommonScale<R2>>::Output>: UnitOrSi,
 {
-    type Output = Self;
+    type Output = <
+        Unit<Si<Var<T>, D, <Pr1 as Min<Pr2>>::Output>, <R1 as CommonScale<R2>>::Output> as UnitOrSi
+    >::Output;
     
-    /// 物理量乘法
-    fn add(self, rhs: Self) -> Self::Output {
-        Unit(self.0 + rhs.0, PhantomData)
+    /// 物理量加法（整数存储时换算溢出则 panic）
+    fn add(self, rhs: Unit<Si<Var<T>, D, Pr2>, R2>) -> Self::Output {
+        let (a, b) = common_values::<T, Pr1, Pr2, R1, R2>((self.0).0 .0, (rhs.0).0 .0);
+        Unit(Si(Var(a + b), PhantomData), PhantomData).unit()
+    }
+}
+
+// U + Si（Si 视为无比例的 Unit）
+impl<T, D, Pr1, Pr2, R> Add<Si<Var<T>, D, Pr2>> for Unit<Si<Var<T>, D, Pr1>, R>
+where
+    T: Primitive,
+    D: Dimensional,
+    Pr1: Prefixed,
+    Pr2: Prefixed,
+    Self: Add<Unit<Si<Var<T>, D, Pr2>, NoRatio>>,
+{
+    type Output = <Self as Add<Unit<Si<Var<T>, D, Pr2>, NoRatio>>>::Output;
+    
+    /// 物理量加法
+    fn add(self, rhs: Si<Var<T>, D, Pr2>) -> Self::Output {
+        self + Unit(rhs, PhantomData)
     }
 }
 
@@ -126,17 +295,41 @@ where
 }
 
 // ----- 减法运算符及减法赋值 -----
-// U - U
-impl<S: Sied, R: Scaled> Sub for Unit<S, R>
+// U - U（比例、词头可不同，结果取公共细比例与较小词头）
+impl<T, D, Pr1, Pr2, R1, R2> Sub<Unit<Si<Var<T>, D, Pr2>, R2>> for Unit<Si<Var<T>, D, Pr1>, R1>
 where
-    S:  Sied + Sub<S, Output = S>,
-    R: Scaled,
+    T: Primitive + Scale,
+    D: Dimensional,
+    Pr1: Prefixed + Min<Pr2, Output: Prefixed> + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    Pr2: Prefixed + Div<<Pr1 as Min<Pr2>>::Output, Output: Conversion>,
+    R1: CommonScale<R2>,
+    Unit<Si<Var<T>, D, <Pr1 as Min<Pr2>>::Output>, <R1 as CommonScale<R2>>::Output>: UnitOrSi,
 {
-    type Output = Self;
+    type Output = <
+        Unit<Si<Var<T>, D, <Pr1 as Min<Pr2>>::Output>, <R1 as CommonScale<R2>>::Output> as UnitOrSi
+    >::Output;
     
-    /// 物理量乘法
-    fn sub(self, rhs: Self) -> Self::Output {
-        Unit(self.0 - rhs.0, PhantomData)
+    /// 物理量减法（整数存储时换算溢出则 panic）
+    fn sub(self, rhs: Unit<Si<Var<T>, D, Pr2>, R2>) -> Self::Output {
+        let (a, b) = common_values::<T, Pr1, Pr2, R1, R2>((self.0).0 .0, (rhs.0).0 .0);
+        Unit(Si(Var(a - b), PhantomData), PhantomData).unit()
+    }
+}
+
+// U - Si（Si 视为无比例的 Unit）
+impl<T, D, Pr1, Pr2, R> Sub<Si<Var<T>, D, Pr2>> for Unit<Si<Var<T>, D, Pr1>, R>
+where
+    T: Primitive,
+    D: Dimensional,
+    Pr1: Prefixed,
+    Pr2: Prefixed,
+    Self: Sub<Unit<Si<Var<T>, D, Pr2>, NoRatio>>,
+{
+    type Output = <Self as Sub<Unit<Si<Var<T>, D, Pr2>, NoRatio>>>::Output;
+    
+    /// 物理量减法
+    fn sub(self, rhs: Si<Var<T>, D, Pr2>) -> Self::Output {
+        self - Unit(rhs, PhantomData)
     }
 }
 
@@ -178,32 +371,32 @@ where
 
 // ----- 乘法运算符及乘法赋值 -----
 
-// U * U
+// U * U（结果中的10的幂次移入词头）
 impl<S1, S2, R1, R2> Mul<Unit<S2, R2>> for Unit<S1, R1>
 where
     S1: Sied + Mul<S2, Output: Sied>,
     S2: Sied,
-    R1: Scaled + Mul<R2, Output: Scaled>,
+    R1: Scaled + Mul<R2>,
     R2: Scaled,
     Unit<
         <S1 as Mul<S2>>::Output,
         <R1 as Mul<R2>>::Output
-    >: UnitOrSi,
+    >: Normalize<Output: UnitOrSi>,
 {
     type Output = <
-        Unit<
+        <Unit<
             <S1 as Mul<S2>>::Output,
             <R1 as Mul<R2>>::Output
-        > as UnitOrSi
+        > as Normalize>::Output as UnitOrSi
     >::Output;
     
     /// 物理量乘法
     fn mul(self, rhs: Unit<S2, R2>) -> Self::Output {
-        Unit(self.0 * rhs.0, PhantomData).unit()
+        Unit(self.0 * rhs.0, PhantomData).normalize().unit()
     }
 }
 
-// U * SI
+// U * SI（结果中的10的幂次移入词头）
 //因为编译器对U * T与U * Si无法区分，Si必须用Si<Var<T>, D, Pr>表示
 impl<S, R, T, D, Pr> Mul<Si<Var<T>, D, Pr>> for Unit<S, R>
 where
@@ -213,15 +406,21 @@ where
     S: Sied + Mul<Si<Var<T>, D, Pr>, Output: Sied>,
     Si<Var<T>, D, Pr>: Sied,
     R: Scaled,
-{
-    type Output = Unit<
-        <S as Mul<Si<Var<T>, D, Pr>>>::Output,  // 单位相乘
+    Unit<
+        <S as Mul<Si<Var<T>, D, Pr>>>::Output,
         R
-    >;
+    >: Normalize<Output: UnitOrSi>,
+{
+    type Output = <
+        <Unit<
+            <S as Mul<Si<Var<T>, D, Pr>>>::Output,  // 单位相乘
+            R
+        > as Normalize>::Output as UnitOrSi
+    >::Output;
     
     /// 物理量乘法
     fn mul(self, rhs: Si<Var<T>, D, Pr>) -> Self::Output {
-        Unit(self.0 * rhs, PhantomData)
+        Unit(self.0 * rhs, PhantomData).normalize().unit()
     }
 }
 
@@ -289,32 +488,32 @@ where
 
 // ----- 除法运算符及除法赋值 -----
 
-// U / U
+// U / U（结果中的10的幂次移入词头）
 impl<S1, S2, R1, R2> Div<Unit<S2, R2>> for Unit<S1, R1>
 where
     S1: Sied + Div<S2, Output: Sied>,
     S2: Sied,
-    R1: Scaled + Div<R2, Output: Scaled>,
+    R1: Scaled + Div<R2>,
     R2: Scaled,
     Unit<
         <S1 as Div<S2>>::Output,  // 单位相除
         <R1 as Div<R2>>::Output
-    >: UnitOrSi,
+    >: Normalize<Output: UnitOrSi>,
 {
     type Output = <
-        Unit<
+        <Unit<
             <S1 as Div<S2>>::Output,  // 单位相除
             <R1 as Div<R2>>::Output
-        > as UnitOrSi
+        > as Normalize>::Output as UnitOrSi
     >::Output;
     
     /// 物理量除法
     fn div(self, rhs: Unit<S2, R2>) -> Self::Output {
-        Unit(self.0 / rhs.0, PhantomData).unit()
+        Unit(self.0 / rhs.0, PhantomData).normalize().unit()
     }
 }
 
-// U / SI
+// U / SI（结果中的10的幂次移入词头）
 //因为编译器对U / T与U / Si无法区分，Si必须用Si<Var<T>, D, Pr>表示
 impl<S, R, T, D, Pr> Div<Si<Var<T>, D, Pr>> for Unit<S, R>
 where
@@ -324,15 +523,21 @@ where
     S: Sied + Div<Si<Var<T>, D, Pr>, Output: Sied>,
     Si<Var<T>, D, Pr>: Sied,
     R: Scaled,
-{
-    type Output = Unit<
-        <S as Div<Si<Var<T>, D, Pr>>>::Output,  // 单位相除
+    Unit<
+        <S as Div<Si<Var<T>, D, Pr>>>::Output,
         R
-    >;
+    >: Normalize<Output: UnitOrSi>,
+{
+    type Output = <
+        <Unit<
+            <S as Div<Si<Var<T>, D, Pr>>>::Output,  // 单位相除
+            R
+        > as Normalize>::Output as UnitOrSi
+    >::Output;
     
     /// 物理量除法
     fn div(self, rhs: Si<Var<T>, D, Pr>) -> Self::Output {
-        Unit(self.0 / rhs, PhantomData)
+        Unit(self.0 / rhs, PhantomData).normalize().unit()
     }
 }
 
@@ -396,4 +601,79 @@ where
     fn div_assign(&mut self, rhs: T) {
         self.0 /= Var(rhs);
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::number::{Const, Z0, P1};
+    use crate::quantity::{Hecto, Deca, Minutes, Hours};
+    use crate::quantity::si_units::Meter;
+
+    #[test]
+    fn test_fold_power_of_ten() {
+        // 10 m 的比例相乘得 10² = 百，退化为 `Si`
+        // Two ratios of 10 multiply to 10², which becomes a prefix and collapses to `Si`
+        type TenMeters = Unit<Meter<i64>, Ratio<P1, Z0, Z0, P1>>;
+        let area: Si<Var<i64>, _, Hecto> = TenMeters::new(3) * TenMeters::new(4);
+        assert_eq!(area.0 .0, 12);
+
+        // 60 = 6 * 10：小时 / 分钟 的 10 移入词头
+        // 60 = 6 * 10: the 10 in hour / minute moves into the prefix
+        type Six = Ratio<Z0, Z0, P1, P1>;
+        let ratio: Unit<Si<Var<i64>, _, Deca>, Six> = Hours::new(2) / Minutes::new(1);
+        assert_eq!((ratio.0).0 .0, 2);
+
+        // 分钟 / 分钟 = 1，退化为 `Si`
+        // Minute / minute = 1, collapsing to `Si`
+        let one: Si<Var<i64>, _, Prefix<Const<0>>> = Minutes::new(6) / Minutes::new(3);
+        assert_eq!(one.0 .0, 2);
+    }
+
+    #[test]
+    fn test_fold_power_of_ten_with_si() {
+        type TenMeters = Unit<Meter<i64>, Ratio<P1, Z0, Z0, P1>>;
+        let m = Meter::<i64>::new(4);
+
+        // U * Si 与 Si * U 同样把 10 移入词头并退化为 `Si`
+        // U * Si and Si * U also move the 10 into the prefix and collapse to `Si`
+        let area: Si<Var<i64>, _, Deca> = TenMeters::new(3) * m;
+        assert_eq!(area.0 .0, 12);
+        let area: Si<Var<i64>, _, Deca> = m * TenMeters::new(3);
+        assert_eq!(area.0 .0, 12);
+
+        // Si / U：1/10 移入词头（分）
+        // Si / U: the 1/10 moves into the prefix (deci)
+        let ratio: Si<Var<i64>, _, Prefix<Const<-1>>> = m / TenMeters::new(2);
+        assert_eq!(ratio.0 .0, 2);
+
+        // U / Si：2/5 = 4/10，比例化为 4，词头为分
+        // U / Si: 2/5 = 4/10, leaving a ratio of 4 and a deci prefix
+        type TwoFifths = Ratio<Const<-1>, Z0, Z0, P1>;
+        type Four = Ratio<Z0, Z0, Z0, Const<2>>;
+        let q: Unit<Si<Var<i64>, _, Prefix<Const<-1>>>, Four> =
+            Unit::<Meter<i64>, TwoFifths>::new(6) / Meter::<i64>::new(3);
+        assert_eq!((q.0).0 .0, 2);
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        let sum = Hours::<i32>::new(2).checked_add(Minutes::<i32>::new(30)).unwrap();
+        assert_eq!((sum.0).0 .0, (Hours::<i32>::new(2) + Minutes::<i32>::new(30)).0 .0 .0);
+        let diff = Hours::<i32>::new(2).checked_sub(Minutes::<i32>::new(30)).unwrap();
+        assert_eq!((diff.0).0 .0, (Hours::<i32>::new(2) - Minutes::<i32>::new(30)).0 .0 .0);
+
+        // 换算到分钟时溢出 / Overflows while rescaling to minutes
+        assert!(Hours::<i32>::new(i32::MAX / 2).checked_add(Minutes::<i32>::new(1)).is_none());
+        // 相加时溢出 / Overflows in the sum itself
+        assert!(Hours::<i32>::new(i32::MAX).checked_add(Hours::<i32>::new(1)).is_none());
+        assert!(Hours::<i32>::new(i32::MIN).checked_sub(Hours::<i32>::new(1)).is_none());
+
+        // U ± Si 与 Si ± U / U ± Si and Si ± U
+        type TenMeters = Unit<Meter<i32>, Ratio<P1, Z0, Z0, P1>>;
+        let sum: Meter<i32> = TenMeters::new(3).checked_add(Meter::<i32>::new(4)).unwrap();
+        assert_eq!(sum.0 .0, 34);
+        let diff: Meter<i32> = Meter::<i32>::new(4).checked_sub(TenMeters::new(3)).unwrap();
+        assert_eq!(diff.0 .0, -26);
+        assert!(Meter::<i32>::new(1).checked_add(TenMeters::new(i32::MAX)).is_none());
+    }
+}